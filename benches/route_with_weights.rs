@@ -0,0 +1,105 @@
+use auria_core::{ExpertId, Tier};
+use auria_router::{DeterministicRouter, ExpertIndexMap, GateScratch, GatingRouter, Router};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::collections::HashMap;
+
+fn expert_weights(count: u32) -> Vec<(ExpertId, f32)> {
+    (0..count)
+        .map(|i| {
+            let mut bytes = [0u8; 32];
+            bytes[0..4].copy_from_slice(&i.to_le_bytes());
+            let w = (i.wrapping_mul(2_654_435_761) % 10_000) as f32 / 10_000.0;
+            (ExpertId(bytes), w)
+        })
+        .collect()
+}
+
+// The pre-refactor strategy: collect, fully sort, take k.
+fn full_sort_top_k(weights: &HashMap<ExpertId, f32>, k: usize) -> Vec<ExpertId> {
+    let mut sorted: Vec<_> = weights.iter().collect();
+    sorted.sort_by(|a, b| b.1.partial_cmp(a.1).unwrap_or(std::cmp::Ordering::Equal));
+    sorted.iter().take(k).map(|(id, _)| (*id).clone()).collect()
+}
+
+fn bench_route_with_weights(c: &mut Criterion) {
+    let router = DeterministicRouter::new_unchecked(1024);
+    let mut group = c.benchmark_group("route_with_weights");
+
+    for &count in &[1_024u32, 8_192, 65_536] {
+        let slice = expert_weights(count);
+        let map: HashMap<ExpertId, f32> = slice.iter().cloned().collect();
+        let index_map: ExpertIndexMap = slice.iter().map(|(id, _)| id.clone()).collect();
+        let dense = index_map.dense_weights(&map, f32::NEG_INFINITY);
+
+        group.bench_with_input(BenchmarkId::new("full_sort", count), &map, |b, map| {
+            b.iter(|| full_sort_top_k(black_box(map), 8))
+        });
+        group.bench_with_input(BenchmarkId::new("hashmap", count), &map, |b, map| {
+            b.iter(|| router.route_with_weights(Tier::Pro, 0, black_box(map)))
+        });
+        group.bench_with_input(BenchmarkId::new("slice", count), &slice, |b, slice| {
+            b.iter(|| router.route_with_weight_slice(Tier::Pro, 0, black_box(slice)))
+        });
+        group.bench_with_input(BenchmarkId::new("dense", count), &dense, |b, dense| {
+            b.iter(|| router.route_with_dense_weights(Tier::Pro, 0, &index_map, black_box(dense)))
+        });
+    }
+
+    group.finish();
+}
+
+// Per-token gate logits for a 64-token batch: one HashMap per token versus
+// contiguous rows through a reused GateScratch.
+fn bench_gating_batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gating_batch");
+    let tokens: Vec<u64> = (0..64).collect();
+
+    for &count in &[256u32, 4_096] {
+        let index_map: ExpertIndexMap = expert_weights(count)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        let logits: Vec<f32> = (0..tokens.len() as u32 * count)
+            .map(|i| (i.wrapping_mul(2_654_435_761) % 10_000) as f32 / 1_000.0)
+            .collect();
+        let rows: Vec<HashMap<ExpertId, f32>> = logits
+            .chunks(count as usize)
+            .map(|row| {
+                index_map
+                    .ids()
+                    .iter()
+                    .cloned()
+                    .zip(row.iter().copied())
+                    .collect()
+            })
+            .collect();
+
+        group.bench_with_input(BenchmarkId::new("hashmap", count), &rows, |b, rows| {
+            let mut router = GatingRouter::new_unchecked(1.0);
+            b.iter(|| {
+                for (&token, row) in tokens.iter().zip(rows) {
+                    router.set_gate_weights(row.clone());
+                    black_box(router.route(Tier::Pro, token));
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("dense", count), &logits, |b, logits| {
+            let router = GatingRouter::new_unchecked(1.0);
+            let mut scratch = GateScratch::with_capacity(count as usize);
+            b.iter(|| {
+                router.route_logits_batch(
+                    Tier::Pro,
+                    &tokens,
+                    &index_map,
+                    black_box(logits),
+                    &mut scratch,
+                )
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_route_with_weights, bench_gating_batch);
+criterion_main!(benches);
@@ -0,0 +1,239 @@
+// File: adaptive.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     SLO-aware tier downgrade for AURIA Runtime Core.
+//     AdaptiveTierRouter serves requests at a lower effective tier (and so a
+//     smaller k) when queue pressure is high or a request's latency budget
+//     cannot be met at its own tier, and counts every downgrade it makes.
+//
+use crate::{
+    Router, RouterState, RoutingContext, RoutingExplanation, RoutingOutcome, StatefulRouter,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+const TIERS: [Tier; 4] = [Tier::Nano, Tier::Standard, Tier::Pro, Tier::Max];
+
+fn tier_rank(tier: Tier) -> usize {
+    match tier {
+        Tier::Nano => 0,
+        Tier::Standard => 1,
+        Tier::Pro => 2,
+        Tier::Max => 3,
+    }
+}
+
+pub struct AdaptiveTierRouter<R: Router> {
+    inner: R,
+    // Pressure at or above `downgrade_at[i]` drops a request by i + 1 tiers.
+    downgrade_at: [f32; 3],
+    pressure: AtomicU32,
+    tier_latency: Mutex<HashMap<Tier, Duration>>,
+    downgrades: Mutex<HashMap<(Tier, Tier), u64>>,
+}
+
+impl<R: Router> AdaptiveTierRouter<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            downgrade_at: [0.7, 0.85, 0.95],
+            pressure: AtomicU32::new(0f32.to_bits()),
+            tier_latency: Mutex::new(HashMap::new()),
+            downgrades: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn with_thresholds(mut self, one_tier: f32, two_tiers: f32, three_tiers: f32) -> Self {
+        self.downgrade_at = [
+            one_tier,
+            two_tiers.max(one_tier),
+            three_tiers.max(two_tiers),
+        ];
+        self
+    }
+
+    // Queue pressure in [0, 1], e.g. queue depth over queue capacity.
+    pub fn set_queue_pressure(&self, pressure: f32) {
+        let pressure = if pressure.is_nan() {
+            0.0
+        } else {
+            pressure.clamp(0.0, 1.0)
+        };
+        self.pressure.store(pressure.to_bits(), Ordering::Relaxed);
+    }
+
+    pub fn queue_pressure(&self) -> f32 {
+        f32::from_bits(self.pressure.load(Ordering::Relaxed))
+    }
+
+    // Expected end-to-end latency of a step served at `tier`.
+    pub fn set_tier_latency(&self, tier: Tier, latency: Duration) {
+        self.tier_latency.lock().unwrap().insert(tier, latency);
+    }
+
+    pub fn downgrades(&self) -> HashMap<(Tier, Tier), u64> {
+        self.downgrades.lock().unwrap().clone()
+    }
+
+    pub fn downgrade_count(&self) -> u64 {
+        self.downgrades.lock().unwrap().values().sum()
+    }
+
+    pub fn effective_tier(&self, tier: Tier) -> Tier {
+        let pressure = self.queue_pressure();
+        let steps = self.downgrade_at.iter().filter(|&&t| pressure >= t).count();
+        TIERS[tier_rank(tier).saturating_sub(steps)]
+    }
+
+    // Highest tier not above the pressure-adjusted one whose expected
+    // latency fits `budget`; tiers without an estimate are assumed to fit.
+    // Falls back to Nano when nothing fits.
+    pub fn budget_tier(&self, tier: Tier, budget: Duration) -> Tier {
+        let latency = self.tier_latency.lock().unwrap();
+        let ceiling = tier_rank(self.effective_tier(tier));
+        TIERS[..=ceiling]
+            .iter()
+            .rev()
+            .find(|t| latency.get(t).is_none_or(|l| *l <= budget))
+            .copied()
+            .unwrap_or(Tier::Nano)
+    }
+
+    pub fn route_with_budget(&self, ctx: &RoutingContext, budget: Duration) -> RoutingOutcome {
+        let effective = self.budget_tier(ctx.tier, budget);
+        self.route_at(ctx, effective)
+    }
+
+    fn route_at(&self, ctx: &RoutingContext, effective: Tier) -> RoutingOutcome {
+        if effective == ctx.tier {
+            return self.inner.route_outcome(ctx);
+        }
+        self.record_downgrade(ctx.tier, effective);
+        let mut lowered = ctx.clone();
+        lowered.tier = effective;
+        let mut outcome = self.inner.route_outcome(&lowered);
+        outcome.downgraded_from = Some(ctx.tier);
+        outcome
+    }
+
+    fn record_downgrade(&self, from: Tier, to: Tier) {
+        *self
+            .downgrades
+            .lock()
+            .unwrap()
+            .entry((from, to))
+            .or_insert(0) += 1;
+    }
+}
+
+impl<R: Router> Router for AdaptiveTierRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.route_outcome(&RoutingContext::new(tier, token_index))
+            .decision
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        let effective = self.effective_tier(tier);
+        if effective != tier {
+            self.record_downgrade(tier, effective);
+        }
+        self.inner
+            .route_with_weights(effective, token_index, weights)
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.route_outcome(ctx).decision
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        self.route_at(ctx, self.effective_tier(ctx.tier))
+    }
+
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        let effective = self.effective_tier(ctx.tier);
+        if effective == ctx.tier {
+            return self.inner.explain(ctx);
+        }
+        self.record_downgrade(ctx.tier, effective);
+        let mut lowered = ctx.clone();
+        lowered.tier = effective;
+        let mut explanation = self.inner.explain(&lowered);
+        explanation.tier = ctx.tier;
+        explanation.outcome.downgraded_from = Some(ctx.tier);
+        explanation
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for AdaptiveTierRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.inner.begin_sequence(seq_id)
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.inner.end_sequence(seq_id)
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.inner.active_sequences()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DeterministicRouter;
+
+    #[test]
+    fn test_pressure_downgrades_tier() {
+        let router = AdaptiveTierRouter::new(DeterministicRouter::new_unchecked(64));
+        assert_eq!(router.route(Tier::Pro, 0).expert_ids.len(), 8);
+
+        router.set_queue_pressure(0.8);
+        let outcome = router.route_outcome(&RoutingContext::new(Tier::Pro, 0));
+        assert_eq!(outcome.decision.expert_ids.len(), 4);
+        assert_eq!(outcome.downgraded_from, Some(Tier::Pro));
+        assert_eq!(outcome.requested_k, 4);
+
+        router.set_queue_pressure(1.0);
+        assert_eq!(router.route(Tier::Nano, 0).expert_ids.len(), 2);
+        assert_eq!(router.effective_tier(Tier::Max), Tier::Nano);
+        assert_eq!(router.downgrades()[&(Tier::Pro, Tier::Standard)], 1);
+        assert_eq!(router.downgrade_count(), 1);
+    }
+
+    #[test]
+    fn test_latency_budget_picks_fitting_tier() {
+        let router = AdaptiveTierRouter::new(DeterministicRouter::new_unchecked(64));
+        router.set_tier_latency(Tier::Max, Duration::from_millis(40));
+        router.set_tier_latency(Tier::Pro, Duration::from_millis(25));
+        router.set_tier_latency(Tier::Standard, Duration::from_millis(12));
+
+        let ctx = RoutingContext::new(Tier::Max, 0);
+        let outcome = router.route_with_budget(&ctx, Duration::from_millis(20));
+        assert_eq!(outcome.downgraded_from, Some(Tier::Max));
+        assert_eq!(outcome.decision.expert_ids.len(), 4);
+
+        let outcome = router.route_with_budget(&ctx, Duration::from_millis(50));
+        assert_eq!(outcome.downgraded_from, None);
+        assert_eq!(
+            router.budget_tier(Tier::Pro, Duration::from_millis(1)),
+            Tier::Nano
+        );
+    }
+}
@@ -0,0 +1,406 @@
+// File: affinity.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Storage backends for sticky-session tables in AURIA Runtime Core.
+//     StickyRouter keeps its sequence -> layer -> experts table in a
+//     StickyStore: in process memory by default, in a journal file so
+//     affinity survives restarts, or in any user implementation (e.g. one
+//     backed by Redis) so replicas behind a load balancer share it.
+//
+use crate::audit::{hex_ids, tier_code};
+use crate::{decision_from_entries, RoutingOutcome, StoreError};
+use auria_core::{ExpertId, Tier};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+// A sticky decision as stored. Drops and masks of the original route are
+// not kept; a reused decision reports only its experts.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StickyRecord {
+    pub tier: u8,
+    pub requested_k: u32,
+    pub shared_count: u32,
+    #[serde(with = "hex_ids")]
+    pub expert_ids: Vec<[u8; 32]>,
+    pub confidence_scores: Vec<f32>,
+    pub gating_weights: Vec<f32>,
+    pub timestamp: u64,
+    pub uses: u64,
+}
+
+impl StickyRecord {
+    pub fn from_outcome(tier: Tier, outcome: &RoutingOutcome) -> Self {
+        let decision = &outcome.decision;
+        Self {
+            tier: tier_code(tier),
+            requested_k: outcome.requested_k,
+            shared_count: outcome.shared_count,
+            expert_ids: decision.expert_ids.iter().map(|id| id.0).collect(),
+            confidence_scores: decision.confidence_scores.clone(),
+            gating_weights: decision.gating_weights.clone(),
+            timestamp: decision.timestamp,
+            uses: 0,
+        }
+    }
+
+    pub fn to_outcome(&self) -> RoutingOutcome {
+        let entries = self
+            .expert_ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| {
+                (
+                    ExpertId(*id),
+                    self.confidence_scores.get(i).copied().unwrap_or(0.0),
+                    self.gating_weights.get(i).copied().unwrap_or(0.0),
+                )
+            })
+            .collect();
+        let mut outcome = RoutingOutcome::new(
+            decision_from_entries(entries, self.timestamp),
+            self.requested_k,
+        );
+        outcome.shared_count = self.shared_count;
+        outcome
+    }
+
+    pub fn is_tier(&self, tier: Tier) -> bool {
+        self.tier == tier_code(tier)
+    }
+
+    pub fn experts(&self) -> Vec<ExpertId> {
+        self.expert_ids.iter().map(|id| ExpertId(*id)).collect()
+    }
+}
+
+// Records are kept only for live sequences: `put` for a sequence that was
+// never begun, or has ended, is ignored.
+pub trait StickyStore: Send + Sync {
+    fn begin(&self, seq_id: u64) -> Result<(), StoreError>;
+    fn end(&self, seq_id: u64) -> Result<(), StoreError>;
+    fn get(&self, seq_id: u64, layer: u32) -> Result<Option<StickyRecord>, StoreError>;
+    fn put(&self, seq_id: u64, layer: u32, record: StickyRecord) -> Result<(), StoreError>;
+    fn live_sequences(&self) -> Result<usize, StoreError>;
+}
+
+impl<S: StickyStore + ?Sized> StickyStore for Arc<S> {
+    fn begin(&self, seq_id: u64) -> Result<(), StoreError> {
+        (**self).begin(seq_id)
+    }
+
+    fn end(&self, seq_id: u64) -> Result<(), StoreError> {
+        (**self).end(seq_id)
+    }
+
+    fn get(&self, seq_id: u64, layer: u32) -> Result<Option<StickyRecord>, StoreError> {
+        (**self).get(seq_id, layer)
+    }
+
+    fn put(&self, seq_id: u64, layer: u32, record: StickyRecord) -> Result<(), StoreError> {
+        (**self).put(seq_id, layer, record)
+    }
+
+    fn live_sequences(&self) -> Result<usize, StoreError> {
+        (**self).live_sequences()
+    }
+}
+
+type Table = HashMap<u64, HashMap<u32, StickyRecord>>;
+
+#[derive(Default)]
+pub struct MemoryStickyStore {
+    sequences: Mutex<Table>,
+}
+
+impl MemoryStickyStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl StickyStore for MemoryStickyStore {
+    fn begin(&self, seq_id: u64) -> Result<(), StoreError> {
+        self.sequences
+            .lock()
+            .unwrap()
+            .insert(seq_id, HashMap::new());
+        Ok(())
+    }
+
+    fn end(&self, seq_id: u64) -> Result<(), StoreError> {
+        self.sequences.lock().unwrap().remove(&seq_id);
+        Ok(())
+    }
+
+    fn get(&self, seq_id: u64, layer: u32) -> Result<Option<StickyRecord>, StoreError> {
+        let sequences = self.sequences.lock().unwrap();
+        Ok(sequences.get(&seq_id).and_then(|l| l.get(&layer)).cloned())
+    }
+
+    fn put(&self, seq_id: u64, layer: u32, record: StickyRecord) -> Result<(), StoreError> {
+        if let Some(layers) = self.sequences.lock().unwrap().get_mut(&seq_id) {
+            layers.insert(layer, record);
+        }
+        Ok(())
+    }
+
+    fn live_sequences(&self) -> Result<usize, StoreError> {
+        Ok(self.sequences.lock().unwrap().len())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum JournalEntry {
+    Begin {
+        seq: u64,
+    },
+    End {
+        seq: u64,
+    },
+    Put {
+        seq: u64,
+        layer: u32,
+        record: StickyRecord,
+    },
+}
+
+struct Journal {
+    table: Table,
+    file: File,
+    lines: usize,
+}
+
+// The table lives in memory and every change is appended to a JSON-lines
+// journal, which is replayed and compacted on open. It survives restarts
+// of one process; replicas sharing affinity need a networked store.
+pub struct FileStickyStore {
+    path: PathBuf,
+    journal: Mutex<Journal>,
+}
+
+impl FileStickyStore {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, StoreError> {
+        let path = path.as_ref().to_path_buf();
+        let mut table = Table::new();
+        if path.exists() {
+            let reader = BufReader::new(File::open(&path).map_err(io_error)?);
+            for (i, line) in reader.lines().enumerate() {
+                let line = line.map_err(io_error)?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let entry = serde_json::from_str(&line).map_err(|e| StoreError::Corrupt {
+                    line: i + 1,
+                    message: e.to_string(),
+                })?;
+                apply(&mut table, entry);
+            }
+        }
+        let (file, lines) = write_compacted(&path, &table)?;
+        Ok(Self {
+            path,
+            journal: Mutex::new(Journal { table, file, lines }),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    // Rewrites the journal to hold only live records.
+    pub fn compact(&self) -> Result<(), StoreError> {
+        let mut journal = self.journal.lock().unwrap();
+        let (file, lines) = write_compacted(&self.path, &journal.table)?;
+        journal.file = file;
+        journal.lines = lines;
+        Ok(())
+    }
+
+    fn append(&self, entry: JournalEntry) -> Result<(), StoreError> {
+        let mut journal = self.journal.lock().unwrap();
+        if let JournalEntry::Put { seq, .. } = &entry {
+            if !journal.table.contains_key(seq) {
+                return Ok(());
+            }
+        }
+        let mut line = serde_json::to_string(&entry).map_err(io_error)?;
+        line.push('\n');
+        journal.file.write_all(line.as_bytes()).map_err(io_error)?;
+        journal.lines += 1;
+        apply(&mut journal.table, entry);
+        let live: usize = journal.table.values().map(|l| l.len() + 1).sum();
+        if journal.lines > 4 * live + 1024 {
+            let (file, lines) = write_compacted(&self.path, &journal.table)?;
+            journal.file = file;
+            journal.lines = lines;
+        }
+        Ok(())
+    }
+}
+
+impl StickyStore for FileStickyStore {
+    fn begin(&self, seq_id: u64) -> Result<(), StoreError> {
+        self.append(JournalEntry::Begin { seq: seq_id })
+    }
+
+    fn end(&self, seq_id: u64) -> Result<(), StoreError> {
+        self.append(JournalEntry::End { seq: seq_id })
+    }
+
+    fn get(&self, seq_id: u64, layer: u32) -> Result<Option<StickyRecord>, StoreError> {
+        let journal = self.journal.lock().unwrap();
+        Ok(journal
+            .table
+            .get(&seq_id)
+            .and_then(|l| l.get(&layer))
+            .cloned())
+    }
+
+    fn put(&self, seq_id: u64, layer: u32, record: StickyRecord) -> Result<(), StoreError> {
+        self.append(JournalEntry::Put {
+            seq: seq_id,
+            layer,
+            record,
+        })
+    }
+
+    fn live_sequences(&self) -> Result<usize, StoreError> {
+        Ok(self.journal.lock().unwrap().table.len())
+    }
+}
+
+fn apply(table: &mut Table, entry: JournalEntry) {
+    match entry {
+        JournalEntry::Begin { seq } => {
+            table.insert(seq, HashMap::new());
+        }
+        JournalEntry::End { seq } => {
+            table.remove(&seq);
+        }
+        JournalEntry::Put { seq, layer, record } => {
+            if let Some(layers) = table.get_mut(&seq) {
+                layers.insert(layer, record);
+            }
+        }
+    }
+}
+
+// Writes the table to a sibling file and renames it over the journal, so a
+// crash mid-compaction leaves the previous journal intact.
+fn write_compacted(path: &Path, table: &Table) -> Result<(File, usize), StoreError> {
+    let tmp = path.with_extension("compact");
+    let mut out = String::new();
+    let mut lines = 0;
+    for (seq, layers) in table {
+        let mut entries = vec![JournalEntry::Begin { seq: *seq }];
+        entries.extend(layers.iter().map(|(layer, record)| JournalEntry::Put {
+            seq: *seq,
+            layer: *layer,
+            record: record.clone(),
+        }));
+        for entry in entries {
+            out.push_str(&serde_json::to_string(&entry).map_err(io_error)?);
+            out.push('\n');
+            lines += 1;
+        }
+    }
+    fs::write(&tmp, out).map_err(io_error)?;
+    fs::rename(&tmp, path).map_err(io_error)?;
+    let file = OpenOptions::new()
+        .append(true)
+        .open(path)
+        .map_err(io_error)?;
+    Ok((file, lines))
+}
+
+fn io_error(err: impl std::fmt::Display) -> StoreError {
+    StoreError::Io(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        index_expert_id, DeterministicRouter, Router, RoutingContext, StatefulRouter, StickyRouter,
+    };
+
+    #[test]
+    fn test_file_store_survives_restart() {
+        let path =
+            std::env::temp_dir().join(format!("auria-router-sticky-{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let at = |token| RoutingContext::new(Tier::Nano, token).with_sequence_id(3);
+
+        let router = StickyRouter::new(DeterministicRouter::new_unchecked(64))
+            .with_store(FileStickyStore::open(&path).unwrap());
+        router.begin_sequence(3);
+        router.begin_sequence(4);
+        router.end_sequence(4);
+        let first = router.route_with_context(&at(10)).expert_ids;
+        drop(router);
+
+        // A restarted process resumes the sequence without begin_sequence.
+        let router = StickyRouter::new(DeterministicRouter::new_unchecked(64))
+            .with_store(FileStickyStore::open(&path).unwrap());
+        assert_eq!(router.active_sequences(), 1);
+        assert_eq!(router.route_with_context(&at(20)).expert_ids, first);
+        router.end_sequence(3);
+        drop(router);
+
+        let store = FileStickyStore::open(&path).unwrap();
+        assert_eq!(store.live_sequences(), Ok(0));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "");
+        let _ = fs::remove_file(&path);
+    }
+
+    struct Down;
+
+    impl StickyStore for Down {
+        fn begin(&self, _seq_id: u64) -> Result<(), StoreError> {
+            Err(StoreError::Unavailable("connection refused".to_string()))
+        }
+
+        fn end(&self, seq_id: u64) -> Result<(), StoreError> {
+            self.begin(seq_id)
+        }
+
+        fn get(&self, seq_id: u64, _layer: u32) -> Result<Option<StickyRecord>, StoreError> {
+            self.begin(seq_id).map(|_| None)
+        }
+
+        fn put(&self, seq_id: u64, _layer: u32, _record: StickyRecord) -> Result<(), StoreError> {
+            self.begin(seq_id)
+        }
+
+        fn live_sequences(&self) -> Result<usize, StoreError> {
+            self.begin(0).map(|_| 0)
+        }
+    }
+
+    #[test]
+    fn test_shared_and_failing_stores() {
+        let shared = Arc::new(MemoryStickyStore::new());
+        let a =
+            StickyRouter::new(DeterministicRouter::new_unchecked(64)).with_store(shared.clone());
+        let b = StickyRouter::new(DeterministicRouter::new_unchecked(64)).with_store(shared);
+        let at = |token| RoutingContext::new(Tier::Nano, token).with_sequence_id(9);
+        a.begin_sequence(9);
+        let first = a.route_with_context(&at(1)).expert_ids;
+        assert_eq!(b.route_with_context(&at(2)).expert_ids, first);
+        assert_eq!(b.sticky_experts(9, 0).unwrap(), first);
+
+        // An unreachable store degrades to stateless routing.
+        let router = StickyRouter::new(DeterministicRouter::new_unchecked(64)).with_store(Down);
+        router.begin_sequence(9);
+        assert_eq!(
+            router.route_with_context(&at(5)).expert_ids[0],
+            index_expert_id(5)
+        );
+        assert_eq!(router.store_errors(), 2);
+    }
+}
@@ -0,0 +1,167 @@
+// File: aggregate.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Micro-batch decision deduplication for AURIA Runtime Core.
+//     An ExpertSetAggregator groups the tokens of a micro-batch by the set
+//     of experts they were routed to, so the runtime can launch one kernel
+//     per unique expert combination instead of one per token.
+//
+use auria_core::{ExpertId, RoutingDecision};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExpertSetGroup {
+    // Canonical order: sorted by id bytes unless the aggregator is order
+    // sensitive, in which case it is the decision's own order.
+    pub expert_ids: Vec<ExpertId>,
+    pub token_indices: Vec<u64>,
+    // Batch positions, aligned with `token_indices`.
+    pub positions: Vec<usize>,
+    // One row per token, aligned with `expert_ids`.
+    pub gating_weights: Vec<Vec<f32>>,
+}
+
+impl ExpertSetGroup {
+    pub fn len(&self) -> usize {
+        self.token_indices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.token_indices.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ExpertSetAggregator {
+    order_sensitive: bool,
+    groups: Vec<ExpertSetGroup>,
+    index: HashMap<Vec<ExpertId>, usize>,
+    tokens: usize,
+}
+
+impl ExpertSetAggregator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Treat [a, b] and [b, a] as different groups, for kernels whose
+    // expert slots are positional.
+    pub fn with_order_sensitive(mut self, order_sensitive: bool) -> Self {
+        self.order_sensitive = order_sensitive;
+        self
+    }
+
+    pub fn push(&mut self, token_index: u64, decision: &RoutingDecision) {
+        let position = self.tokens;
+        self.tokens += 1;
+
+        let mut entries: Vec<(&ExpertId, f32)> = decision
+            .expert_ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (id, decision.gating_weights.get(i).copied().unwrap_or(1.0)))
+            .collect();
+        if !self.order_sensitive {
+            entries.sort_by_key(|(id, _)| id.0);
+        }
+        let key: Vec<ExpertId> = entries.iter().map(|(id, _)| (*id).clone()).collect();
+        let weights = entries.into_iter().map(|(_, w)| w).collect();
+
+        let slot = match self.index.get(&key) {
+            Some(&slot) => slot,
+            None => {
+                self.groups.push(ExpertSetGroup {
+                    expert_ids: key.clone(),
+                    token_indices: Vec::new(),
+                    positions: Vec::new(),
+                    gating_weights: Vec::new(),
+                });
+                self.index.insert(key, self.groups.len() - 1);
+                self.groups.len() - 1
+            }
+        };
+        let group = &mut self.groups[slot];
+        group.token_indices.push(token_index);
+        group.positions.push(position);
+        group.gating_weights.push(weights);
+    }
+
+    pub fn extend<'a>(&mut self, decisions: impl IntoIterator<Item = (u64, &'a RoutingDecision)>) {
+        for (token_index, decision) in decisions {
+            self.push(token_index, decision);
+        }
+    }
+
+    pub fn tokens(&self) -> usize {
+        self.tokens
+    }
+
+    pub fn unique_sets(&self) -> usize {
+        self.groups.len()
+    }
+
+    // Fraction of tokens that share a group with an earlier token.
+    pub fn dedup_ratio(&self) -> f32 {
+        if self.tokens == 0 {
+            return 0.0;
+        }
+        1.0 - self.groups.len() as f32 / self.tokens as f32
+    }
+
+    // Groups in order of first appearance.
+    pub fn finish(self) -> Vec<ExpertSetGroup> {
+        self.groups
+    }
+
+    pub fn group(
+        mut self,
+        token_indices: &[u64],
+        decisions: &[RoutingDecision],
+    ) -> Vec<ExpertSetGroup> {
+        self.extend(token_indices.iter().copied().zip(decisions));
+        self.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter, Router};
+    use auria_core::Tier;
+
+    #[test]
+    fn test_groups_tokens_by_expert_set() {
+        let router = DeterministicRouter::new_unchecked(4);
+        let tokens: Vec<u64> = (0..12).collect();
+        let decisions: Vec<RoutingDecision> = tokens
+            .iter()
+            .map(|&t| router.route(Tier::Nano, t))
+            .collect();
+
+        let mut aggregator = ExpertSetAggregator::new();
+        aggregator.extend(tokens.iter().copied().zip(&decisions));
+        assert_eq!(aggregator.unique_sets(), 4);
+        assert!((aggregator.dedup_ratio() - 2.0 / 3.0).abs() < 1e-6);
+
+        let groups = aggregator.finish();
+        assert_eq!(
+            groups[0].expert_ids,
+            vec![index_expert_id(0), index_expert_id(1)]
+        );
+        assert_eq!(groups[0].token_indices, vec![0, 4, 8]);
+        // Token 3 picks [3, 0]; canonical order puts expert 0 first.
+        assert_eq!(
+            groups[3].expert_ids,
+            vec![index_expert_id(0), index_expert_id(3)]
+        );
+        assert_eq!(groups.iter().map(ExpertSetGroup::len).sum::<usize>(), 12);
+
+        let ordered = ExpertSetAggregator::new()
+            .with_order_sensitive(true)
+            .group(&tokens, &decisions);
+        assert_eq!(
+            ordered[3].expert_ids,
+            vec![index_expert_id(3), index_expert_id(0)]
+        );
+    }
+}
@@ -0,0 +1,585 @@
+// File: audit.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Routing audit log for AURIA Runtime Core.
+//     Buffers routing decisions on a background writer thread and flushes
+//     them to JSONL or a compact length-prefixed binary format, with file
+//     rotation and sampling rate controls.
+//
+use crate::{Router, RouterState, RoutingContext, RoutingOutcome, StatefulRouter};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuditFormat {
+    Jsonl,
+    Binary,
+}
+
+#[derive(Clone, Debug)]
+pub struct AuditConfig {
+    pub path: PathBuf,
+    pub format: AuditFormat,
+    pub batch_size: usize,
+    pub max_file_bytes: u64,
+    pub max_files: usize,
+    pub sample_rate: f64,
+}
+
+impl AuditConfig {
+    pub fn new(path: impl Into<PathBuf>, format: AuditFormat) -> Self {
+        Self {
+            path: path.into(),
+            format,
+            batch_size: 256,
+            max_file_bytes: 64 * 1024 * 1024,
+            max_files: 8,
+            sample_rate: 1.0,
+        }
+    }
+
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    pub fn with_rotation(mut self, max_file_bytes: u64, max_files: usize) -> Self {
+        self.max_file_bytes = max_file_bytes.max(1);
+        self.max_files = max_files;
+        self
+    }
+
+    pub fn with_sample_rate(mut self, sample_rate: f64) -> Self {
+        self.sample_rate = sample_rate.clamp(0.0, 1.0);
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    pub timestamp: u64,
+    pub tier: u8,
+    pub token_index: u64,
+    #[serde(with = "hex_ids")]
+    pub expert_ids: Vec<[u8; 32]>,
+    pub gating_weights: Vec<f32>,
+}
+
+impl AuditRecord {
+    pub fn from_decision(tier: Tier, token_index: u64, decision: &RoutingDecision) -> Self {
+        Self {
+            timestamp: decision.timestamp,
+            tier: tier_code(tier),
+            token_index,
+            expert_ids: decision.expert_ids.iter().map(|id| id.0).collect(),
+            gating_weights: decision.gating_weights.clone(),
+        }
+    }
+
+    pub fn tier(&self) -> Option<Tier> {
+        match self.tier {
+            0 => Some(Tier::Nano),
+            1 => Some(Tier::Standard),
+            2 => Some(Tier::Pro),
+            3 => Some(Tier::Max),
+            _ => None,
+        }
+    }
+
+    // Layout: timestamp u64 | tier u8 | token_index u64 | count u16 |
+    // count * (id [u8; 32] | weight f32), all little-endian.
+    fn encode_binary(&self) -> Vec<u8> {
+        let count = self.expert_ids.len().min(u16::MAX as usize);
+        let mut buf = Vec::with_capacity(19 + count * 36);
+        buf.extend_from_slice(&self.timestamp.to_le_bytes());
+        buf.push(self.tier);
+        buf.extend_from_slice(&self.token_index.to_le_bytes());
+        buf.extend_from_slice(&(count as u16).to_le_bytes());
+        for i in 0..count {
+            buf.extend_from_slice(&self.expert_ids[i]);
+            let weight = self.gating_weights.get(i).copied().unwrap_or(0.0);
+            buf.extend_from_slice(&weight.to_le_bytes());
+        }
+        buf
+    }
+
+    fn decode_binary(buf: &[u8]) -> io::Result<Self> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "truncated audit record");
+        if buf.len() < 19 {
+            return Err(invalid());
+        }
+        let timestamp = u64::from_le_bytes(buf[0..8].try_into().unwrap());
+        let tier = buf[8];
+        let token_index = u64::from_le_bytes(buf[9..17].try_into().unwrap());
+        let count = u16::from_le_bytes(buf[17..19].try_into().unwrap()) as usize;
+        if buf.len() != 19 + count * 36 {
+            return Err(invalid());
+        }
+
+        let mut expert_ids = Vec::with_capacity(count);
+        let mut gating_weights = Vec::with_capacity(count);
+        for entry in buf[19..].chunks_exact(36) {
+            expert_ids.push(entry[0..32].try_into().unwrap());
+            gating_weights.push(f32::from_le_bytes(entry[32..36].try_into().unwrap()));
+        }
+
+        Ok(Self {
+            timestamp,
+            tier,
+            token_index,
+            expert_ids,
+            gating_weights,
+        })
+    }
+}
+
+pub(crate) fn tier_code(tier: Tier) -> u8 {
+    match tier {
+        Tier::Nano => 0,
+        Tier::Standard => 1,
+        Tier::Pro => 2,
+        Tier::Max => 3,
+    }
+}
+
+pub(crate) mod hex_ids {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(ids: &[[u8; 32]], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            ids.iter()
+                .map(|id| id.iter().map(|b| format!("{:02x}", b)).collect::<String>()),
+        )
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<[u8; 32]>, D::Error> {
+        let strings = Vec::<String>::deserialize(deserializer)?;
+        strings
+            .iter()
+            .map(|s| {
+                if s.len() != 64 {
+                    return Err(serde::de::Error::custom("expert id must be 64 hex chars"));
+                }
+                let mut id = [0u8; 32];
+                for (i, byte) in id.iter_mut().enumerate() {
+                    *byte = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16)
+                        .map_err(serde::de::Error::custom)?;
+                }
+                Ok(id)
+            })
+            .collect()
+    }
+}
+
+enum AuditMessage {
+    Record(AuditRecord),
+    Flush(mpsc::Sender<io::Result<()>>),
+}
+
+pub struct AuditLog {
+    sender: Mutex<mpsc::Sender<AuditMessage>>,
+    worker: Option<JoinHandle<io::Result<()>>>,
+    sample_rate: f64,
+    write_errors: Arc<AtomicU64>,
+}
+
+impl AuditLog {
+    pub fn open(config: AuditConfig) -> io::Result<Self> {
+        let sample_rate = config.sample_rate;
+        let write_errors = Arc::new(AtomicU64::new(0));
+        let writer = AuditWriter::open(config, write_errors.clone())?;
+        let (sender, receiver) = mpsc::channel();
+        let worker = std::thread::Builder::new()
+            .name("auria-router-audit".into())
+            .spawn(move || writer.run(receiver))?;
+
+        Ok(Self {
+            sender: Mutex::new(sender),
+            worker: Some(worker),
+            sample_rate,
+            write_errors,
+        })
+    }
+
+    // Records lost to I/O errors (full disk, failed rotation). The writer
+    // keeps running; the first error since the last flush is returned by
+    // the next flush.
+    pub fn write_errors(&self) -> u64 {
+        self.write_errors.load(Ordering::Relaxed)
+    }
+
+    pub fn record(&self, tier: Tier, token_index: u64, decision: &RoutingDecision) {
+        if self.sample_rate < 1.0 && rand::random::<f64>() >= self.sample_rate {
+            return;
+        }
+        let record = AuditRecord::from_decision(tier, token_index, decision);
+        let _ = self
+            .sender
+            .lock()
+            .unwrap()
+            .send(AuditMessage::Record(record));
+    }
+
+    pub fn flush(&self) -> io::Result<()> {
+        let (ack_tx, ack_rx) = mpsc::channel();
+        let sent = self
+            .sender
+            .lock()
+            .unwrap()
+            .send(AuditMessage::Flush(ack_tx));
+        if sent.is_err() {
+            return Err(io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "audit writer stopped",
+            ));
+        }
+        ack_rx.recv().unwrap_or_else(|_| {
+            Err(io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "audit writer stopped",
+            ))
+        })
+    }
+
+    pub fn close(mut self) -> io::Result<()> {
+        self.shutdown()
+    }
+
+    fn shutdown(&mut self) -> io::Result<()> {
+        // Replacing the sender drops the original and disconnects the worker.
+        let (dangling, _) = mpsc::channel();
+        drop(std::mem::replace(
+            &mut *self.sender.lock().unwrap(),
+            dangling,
+        ));
+        match self.worker.take() {
+            Some(worker) => worker
+                .join()
+                .unwrap_or_else(|_| Err(io::Error::other("audit writer panicked"))),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for AuditLog {
+    fn drop(&mut self) {
+        let _ = self.shutdown();
+    }
+}
+
+struct AuditWriter {
+    config: AuditConfig,
+    out: BufWriter<File>,
+    written: u64,
+    pending: usize,
+    errors: Arc<AtomicU64>,
+    first_error: Option<io::Error>,
+}
+
+impl AuditWriter {
+    fn open(config: AuditConfig, errors: Arc<AtomicU64>) -> io::Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&config.path)?;
+        let written = file.metadata()?.len();
+        Ok(Self {
+            config,
+            out: BufWriter::new(file),
+            written,
+            pending: 0,
+            errors,
+            first_error: None,
+        })
+    }
+
+    fn run(mut self, receiver: mpsc::Receiver<AuditMessage>) -> io::Result<()> {
+        for message in receiver {
+            match message {
+                AuditMessage::Record(record) => {
+                    if let Err(e) = self.write(&record) {
+                        self.errors.fetch_add(1, Ordering::Relaxed);
+                        self.first_error.get_or_insert(e);
+                    }
+                }
+                AuditMessage::Flush(ack) => {
+                    let _ = ack.send(self.report());
+                }
+            }
+        }
+        self.report()
+    }
+
+    fn report(&mut self) -> io::Result<()> {
+        let flushed = self.flush();
+        match self.first_error.take() {
+            Some(e) => Err(e),
+            None => flushed,
+        }
+    }
+
+    fn write(&mut self, record: &AuditRecord) -> io::Result<()> {
+        let bytes = match self.config.format {
+            AuditFormat::Jsonl => {
+                let mut line = serde_json::to_vec(record).map_err(io::Error::from)?;
+                line.push(b'\n');
+                line
+            }
+            AuditFormat::Binary => {
+                let payload = record.encode_binary();
+                let mut framed = Vec::with_capacity(4 + payload.len());
+                framed.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+                framed.extend_from_slice(&payload);
+                framed
+            }
+        };
+
+        if self.written > 0 && self.written + bytes.len() as u64 > self.config.max_file_bytes {
+            self.rotate()?;
+        }
+
+        self.out.write_all(&bytes)?;
+        self.written += bytes.len() as u64;
+        self.pending += 1;
+        if self.pending >= self.config.batch_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.pending = 0;
+        self.out.flush()
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.flush()?;
+        let path = &self.config.path;
+        if self.config.max_files == 0 {
+            fs::remove_file(path)?;
+        } else {
+            let _ = fs::remove_file(rotated_path(path, self.config.max_files));
+            for i in (1..self.config.max_files).rev() {
+                let from = rotated_path(path, i);
+                if from.exists() {
+                    fs::rename(&from, rotated_path(path, i + 1))?;
+                }
+            }
+            fs::rename(path, rotated_path(path, 1))?;
+        }
+
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        self.out = BufWriter::new(file);
+        self.written = 0;
+        Ok(())
+    }
+}
+
+fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{}", index));
+    PathBuf::from(name)
+}
+
+pub fn read_jsonl<R: Read>(reader: R) -> io::Result<Vec<AuditRecord>> {
+    let stream = serde_json::Deserializer::from_reader(reader).into_iter::<AuditRecord>();
+    stream.map(|r| r.map_err(io::Error::from)).collect()
+}
+
+pub fn read_binary<R: Read>(mut reader: R) -> io::Result<Vec<AuditRecord>> {
+    let mut records = Vec::new();
+    let mut len_buf = [0u8; 4];
+    loop {
+        match reader.read_exact(&mut len_buf) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => break,
+            Err(e) => return Err(e),
+        }
+        let mut payload = vec![0u8; u32::from_le_bytes(len_buf) as usize];
+        reader.read_exact(&mut payload)?;
+        records.push(AuditRecord::decode_binary(&payload)?);
+    }
+    Ok(records)
+}
+
+pub struct AuditedRouter<R: Router> {
+    inner: R,
+    log: AuditLog,
+}
+
+impl<R: Router> AuditedRouter<R> {
+    pub fn new(inner: R, log: AuditLog) -> Self {
+        Self { inner, log }
+    }
+
+    pub fn log(&self) -> &AuditLog {
+        &self.log
+    }
+
+    pub fn into_parts(self) -> (R, AuditLog) {
+        (self.inner, self.log)
+    }
+}
+
+impl<R: Router> Router for AuditedRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        let decision = self.inner.route(tier, token_index);
+        self.log.record(tier, token_index, &decision);
+        decision
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        let decision = self.inner.route_with_weights(tier, token_index, weights);
+        self.log.record(tier, token_index, &decision);
+        decision
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        let decision = self.inner.route_with_context(ctx);
+        self.log.record(ctx.tier, ctx.token_index, &decision);
+        decision
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        let outcome = self.inner.route_outcome(ctx);
+        self.log
+            .record(ctx.tier, ctx.token_index, &outcome.decision);
+        outcome
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for AuditedRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.inner.begin_sequence(seq_id)
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.inner.end_sequence(seq_id)
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.inner.active_sequences()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DeterministicRouter;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("auria-router-audit-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_audit_jsonl_roundtrip() {
+        let path = temp_path("roundtrip.jsonl");
+        let log = AuditLog::open(AuditConfig::new(&path, AuditFormat::Jsonl)).unwrap();
+        let router = AuditedRouter::new(DeterministicRouter::new_unchecked(16), log);
+        let decision = router.route(Tier::Standard, 3);
+        router.log().flush().unwrap();
+
+        let records = read_jsonl(File::open(&path).unwrap()).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].tier(), Some(Tier::Standard));
+        assert_eq!(records[0].token_index, 3);
+        let ids: Vec<[u8; 32]> = decision.expert_ids.iter().map(|id| id.0).collect();
+        assert_eq!(records[0].expert_ids, ids);
+    }
+
+    #[test]
+    fn test_audit_binary_roundtrip() {
+        let path = temp_path("roundtrip.bin");
+        let log = AuditLog::open(AuditConfig::new(&path, AuditFormat::Binary)).unwrap();
+        let router = DeterministicRouter::new_unchecked(16);
+        for token in 0..10 {
+            log.record(Tier::Nano, token, &router.route(Tier::Nano, token));
+        }
+        log.close().unwrap();
+
+        let records = read_binary(File::open(&path).unwrap()).unwrap();
+        assert_eq!(records.len(), 10);
+        assert_eq!(records[9].token_index, 9);
+        assert_eq!(records[9].expert_ids.len(), 2);
+    }
+
+    #[test]
+    fn test_audit_rotation_and_sampling() {
+        let path = temp_path("rotate.bin");
+        let config = AuditConfig::new(&path, AuditFormat::Binary)
+            .with_batch_size(1)
+            .with_rotation(200, 2);
+        let log = AuditLog::open(config).unwrap();
+        let router = DeterministicRouter::new_unchecked(16);
+        for token in 0..20 {
+            log.record(Tier::Nano, token, &router.route(Tier::Nano, token));
+        }
+        log.close().unwrap();
+        assert!(rotated_path(&path, 1).exists());
+        assert!(rotated_path(&path, 2).exists());
+        assert!(!rotated_path(&path, 3).exists());
+
+        let path = temp_path("sampled.jsonl");
+        let config = AuditConfig::new(&path, AuditFormat::Jsonl).with_sample_rate(0.0);
+        let log = AuditLog::open(config).unwrap();
+        log.record(Tier::Nano, 0, &router.route(Tier::Nano, 0));
+        log.close().unwrap();
+        assert!(read_jsonl(File::open(&path).unwrap()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_audit_writer_survives_io_errors() {
+        let dir =
+            std::env::temp_dir().join(format!("auria-router-audit-errors-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let config = AuditConfig::new(dir.join("log.bin"), AuditFormat::Binary)
+            .with_batch_size(1)
+            .with_rotation(100, 2);
+        let log = AuditLog::open(config).unwrap();
+        let router = DeterministicRouter::new_unchecked(16);
+        log.record(Tier::Nano, 0, &router.route(Tier::Nano, 0));
+        log.flush().unwrap();
+
+        // Rotation renames within the directory, which is now gone.
+        fs::remove_dir_all(&dir).unwrap();
+        for token in 1..10 {
+            log.record(Tier::Nano, token, &router.route(Tier::Nano, token));
+        }
+        assert!(log.flush().is_err());
+        assert_eq!(log.write_errors(), 9);
+        // Each error is reported by one flush only.
+        assert!(log.flush().is_ok());
+
+        // The writer is still running.
+        log.record(Tier::Nano, 10, &router.route(Tier::Nano, 10));
+        assert!(log.flush().is_err());
+        assert_eq!(log.write_errors(), 10);
+    }
+}
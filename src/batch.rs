@@ -0,0 +1,73 @@
+// File: batch.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Batch routing helpers for prefill workloads in AURIA Runtime Core.
+//     Routes many tokens in one call, optionally in parallel with rayon
+//     (feature "rayon"), always returning decisions in input order.
+//
+use crate::Router;
+use auria_core::{RoutingDecision, Tier};
+
+pub fn route_batch<R: Router + ?Sized>(
+    router: &R,
+    tier: Tier,
+    token_indices: &[u64],
+) -> Vec<RoutingDecision> {
+    token_indices
+        .iter()
+        .map(|&token_index| router.route(tier, token_index))
+        .collect()
+}
+
+// Output order always matches `token_indices`. Decisions are deterministic
+// for routers whose selection depends only on (tier, token_index); routers
+// with shared mutable cursors (e.g. RoundRobinRouter) observe calls in
+// scheduling order.
+#[cfg(feature = "rayon")]
+pub fn par_route_batch<R: Router + ?Sized>(
+    router: &R,
+    tier: Tier,
+    token_indices: &[u64],
+) -> Vec<RoutingDecision> {
+    use rayon::prelude::*;
+
+    token_indices
+        .par_iter()
+        .map(|&token_index| router.route(tier, token_index))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DeterministicRouter;
+
+    #[test]
+    fn test_route_batch_preserves_order() {
+        let router = DeterministicRouter::new_unchecked(64);
+        let tokens: Vec<u64> = (0..100).rev().collect();
+        let decisions = route_batch(&router, Tier::Nano, &tokens);
+
+        assert_eq!(decisions.len(), tokens.len());
+        for (decision, &token) in decisions.iter().zip(&tokens) {
+            assert_eq!(
+                decision.expert_ids,
+                router.route(Tier::Nano, token).expert_ids
+            );
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_route_batch_matches_sequential() {
+        let router = DeterministicRouter::new_unchecked(64);
+        let tokens: Vec<u64> = (0..10_000).map(|t| t * 7919).collect();
+        let sequential = route_batch(&router, Tier::Pro, &tokens);
+        let parallel = par_route_batch(&router, Tier::Pro, &tokens);
+
+        assert_eq!(sequential.len(), parallel.len());
+        for (a, b) in sequential.iter().zip(&parallel) {
+            assert_eq!(a.expert_ids, b.expert_ids);
+        }
+    }
+}
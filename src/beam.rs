@@ -0,0 +1,237 @@
+// File: beam.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Beam-search-aware routing for AURIA Runtime Core.
+//     Routes every beam of a decoding step and can trade a little per-beam
+//     rank for expert overlap across beams, so the union of activated
+//     experts (and the expert weights loaded) grows slower than the beam
+//     width.
+//
+use crate::{
+    decision_entries, decision_from_entries, Router, RouterState, RoutingContext, RoutingOutcome,
+    StatefulRouter,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BeamOverlap {
+    Independent,
+    // Each beam may pick from its top `search_width * k` candidates,
+    // preferring experts that the most beams can also use.
+    Maximize { search_width: u32 },
+}
+
+pub struct BeamRouter<R: Router> {
+    inner: R,
+    overlap: BeamOverlap,
+}
+
+type Entry = (ExpertId, f32, f32);
+
+impl<R: Router> BeamRouter<R> {
+    pub fn new(inner: R, overlap: BeamOverlap) -> Self {
+        Self { inner, overlap }
+    }
+
+    // Per-beam gate weights, one map per beam.
+    pub fn route_beams_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        beam_weights: &[HashMap<ExpertId, f32>],
+    ) -> Vec<RoutingDecision> {
+        let primaries: Vec<RoutingDecision> = beam_weights
+            .iter()
+            .map(|weights| self.inner.route_with_weights(tier, token_index, weights))
+            .collect();
+        self.share_experts(primaries, |beam| {
+            let mut ranked: Vec<Entry> = beam_weights[beam]
+                .iter()
+                .map(|(id, w)| (id.clone(), 1.0, *w))
+                .collect();
+            ranked.sort_by(|a, b| crate::descending(a.2, b.2));
+            ranked
+        })
+    }
+
+    // `candidates(beam)` yields that beam's ranked candidate list and is only
+    // materialized when overlap is being maximized.
+    fn share_experts(
+        &self,
+        primaries: Vec<RoutingDecision>,
+        candidates: impl Fn(usize) -> Vec<Entry>,
+    ) -> Vec<RoutingDecision> {
+        let BeamOverlap::Maximize { search_width } = self.overlap else {
+            return primaries;
+        };
+        let windows: Vec<Vec<Entry>> = primaries
+            .iter()
+            .enumerate()
+            .map(|(beam, primary)| {
+                let width = primary.expert_ids.len() * search_width.max(1) as usize;
+                let mut window = decision_entries(primary.clone());
+                for entry in candidates(beam) {
+                    if window.len() >= width {
+                        break;
+                    }
+                    if !window.iter().any(|(id, _, _)| *id == entry.0) {
+                        window.push(entry);
+                    }
+                }
+                window
+            })
+            .collect();
+
+        let mut votes: HashMap<&ExpertId, usize> = HashMap::new();
+        for window in &windows {
+            for (id, _, _) in window {
+                *votes.entry(id).or_insert(0) += 1;
+            }
+        }
+
+        windows
+            .iter()
+            .zip(&primaries)
+            .map(|(window, primary)| {
+                let k = primary.expert_ids.len();
+                let mut ranked: Vec<(usize, &Entry)> = window.iter().enumerate().collect();
+                // Most-shared first, then the beam's own rank.
+                ranked.sort_by_key(|(rank, (id, _, _))| (std::cmp::Reverse(votes[id]), *rank));
+                let mut chosen: Vec<(usize, Entry)> = ranked
+                    .into_iter()
+                    .take(k)
+                    .map(|(rank, entry)| (rank, entry.clone()))
+                    .collect();
+                chosen.sort_by_key(|(rank, _)| *rank);
+                decision_from_entries(
+                    chosen.into_iter().map(|(_, entry)| entry).collect(),
+                    primary.timestamp,
+                )
+            })
+            .collect()
+    }
+}
+
+// Size of the union of experts across beam decisions.
+pub fn activated_experts(decisions: &[RoutingDecision]) -> usize {
+    decisions
+        .iter()
+        .flat_map(|d| d.expert_ids.iter())
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+impl<R: Router> Router for BeamRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.inner.route(tier, token_index)
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        self.inner.route_with_weights(tier, token_index, weights)
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.inner.route_with_context(ctx)
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        self.inner.route_outcome(ctx)
+    }
+
+    // Candidates come from each beam's Max-tier selection, the ranked
+    // superset used by the other redistributing wrappers.
+    fn route_beams(&self, tier: Tier, token_index: u64, beam_count: u32) -> Vec<RoutingDecision> {
+        let context = |beam: usize| RoutingContext::new(tier, token_index).with_beam(beam as u32);
+        let primaries = (0..beam_count as usize)
+            .map(|beam| self.inner.route_with_context(&context(beam)))
+            .collect();
+        self.share_experts(primaries, |beam| {
+            let mut wide = context(beam);
+            wide.tier = Tier::Max;
+            decision_entries(self.inner.route_with_context(&wide))
+        })
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for BeamRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.inner.begin_sequence(seq_id)
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.inner.end_sequence(seq_id)
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.inner.active_sequences()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter};
+
+    // Beam b prefers experts b, b+1, ... with a gentle decay, so neighbouring
+    // beams rank mostly the same experts in a different order.
+    fn beam_weights(beams: u32) -> Vec<HashMap<ExpertId, f32>> {
+        (0..beams)
+            .map(|b| {
+                (0..32)
+                    .map(|i| {
+                        let distance = (i + 32 - b) % 32;
+                        (index_expert_id(i), 1.0 / (1.0 + distance as f32))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_maximize_overlap_shrinks_activated_set() {
+        let weights = beam_weights(4);
+        let independent = BeamRouter::new(
+            DeterministicRouter::new_unchecked(32),
+            BeamOverlap::Independent,
+        )
+        .route_beams_with_weights(Tier::Standard, 0, &weights);
+        let shared = BeamRouter::new(
+            DeterministicRouter::new_unchecked(32),
+            BeamOverlap::Maximize { search_width: 2 },
+        )
+        .route_beams_with_weights(Tier::Standard, 0, &weights);
+
+        assert_eq!(shared.len(), 4);
+        assert!(shared.iter().all(|d| d.expert_ids.len() == 4));
+        assert_eq!(activated_experts(&independent), 7);
+        assert!(activated_experts(&shared) < activated_experts(&independent));
+    }
+
+    #[test]
+    fn test_route_beams_returns_one_decision_per_beam() {
+        let router = DeterministicRouter::new_unchecked(64);
+        let decisions = router.route_beams(Tier::Pro, 5, 3);
+        assert_eq!(decisions.len(), 3);
+        assert_eq!(
+            decisions[0].expert_ids,
+            router.route(Tier::Pro, 5).expert_ids
+        );
+
+        let beam = BeamRouter::new(router, BeamOverlap::Maximize { search_width: 2 });
+        assert_eq!(activated_experts(&beam.route_beams(Tier::Pro, 5, 3)), 8);
+    }
+}
@@ -0,0 +1,183 @@
+// File: bias.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Per-expert gating bias terms for AURIA Runtime Core.
+//     Additive biases are applied to gate logits before softmax and can be
+//     updated independently of the gate weights, including auxiliary-loss-
+//     free load balancing that nudges biases toward under-used experts.
+//
+use crate::LoadWindow;
+use auria_core::ExpertId;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BiasBalancing {
+    pub update_rate: f32,
+    pub update_interval: u64,
+}
+
+impl BiasBalancing {
+    pub fn new(update_rate: f32, update_interval: u64) -> Self {
+        Self {
+            update_rate: update_rate.abs(),
+            update_interval: update_interval.max(1),
+        }
+    }
+}
+
+#[derive(Default)]
+struct IntervalLoad {
+    counts: HashMap<ExpertId, u64>,
+    steps: u64,
+}
+
+#[derive(Default)]
+pub(crate) struct ExpertBiases {
+    biases: RwLock<HashMap<ExpertId, f32>>,
+    balancing: Option<BiasBalancing>,
+    load: Mutex<IntervalLoad>,
+    // Replaces the per-interval counts when set.
+    window: Option<Arc<LoadWindow>>,
+}
+
+impl ExpertBiases {
+    pub(crate) fn set(&self, expert_id: ExpertId, bias: f32) {
+        self.biases.write().unwrap().insert(expert_id, bias);
+    }
+
+    pub(crate) fn get(&self, expert_id: &ExpertId) -> f32 {
+        self.biases
+            .read()
+            .unwrap()
+            .get(expert_id)
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    pub(crate) fn all(&self) -> HashMap<ExpertId, f32> {
+        self.biases.read().unwrap().clone()
+    }
+
+    pub(crate) fn clear(&self) {
+        self.biases.write().unwrap().clear();
+        *self.load.lock().unwrap() = IntervalLoad::default();
+    }
+
+    pub(crate) fn set_balancing(&mut self, balancing: Option<BiasBalancing>) {
+        self.balancing = balancing;
+        *self.load.lock().unwrap() = IntervalLoad::default();
+    }
+
+    pub(crate) fn set_load_window(&mut self, window: Option<Arc<LoadWindow>>) {
+        self.window = window;
+    }
+
+    pub(crate) fn apply<'a>(
+        &self,
+        logits: &'a HashMap<ExpertId, f32>,
+    ) -> Cow<'a, HashMap<ExpertId, f32>> {
+        let biases = self.biases.read().unwrap();
+        if biases.is_empty() {
+            return Cow::Borrowed(logits);
+        }
+        Cow::Owned(
+            logits
+                .iter()
+                .map(|(id, w)| (id.clone(), w + biases.get(id).copied().unwrap_or(0.0)))
+                .collect(),
+        )
+    }
+
+    // Every `update_interval` steps each expert's bias moves by
+    // `update_rate` toward the mean load: up if under-used, down if over.
+    // Load is the interval's own selections, or the shared window's counts.
+    pub(crate) fn record_load<'a>(
+        &self,
+        selected: &[ExpertId],
+        experts: impl Iterator<Item = &'a ExpertId>,
+    ) {
+        let Some(balancing) = self.balancing else {
+            return;
+        };
+        let mut load = self.load.lock().unwrap();
+        if self.window.is_none() {
+            for id in selected {
+                *load.counts.entry(id.clone()).or_insert(0) += 1;
+            }
+        }
+        load.steps += 1;
+        if load.steps < balancing.update_interval {
+            return;
+        }
+        if let Some(window) = &self.window {
+            load.counts = window.counts();
+        }
+
+        let experts: Vec<&ExpertId> = experts.collect();
+        if !experts.is_empty() {
+            let total: u64 = load.counts.values().sum();
+            let mean = total as f32 / experts.len() as f32;
+            let mut biases = self.biases.write().unwrap();
+            for id in experts {
+                let count = load.counts.get(id).copied().unwrap_or(0) as f32;
+                let nudge = if count < mean {
+                    balancing.update_rate
+                } else if count > mean {
+                    -balancing.update_rate
+                } else {
+                    0.0
+                };
+                *biases.entry(id.clone()).or_insert(0.0) += nudge;
+            }
+        }
+        *load = IntervalLoad::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, GatingRouter, Router};
+    use auria_core::Tier;
+
+    #[test]
+    fn test_expert_bias_shifts_selection() {
+        let mut router = GatingRouter::new_unchecked(1.0);
+        for i in 0..4 {
+            router.set_gate_weight(index_expert_id(i), i as f32);
+        }
+        assert_eq!(
+            router.route(Tier::Nano, 0).expert_ids[0],
+            index_expert_id(3)
+        );
+
+        router.set_expert_bias(index_expert_id(0), 10.0);
+        assert_eq!(
+            router.route(Tier::Nano, 0).expert_ids[0],
+            index_expert_id(0)
+        );
+        assert_eq!(router.expert_bias(&index_expert_id(0)), 10.0);
+    }
+
+    #[test]
+    fn test_bias_balancing_spreads_load() {
+        let mut router = GatingRouter::new_unchecked(1.0);
+        for i in 0..8 {
+            router.set_gate_weight(index_expert_id(i), 1.0 + i as f32 * 0.01);
+        }
+        let distinct = |router: &GatingRouter| {
+            let mut used = std::collections::HashSet::new();
+            for token in 0..400 {
+                used.extend(router.route(Tier::Nano, token).expert_ids);
+            }
+            used.len()
+        };
+        assert_eq!(distinct(&router), 2);
+
+        router.set_bias_balancing(Some(BiasBalancing::new(0.05, 4)));
+        assert_eq!(distinct(&router), 8);
+        assert!(!router.expert_biases().is_empty());
+    }
+}
@@ -0,0 +1,131 @@
+// File: auria_sim.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Command-line front end for the routing simulation harness
+//     (feature "sim-cli"). Runs a built-in router over a synthetic
+//     workload and prints the simulation report.
+//
+use auria_core::{ExpertId, Tier};
+use auria_router::sim::{self, SimConfig, TokenDistribution};
+use auria_router::{
+    AnyRouter, ConfigError, DeterministicRouter, GatingRouter, HashRouter, RoundRobinRouter,
+};
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: auria-sim [--router deterministic|hash|round-robin|gating] \
+[--experts N] [--tier nano|standard|pro|max] [--steps N] [--batch N] \
+[--capacity-factor F] [--workload uniform|zipf|bursty] [--seed N]";
+
+fn parse_tier(value: &str) -> Option<Tier> {
+    match value {
+        "nano" => Some(Tier::Nano),
+        "standard" => Some(Tier::Standard),
+        "pro" => Some(Tier::Pro),
+        "max" => Some(Tier::Max),
+        _ => None,
+    }
+}
+
+// Same index-to-ID layout the built-in index routers use.
+fn expert_ids(count: u32) -> Vec<ExpertId> {
+    (0..count)
+        .map(|i| {
+            let mut bytes = [0u8; 32];
+            bytes[0..4].copy_from_slice(&i.to_le_bytes());
+            ExpertId(bytes)
+        })
+        .collect()
+}
+
+fn build_router(name: &str, experts: u32, seed: u64) -> Option<Result<AnyRouter, ConfigError>> {
+    let router = match name {
+        "deterministic" => DeterministicRouter::new(experts).map(AnyRouter::Deterministic),
+        "hash" => HashRouter::new(experts, seed).map(AnyRouter::Hash),
+        "round-robin" => RoundRobinRouter::new(expert_ids(experts)).map(AnyRouter::RoundRobin),
+        "gating" => GatingRouter::new(1.0).map(|mut router| {
+            router.set_sampling_seed(Some(seed));
+            for id in expert_ids(experts) {
+                router.set_gate_weight(id, 0.0);
+            }
+            AnyRouter::Gating(router)
+        }),
+        _ => return None,
+    };
+    Some(router)
+}
+
+fn main() -> ExitCode {
+    let mut router = String::from("deterministic");
+    let mut experts = 64u32;
+    let mut tier = Tier::Standard;
+    let mut steps = 10_000u64;
+    let mut batch = 256usize;
+    let mut capacity_factor = 1.25f32;
+    let mut workload = String::from("uniform");
+    let mut seed = 0u64;
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        let Some(value) = iter.next() else {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        };
+        let ok = match flag.as_str() {
+            "--router" => {
+                router = value.clone();
+                true
+            }
+            "--experts" => value.parse().map(|v| experts = v).is_ok(),
+            "--tier" => parse_tier(value).map(|t| tier = t).is_some(),
+            "--steps" => value.parse().map(|v| steps = v).is_ok(),
+            "--batch" => value.parse().map(|v| batch = v).is_ok(),
+            "--capacity-factor" => value.parse().map(|v| capacity_factor = v).is_ok(),
+            "--workload" => {
+                workload = value.clone();
+                true
+            }
+            "--seed" => value.parse().map(|v| seed = v).is_ok(),
+            _ => false,
+        };
+        if !ok {
+            eprintln!("invalid argument {flag} {value}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let distribution = match workload.as_str() {
+        "uniform" => TokenDistribution::Uniform { vocab: 50_000 },
+        "zipf" => TokenDistribution::Zipf {
+            vocab: 50_000,
+            exponent: 1.1,
+        },
+        "bursty" => TokenDistribution::BurstySessions {
+            sessions: 32,
+            burst_len: 64,
+        },
+        _ => {
+            eprintln!("unknown workload {workload}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let router = match build_router(&router, experts, seed) {
+        Some(Ok(router)) => router,
+        Some(Err(err)) => {
+            eprintln!("invalid configuration: {err}");
+            return ExitCode::FAILURE;
+        }
+        None => {
+            eprintln!("unknown router {router}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let config = SimConfig::new(tier, experts, distribution)
+        .with_steps(steps)
+        .with_batch_size(batch)
+        .with_capacity_factor(capacity_factor)
+        .with_seed(seed);
+    println!("{}", sim::run(&router, &config));
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,430 @@
+// File: budget.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Process-wide routing budget for AURIA Runtime Core.
+//     A RoutingBudget caps the number of distinct experts active at once
+//     across all concurrent requests and the rate of expert activations.
+//     BudgetedRouters share one budget behind an Arc and consult it
+//     atomically before finalizing a decision, downgrading the decision or
+//     queuing for capacity when the budget is exhausted.
+//
+use crate::{
+    decision_entries, decision_from_entries, BudgetError, DropReason, Router, RouterState,
+    RoutingContext, RoutingExplanation, RoutingOutcome, StatefulRouter,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+struct BudgetState {
+    // Reference counts of the experts held by live leases.
+    active: HashMap<ExpertId, u32>,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+pub struct RoutingBudget {
+    max_active: Option<usize>,
+    // (activations per second, burst)
+    max_rate: Option<(f64, f64)>,
+    state: Mutex<BudgetState>,
+    released: Condvar,
+}
+
+impl Default for RoutingBudget {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RoutingBudget {
+    // Unbounded until limits are configured.
+    pub fn new() -> Self {
+        Self {
+            max_active: None,
+            max_rate: None,
+            state: Mutex::new(BudgetState {
+                active: HashMap::new(),
+                tokens: 0.0,
+                last_refill: Instant::now(),
+            }),
+            released: Condvar::new(),
+        }
+    }
+
+    pub fn with_max_active_experts(mut self, max_active: usize) -> Self {
+        self.max_active = Some(max_active);
+        self
+    }
+
+    // Every expert in an admitted decision is one activation.
+    pub fn with_max_activations_per_sec(mut self, rate: f64, burst: f64) -> Self {
+        let burst = burst.max(1.0);
+        self.max_rate = Some((rate.max(0.0), burst));
+        self.state.get_mut().unwrap().tokens = burst;
+        self
+    }
+
+    pub fn active_experts(&self) -> usize {
+        self.state.lock().unwrap().active.len()
+    }
+
+    pub fn is_active(&self, expert_id: &ExpertId) -> bool {
+        self.state.lock().unwrap().active.contains_key(expert_id)
+    }
+
+    // All of `expert_ids` or nothing.
+    pub fn try_acquire(
+        self: &Arc<Self>,
+        expert_ids: &[ExpertId],
+    ) -> Result<BudgetLease, BudgetError> {
+        let mut state = self.state.lock().unwrap();
+        self.admit(&mut state, expert_ids)?;
+        Ok(self.lease(expert_ids.to_vec()))
+    }
+
+    // Waits up to `timeout` for leases to be released or the rate to
+    // refill, then reports why the request still does not fit.
+    pub fn acquire_timeout(
+        self: &Arc<Self>,
+        expert_ids: &[ExpertId],
+        timeout: Duration,
+    ) -> Result<BudgetLease, BudgetError> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.state.lock().unwrap();
+        loop {
+            let err = match self.admit(&mut state, expert_ids) {
+                Ok(()) => return Ok(self.lease(expert_ids.to_vec())),
+                Err(err) => err,
+            };
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(err);
+            }
+            // Rate refills are not signalled, so poll at least every 10ms.
+            let wait = (deadline - now).min(Duration::from_millis(10));
+            state = self.released.wait_timeout(state, wait).unwrap().0;
+        }
+    }
+
+    // Admits candidates in preference order until `k` are held, skipping
+    // any that do not fit. The lease may hold fewer than `k`.
+    pub fn acquire_within(self: &Arc<Self>, candidates: &[ExpertId], k: usize) -> BudgetLease {
+        let mut state = self.state.lock().unwrap();
+        let mut admitted = Vec::with_capacity(k);
+        for id in candidates {
+            if admitted.len() == k {
+                break;
+            }
+            if !admitted.contains(id) && self.admit(&mut state, std::slice::from_ref(id)).is_ok() {
+                admitted.push(id.clone());
+            }
+        }
+        self.lease(admitted)
+    }
+
+    fn admit(&self, state: &mut BudgetState, expert_ids: &[ExpertId]) -> Result<(), BudgetError> {
+        if let Some(max_active) = self.max_active {
+            let mut new: Vec<&ExpertId> = expert_ids
+                .iter()
+                .filter(|id| !state.active.contains_key(id))
+                .collect();
+            new.sort_by_key(|id| id.0);
+            new.dedup();
+            let available = max_active.saturating_sub(state.active.len());
+            if new.len() > available {
+                return Err(BudgetError::ActiveExperts {
+                    requested: new.len(),
+                    available,
+                });
+            }
+        }
+        if let Some((rate, burst)) = self.max_rate {
+            let now = Instant::now();
+            let elapsed = now
+                .saturating_duration_since(state.last_refill)
+                .as_secs_f64();
+            state.tokens = (state.tokens + elapsed * rate).min(burst);
+            state.last_refill = now;
+            let needed = expert_ids.len() as f64;
+            if state.tokens < needed {
+                return Err(BudgetError::ActivationRate {
+                    requested: expert_ids.len(),
+                });
+            }
+            state.tokens -= needed;
+        }
+        for id in expert_ids {
+            *state.active.entry(id.clone()).or_insert(0) += 1;
+        }
+        Ok(())
+    }
+
+    fn lease(self: &Arc<Self>, expert_ids: Vec<ExpertId>) -> BudgetLease {
+        BudgetLease {
+            budget: self.clone(),
+            expert_ids,
+        }
+    }
+
+    fn release(&self, expert_ids: &[ExpertId]) {
+        let mut state = self.state.lock().unwrap();
+        for id in expert_ids {
+            if let Some(count) = state.active.get_mut(id) {
+                *count -= 1;
+                if *count == 0 {
+                    state.active.remove(id);
+                }
+            }
+        }
+        drop(state);
+        self.released.notify_all();
+    }
+}
+
+// Holds its experts active in the budget until dropped.
+#[must_use = "dropping a lease releases its experts immediately"]
+pub struct BudgetLease {
+    budget: Arc<RoutingBudget>,
+    expert_ids: Vec<ExpertId>,
+}
+
+impl BudgetLease {
+    pub fn expert_ids(&self) -> &[ExpertId] {
+        &self.expert_ids
+    }
+}
+
+impl Drop for BudgetLease {
+    fn drop(&mut self) {
+        self.budget.release(&self.expert_ids);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BudgetPolicy {
+    // Keep the best experts that fit, topping up with next-best candidates
+    // that are already active.
+    Downgrade,
+    // Wait up to `timeout` for the full decision to fit.
+    Queue { timeout: Duration },
+}
+
+pub struct BudgetedRouter<R: Router> {
+    inner: R,
+    budget: Arc<RoutingBudget>,
+    policy: BudgetPolicy,
+}
+
+impl<R: Router> BudgetedRouter<R> {
+    pub fn new(inner: R, budget: Arc<RoutingBudget>) -> Self {
+        Self {
+            inner,
+            budget,
+            policy: BudgetPolicy::Downgrade,
+        }
+    }
+
+    pub fn with_policy(mut self, policy: BudgetPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn budget(&self) -> &Arc<RoutingBudget> {
+        &self.budget
+    }
+
+    // The lease keeps the decision's experts counted as active; hold it
+    // for the duration of the forward pass.
+    pub fn route_leased(
+        &self,
+        ctx: &RoutingContext,
+    ) -> Result<(RoutingOutcome, BudgetLease), BudgetError> {
+        self.finalize(ctx, self.inner.route_outcome(ctx))
+    }
+
+    fn finalize(
+        &self,
+        ctx: &RoutingContext,
+        mut outcome: RoutingOutcome,
+    ) -> Result<(RoutingOutcome, BudgetLease), BudgetError> {
+        let err = match self.policy {
+            BudgetPolicy::Queue { timeout } => {
+                let lease = self
+                    .budget
+                    .acquire_timeout(&outcome.decision.expert_ids, timeout)?;
+                return Ok((outcome, lease));
+            }
+            BudgetPolicy::Downgrade => {
+                match self.budget.try_acquire(&outcome.decision.expert_ids) {
+                    Ok(lease) => return Ok((outcome, lease)),
+                    Err(err) => err,
+                }
+            }
+        };
+
+        let timestamp = outcome.decision.timestamp;
+        let primary = decision_entries(outcome.decision.clone());
+        let mut wide = ctx.clone();
+        wide.tier = Tier::Max;
+        let mut candidates: Vec<(ExpertId, f32, f32)> = primary.clone();
+        for entry in decision_entries(self.inner.route_with_context(&wide)) {
+            if self.budget.is_active(&entry.0) && !candidates.iter().any(|(c, _, _)| *c == entry.0)
+            {
+                candidates.push(entry);
+            }
+        }
+        let ids: Vec<ExpertId> = candidates.iter().map(|(id, _, _)| id.clone()).collect();
+        let lease = self.budget.acquire_within(&ids, primary.len());
+        if lease.expert_ids().is_empty() {
+            return Err(err);
+        }
+
+        let kept: Vec<(ExpertId, f32, f32)> = candidates
+            .into_iter()
+            .filter(|(id, _, _)| lease.expert_ids().contains(id))
+            .collect();
+        outcome.fallback_used = kept
+            .iter()
+            .any(|(id, _, _)| !primary.iter().any(|(p, _, _)| p == id));
+        outcome.decision = decision_from_entries(kept, timestamp);
+        for (id, _, _) in primary {
+            if !lease.expert_ids().contains(&id) {
+                outcome.record_drop(id, DropReason::Capacity);
+            }
+        }
+        Ok((outcome, lease))
+    }
+
+    // Through the Router trait the lease is released as soon as the
+    // decision is made, so only the activation rate is enforced; use
+    // route_leased to hold experts active.
+    fn budgeted(&self, ctx: &RoutingContext, outcome: RoutingOutcome) -> RoutingOutcome {
+        let requested_k = outcome.requested_k;
+        let primary = outcome.decision.expert_ids.clone();
+        let timestamp = outcome.decision.timestamp;
+        match self.finalize(ctx, outcome) {
+            Ok((outcome, _lease)) => outcome,
+            Err(_) => {
+                let mut empty =
+                    RoutingOutcome::new(decision_from_entries(Vec::new(), timestamp), requested_k);
+                for id in primary {
+                    empty.record_drop(id, DropReason::Capacity);
+                }
+                empty
+            }
+        }
+    }
+}
+
+impl<R: Router> Router for BudgetedRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.route_with_context(&RoutingContext::new(tier, token_index))
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        let decision = self.inner.route_with_weights(tier, token_index, weights);
+        let k = decision.expert_ids.len() as u32;
+        self.budgeted(
+            &RoutingContext::new(tier, token_index),
+            RoutingOutcome::new(decision, k),
+        )
+        .decision
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.route_outcome(ctx).decision
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        self.budgeted(ctx, self.inner.route_outcome(ctx))
+    }
+
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        let explanation = self.inner.explain(ctx);
+        let outcome = self.budgeted(ctx, explanation.outcome.clone());
+        explanation.layer("budget", outcome)
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for BudgetedRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.inner.begin_sequence(seq_id)
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.inner.end_sequence(seq_id)
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.inner.active_sequences()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter};
+
+    #[test]
+    fn test_concurrent_requests_share_active_budget() {
+        let budget = Arc::new(RoutingBudget::new().with_max_active_experts(3));
+        let router = BudgetedRouter::new(DeterministicRouter::new_unchecked(16), budget.clone());
+
+        let (first, lease) = router
+            .route_leased(&RoutingContext::new(Tier::Nano, 0))
+            .unwrap();
+        assert_eq!(
+            first.decision.expert_ids,
+            vec![index_expert_id(0), index_expert_id(1)]
+        );
+        assert_eq!(budget.active_experts(), 2);
+
+        // Token 4 wants experts 4 and 5; one slot is left, so it keeps 4
+        // and falls back to the already-active expert 0.
+        let (second, _second_lease) = router
+            .route_leased(&RoutingContext::new(Tier::Nano, 4))
+            .unwrap();
+        assert_eq!(
+            second.decision.expert_ids,
+            vec![index_expert_id(4), index_expert_id(0)]
+        );
+        assert_eq!(second.dropped[0].reason, DropReason::Capacity);
+        assert_eq!(budget.active_experts(), 3);
+
+        drop(lease);
+        assert_eq!(budget.active_experts(), 2);
+        assert!(!budget.is_active(&index_expert_id(1)));
+    }
+
+    #[test]
+    fn test_queue_policy_times_out_and_rate_limits() {
+        let budget = Arc::new(RoutingBudget::new().with_max_activations_per_sec(0.0, 4.0));
+        let router = BudgetedRouter::new(DeterministicRouter::new_unchecked(64), budget)
+            .with_policy(BudgetPolicy::Queue {
+                timeout: Duration::from_millis(5),
+            });
+        let ctx = RoutingContext::new(Tier::Nano, 0);
+        assert!(router.route_leased(&ctx).is_ok());
+        assert!(router.route_leased(&ctx).is_ok());
+        assert_eq!(
+            router.route_leased(&ctx).err(),
+            Some(BudgetError::ActivationRate { requested: 2 })
+        );
+        assert!(router.route(Tier::Nano, 0).expert_ids.is_empty());
+    }
+}
@@ -0,0 +1,415 @@
+// File: canary.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Canary routing for newly deployed experts in AURIA Runtime Core.
+//     Ramps traffic to a designated set of canary experts from 0% to a
+//     target share over a configured number of routing calls, with an
+//     abort switch that instantly reverts to the baseline expert set.
+//
+use crate::{
+    splitmix64, CanaryState, DropReason, Router, RouterState, RoutingContext, RoutingExplanation,
+    RoutingOutcome, StatefulRouter,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+const MASK: &str = "canary";
+
+pub struct CanaryRouter<R: Router> {
+    inner: R,
+    canary_experts: Vec<ExpertId>,
+    target_share: f32,
+    ramp_steps: u64,
+    progress: AtomicU64,
+    aborted: AtomicBool,
+    // Live sequences draw once at begin and keep that canary (or none).
+    sequences: Mutex<HashMap<u64, Option<ExpertId>>>,
+}
+
+impl<R: Router> CanaryRouter<R> {
+    pub fn new(
+        inner: R,
+        canary_experts: Vec<ExpertId>,
+        target_share: f32,
+        ramp_steps: u64,
+    ) -> Self {
+        Self {
+            inner,
+            canary_experts,
+            target_share: target_share.clamp(0.0, 1.0),
+            ramp_steps,
+            progress: AtomicU64::new(0),
+            aborted: AtomicBool::new(false),
+            sequences: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn abort(&self) {
+        self.aborted.store(true, Ordering::SeqCst);
+    }
+
+    pub fn resume(&self) {
+        self.aborted.store(false, Ordering::SeqCst);
+    }
+
+    pub fn is_aborted(&self) -> bool {
+        self.aborted.load(Ordering::SeqCst)
+    }
+
+    pub fn reset(&self) {
+        self.progress.store(0, Ordering::Relaxed);
+    }
+
+    pub fn progress(&self) -> u64 {
+        self.progress.load(Ordering::Relaxed)
+    }
+
+    pub fn set_progress(&self, progress: u64) {
+        self.progress.store(progress, Ordering::Relaxed);
+    }
+
+    pub fn canary_experts(&self) -> &[ExpertId] {
+        &self.canary_experts
+    }
+
+    pub fn current_share(&self) -> f32 {
+        self.share_at(self.progress())
+    }
+
+    fn share_at(&self, step: u64) -> f32 {
+        if self.is_aborted() {
+            return 0.0;
+        }
+        if self.ramp_steps == 0 || step >= self.ramp_steps {
+            return self.target_share;
+        }
+        self.target_share * (step as f32 / self.ramp_steps as f32)
+    }
+
+    // Advances the ramp and decides whether this call is served by a canary.
+    fn next_canary(&self) -> Option<&ExpertId> {
+        self.canary_at(self.progress.fetch_add(1, Ordering::Relaxed))
+    }
+
+    fn canary_at(&self, step: u64) -> Option<&ExpertId> {
+        if self.canary_experts.is_empty() {
+            return None;
+        }
+        let share = self.share_at(step);
+        let draw = (splitmix64(step) >> 11) as f64 / (1u64 << 53) as f64;
+        if draw < share as f64 {
+            Some(&self.canary_experts[(step % self.canary_experts.len() as u64) as usize])
+        } else {
+            None
+        }
+    }
+
+    // Live sequences keep the canary drawn at begin; other traffic draws per
+    // call. `advance` is false for explain, which must not move the ramp.
+    fn context_canary(&self, ctx: &RoutingContext, advance: bool) -> Option<ExpertId> {
+        let assigned = ctx
+            .sequence_id
+            .and_then(|seq_id| self.sequences.lock().unwrap().get(&seq_id).cloned());
+        match assigned {
+            // An abort reverts live sequences too.
+            Some(canary) if !self.is_aborted() => canary,
+            Some(_) => None,
+            None if advance => self.next_canary().cloned(),
+            None => self.canary_at(self.progress()).cloned(),
+        }
+    }
+
+    fn apply(&self, decision: RoutingDecision, canary: Option<&ExpertId>) -> RoutingDecision {
+        self.apply_outcome(RoutingOutcome::new(decision, 0), canary)
+            .decision
+    }
+
+    // Baseline picks that are canary experts are recorded as masked; the
+    // canary, when drawn, takes the last slot so k is unchanged.
+    fn apply_outcome(
+        &self,
+        mut outcome: RoutingOutcome,
+        canary: Option<&ExpertId>,
+    ) -> RoutingOutcome {
+        for id in strip_experts(&mut outcome.decision, &self.canary_experts) {
+            outcome.record_drop(
+                id,
+                DropReason::Masked {
+                    mask: MASK.to_string(),
+                },
+            );
+        }
+        let decision = &mut outcome.decision;
+        if let Some(canary) = canary {
+            if decision.expert_ids.is_empty() {
+                decision.expert_ids.push(canary.clone());
+                decision.confidence_scores.push(1.0);
+                decision.gating_weights.push(1.0);
+            } else {
+                let last = decision.expert_ids.len() - 1;
+                decision.expert_ids[last] = canary.clone();
+            }
+        }
+        outcome
+    }
+
+    fn baseline_weights(&self, weights: &HashMap<ExpertId, f32>) -> HashMap<ExpertId, f32> {
+        weights
+            .iter()
+            .filter(|(id, _)| !self.canary_experts.contains(id))
+            .map(|(id, w)| (id.clone(), *w))
+            .collect()
+    }
+}
+
+fn strip_experts(decision: &mut RoutingDecision, excluded: &[ExpertId]) -> Vec<ExpertId> {
+    let mut removed = Vec::new();
+    let mut i = 0;
+    while i < decision.expert_ids.len() {
+        if excluded.contains(&decision.expert_ids[i]) {
+            removed.push(decision.expert_ids.remove(i));
+            if i < decision.confidence_scores.len() {
+                decision.confidence_scores.remove(i);
+            }
+            if i < decision.gating_weights.len() {
+                decision.gating_weights.remove(i);
+            }
+        } else {
+            i += 1;
+        }
+    }
+    removed
+}
+
+impl<R: Router> Router for CanaryRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        let canary = self.next_canary();
+        let decision = self.inner.route(tier, token_index);
+        self.apply(decision, canary)
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        let canary = self.next_canary();
+        let baseline = self.baseline_weights(weights);
+        let decision = self.inner.route_with_weights(tier, token_index, &baseline);
+        self.apply(decision, canary)
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        let canary = self.context_canary(ctx, true);
+        self.apply(self.inner.route_with_context(ctx), canary.as_ref())
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        let canary = self.context_canary(ctx, true);
+        self.apply_outcome(self.inner.route_outcome(ctx), canary.as_ref())
+    }
+
+    fn route_outcome_with_weights(
+        &self,
+        ctx: &RoutingContext,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingOutcome {
+        let canary = self.context_canary(ctx, true);
+        let baseline = self.baseline_weights(weights);
+        let outcome = self.inner.route_outcome_with_weights(ctx, &baseline);
+        self.apply_outcome(outcome, canary.as_ref())
+    }
+
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        let canary = self.context_canary(ctx, false);
+        let explanation = self.inner.explain(ctx);
+        let outcome = self.apply_outcome(explanation.outcome.clone(), canary.as_ref());
+        explanation.layer(MASK, outcome)
+    }
+
+    fn snapshot(&self) -> RouterState {
+        RouterState {
+            canary: Some(CanaryState {
+                progress: self.progress(),
+                aborted: self.is_aborted(),
+            }),
+            ..RouterState::default()
+        }
+        .with_inner(self.inner.snapshot())
+    }
+
+    fn restore(&self, state: &RouterState) {
+        if let Some(canary) = &state.canary {
+            self.set_progress(canary.progress);
+            self.aborted.store(canary.aborted, Ordering::SeqCst);
+        }
+        if let Some(inner) = state.inner() {
+            self.inner.restore(inner);
+        }
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for CanaryRouter<R> {
+    // Each sequence advances the ramp once, so the canary share is measured
+    // in sequences rather than tokens for session-scoped traffic.
+    fn begin_sequence(&self, seq_id: u64) {
+        let canary = self.next_canary().cloned();
+        self.sequences.lock().unwrap().insert(seq_id, canary);
+        self.inner.begin_sequence(seq_id);
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.sequences.lock().unwrap().remove(&seq_id);
+        self.inner.end_sequence(seq_id);
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.sequences.lock().unwrap().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DeterministicRouter, RoundRobinRouter, TierKTable};
+
+    fn canary_id() -> ExpertId {
+        ExpertId([0xAA; 32])
+    }
+
+    fn canary_hits<R: Router>(router: &CanaryRouter<R>, calls: u64) -> usize {
+        (0..calls)
+            .filter(|&t| {
+                router
+                    .route(Tier::Standard, t)
+                    .expert_ids
+                    .contains(&canary_id())
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_canary_ramps_to_target_share() {
+        let router = CanaryRouter::new(
+            DeterministicRouter::new_unchecked(64),
+            vec![canary_id()],
+            0.5,
+            1000,
+        );
+        assert_eq!(router.current_share(), 0.0);
+
+        let ramp_hits = canary_hits(&router, 1000);
+        let steady_hits = canary_hits(&router, 1000);
+        assert!(ramp_hits > 0);
+        assert!(steady_hits > ramp_hits);
+        assert!((400..600).contains(&steady_hits));
+        assert_eq!(router.current_share(), 0.5);
+    }
+
+    #[test]
+    fn test_canary_abort_reverts_to_baseline() {
+        let router = CanaryRouter::new(
+            DeterministicRouter::new_unchecked(64),
+            vec![canary_id()],
+            1.0,
+            0,
+        );
+        assert_eq!(canary_hits(&router, 100), 100);
+
+        router.abort();
+        assert_eq!(canary_hits(&router, 100), 0);
+        let decision = router.route(Tier::Standard, 0);
+        assert_eq!(decision.expert_ids.len(), 4);
+
+        router.resume();
+        assert_eq!(canary_hits(&router, 100), 100);
+    }
+
+    #[test]
+    fn test_canary_snapshot_includes_inner_state() {
+        let experts: Vec<ExpertId> = (0..8u8).map(|i| ExpertId([i; 32])).collect();
+        let router = CanaryRouter::new(
+            RoundRobinRouter::new_unchecked(experts.clone()),
+            vec![canary_id()],
+            0.2,
+            500,
+        );
+        canary_hits(&router, 37);
+        router.abort();
+        let state = router.snapshot();
+        assert_eq!(state.canary.as_ref().unwrap().progress, 37);
+        assert_eq!(state.inner().unwrap().round_robin_cursor, Some(37));
+
+        let restarted = CanaryRouter::new(
+            RoundRobinRouter::new_unchecked(experts),
+            vec![canary_id()],
+            0.2,
+            500,
+        );
+        restarted.restore(&state);
+        assert!(restarted.is_aborted());
+        assert_eq!(restarted.snapshot(), state);
+    }
+
+    #[test]
+    fn test_canary_assignment_is_per_sequence() {
+        let router = CanaryRouter::new(
+            DeterministicRouter::new_unchecked(64),
+            vec![canary_id()],
+            0.5,
+            0,
+        );
+        let served = |seq: u64| {
+            (0..20)
+                .filter(|&t| {
+                    let ctx = RoutingContext::new(Tier::Standard, t).with_sequence_id(seq);
+                    router
+                        .route_with_context(&ctx)
+                        .expert_ids
+                        .contains(&canary_id())
+                })
+                .count()
+        };
+        for seq in 0..20 {
+            router.begin_sequence(seq);
+            let hits = served(seq);
+            assert!(hits == 0 || hits == 20, "hits = {}", hits);
+        }
+        assert_eq!(router.progress(), 20);
+        assert_eq!(router.active_sequences(), 20);
+
+        (0..20).for_each(|seq| router.end_sequence(seq));
+        assert_eq!(router.active_sequences(), 0);
+    }
+
+    #[test]
+    fn test_canary_outcome_keeps_inner_k() {
+        let inner = DeterministicRouter::new_unchecked(64).with_k_selector(TierKTable {
+            nano: 4,
+            standard: 4,
+            pro: 8,
+            max: 16,
+        });
+        let router = CanaryRouter::new(inner, vec![canary_id()], 1.0, 0);
+        let ctx = RoutingContext::new(Tier::Nano, 3);
+        let outcome = router.route_outcome(&ctx);
+        assert_eq!(outcome.requested_k, 4);
+        assert_eq!(outcome.decision.expert_ids.len(), 4);
+        assert_eq!(outcome.decision.expert_ids[3], canary_id());
+        assert!(!outcome.is_degraded());
+        assert!(router.self_check(65, &[Tier::Nano]).is_ok());
+
+        let progress = router.progress();
+        let explanation = router.explain(&ctx);
+        assert_eq!(router.progress(), progress);
+        assert_eq!(explanation.outcome.requested_k, 4);
+        assert!(explanation
+            .outcome
+            .decision
+            .expert_ids
+            .contains(&canary_id()));
+    }
+}
@@ -0,0 +1,257 @@
+// File: chaos.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Fault injection for resilience testing of AURIA Runtime Core (feature
+//     "test-support"). ChaosRouter wraps any router and, at configured
+//     per-fault rates drawn from a seeded stream, substitutes random
+//     experts, empties decisions, duplicates expert IDs or adds latency.
+//
+use crate::{
+    index_expert_id, splitmix64, Router, RouterState, RoutingContext, RoutingExplanation,
+    RoutingOutcome, StatefulRouter,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Fault {
+    Substitution,
+    Empty,
+    Duplicate,
+    Latency,
+}
+
+const FAULTS: [Fault; 4] = [
+    Fault::Substitution,
+    Fault::Empty,
+    Fault::Duplicate,
+    Fault::Latency,
+];
+
+pub struct ChaosRouter<R: Router> {
+    inner: R,
+    seed: u64,
+    substitution_rate: f32,
+    // Substitutes are drawn from experts 0..expert_count.
+    expert_count: u32,
+    empty_rate: f32,
+    duplicate_rate: f32,
+    latency_rate: f32,
+    latency: Duration,
+    enabled: AtomicBool,
+    calls: AtomicU64,
+    injected: [AtomicU64; 4],
+}
+
+impl<R: Router> ChaosRouter<R> {
+    // All rates start at zero; enable faults with the builders below.
+    pub fn new(inner: R, seed: u64) -> Self {
+        Self {
+            inner,
+            seed,
+            substitution_rate: 0.0,
+            expert_count: 1,
+            empty_rate: 0.0,
+            duplicate_rate: 0.0,
+            latency_rate: 0.0,
+            latency: Duration::ZERO,
+            enabled: AtomicBool::new(true),
+            calls: AtomicU64::new(0),
+            injected: Default::default(),
+        }
+    }
+
+    pub fn with_substitution(mut self, rate: f32, expert_count: u32) -> Self {
+        self.substitution_rate = clamp_rate(rate);
+        self.expert_count = expert_count.max(1);
+        self
+    }
+
+    pub fn with_empty(mut self, rate: f32) -> Self {
+        self.empty_rate = clamp_rate(rate);
+        self
+    }
+
+    pub fn with_duplicates(mut self, rate: f32) -> Self {
+        self.duplicate_rate = clamp_rate(rate);
+        self
+    }
+
+    pub fn with_latency(mut self, rate: f32, latency: Duration) -> Self {
+        self.latency_rate = clamp_rate(rate);
+        self.latency = latency;
+        self
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn injected(&self, fault: Fault) -> u64 {
+        self.injected[fault as usize].load(Ordering::Relaxed)
+    }
+
+    pub fn injected_counts(&self) -> HashMap<Fault, u64> {
+        FAULTS
+            .iter()
+            .map(|&f| (f, self.injected(f)))
+            .filter(|(_, n)| *n > 0)
+            .collect()
+    }
+
+    // Faults are drawn per call from (seed, call number), so a run with the
+    // same seed and call order injects the same faults.
+    fn disrupt(&self, mut decision: RoutingDecision) -> RoutingDecision {
+        if !self.is_enabled() {
+            return decision;
+        }
+        let call = self.calls.fetch_add(1, Ordering::Relaxed);
+        let base = splitmix64(self.seed ^ splitmix64(call));
+        let draw = |fault: Fault| splitmix64(base ^ (fault as u64 + 1));
+        let hit = |fault: Fault, rate: f32| {
+            let u = (draw(fault) >> 40) as f32 / (1u64 << 24) as f32;
+            let hit = u < rate;
+            if hit {
+                self.injected[fault as usize].fetch_add(1, Ordering::Relaxed);
+            }
+            hit
+        };
+
+        if hit(Fault::Latency, self.latency_rate) {
+            std::thread::sleep(self.latency);
+        }
+        if hit(Fault::Empty, self.empty_rate) {
+            decision.expert_ids.clear();
+            decision.confidence_scores.clear();
+            decision.gating_weights.clear();
+            return decision;
+        }
+        let len = decision.expert_ids.len();
+        if len > 0 && hit(Fault::Substitution, self.substitution_rate) {
+            let r = draw(Fault::Substitution);
+            let slot = (r % len as u64) as usize;
+            let expert = ((r >> 32) % self.expert_count as u64) as u32;
+            decision.expert_ids[slot] = index_expert_id(expert);
+        }
+        if len > 0 && hit(Fault::Duplicate, self.duplicate_rate) {
+            let slot = (draw(Fault::Duplicate) % len as u64) as usize;
+            decision.expert_ids.push(decision.expert_ids[slot].clone());
+            for scores in [
+                &mut decision.confidence_scores,
+                &mut decision.gating_weights,
+            ] {
+                if let Some(&score) = scores.get(slot) {
+                    scores.push(score);
+                }
+            }
+        }
+        decision
+    }
+}
+
+fn clamp_rate(rate: f32) -> f32 {
+    if rate.is_nan() {
+        0.0
+    } else {
+        rate.clamp(0.0, 1.0)
+    }
+}
+
+impl<R: Router> Router for ChaosRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.disrupt(self.inner.route(tier, token_index))
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        self.disrupt(self.inner.route_with_weights(tier, token_index, weights))
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.disrupt(self.inner.route_with_context(ctx))
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        let mut outcome = self.inner.route_outcome(ctx);
+        outcome.decision = self.disrupt(outcome.decision);
+        outcome
+    }
+
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        let explanation = self.inner.explain(ctx);
+        let mut outcome = explanation.outcome.clone();
+        outcome.decision = self.disrupt(outcome.decision);
+        explanation.layer("chaos", outcome)
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for ChaosRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.inner.begin_sequence(seq_id)
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.inner.end_sequence(seq_id)
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.inner.active_sequences()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{validate_decision, DeterministicRouter, RouterConfig};
+
+    #[test]
+    fn test_faults_injected_at_rate_and_reproducible() {
+        let build = || {
+            ChaosRouter::new(DeterministicRouter::new_unchecked(64), 7)
+                .with_empty(0.1)
+                .with_duplicates(0.2)
+                .with_substitution(0.3, 64)
+        };
+        let router = build();
+        let decisions: Vec<RoutingDecision> =
+            (0..1000).map(|t| router.route(Tier::Standard, t)).collect();
+
+        let empty = router.injected(Fault::Empty);
+        assert!((60..140).contains(&empty), "empty = {}", empty);
+        assert!(router.injected(Fault::Duplicate) > 100);
+        assert_eq!(router.injected(Fault::Latency), 0);
+        let config = RouterConfig::new();
+        assert!(decisions
+            .iter()
+            .any(|d| validate_decision(d, &config).is_err()));
+
+        let replay = build();
+        for (t, decision) in decisions.iter().enumerate() {
+            assert_eq!(
+                replay.route(Tier::Standard, t as u64).expert_ids,
+                decision.expert_ids
+            );
+        }
+
+        replay.set_enabled(false);
+        assert_eq!(replay.route(Tier::Standard, 0).expert_ids.len(), 4);
+    }
+}
@@ -0,0 +1,346 @@
+// File: checkpoint.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Gate checkpoint loading for AURIA Runtime Core.
+//     Reads per-expert gate weights straight from safetensors or npz files
+//     exported by training, mapping tensor rows to ExpertIds through a
+//     GateManifest, so GatingRouter can be updated without a conversion step.
+//
+use crate::{CheckpointError, ExpertIndexMap, GatingRouter, SanitationReport};
+use auria_core::ExpertId;
+use std::collections::HashMap;
+use std::io::{Read, Seek};
+use std::path::Path;
+
+// Row `i` of `tensor` holds the gate weight for `experts.expert_id(i)`. The
+// tensor must be `[experts]` or `[experts, 1]`.
+#[derive(Clone, Debug)]
+pub struct GateManifest {
+    pub tensor: String,
+    pub experts: ExpertIndexMap,
+}
+
+impl GateManifest {
+    pub fn new(tensor: impl Into<String>, experts: ExpertIndexMap) -> Self {
+        Self {
+            tensor: tensor.into(),
+            experts,
+        }
+    }
+
+    fn weights(
+        &self,
+        shape: &[usize],
+        values: Vec<f32>,
+    ) -> Result<HashMap<ExpertId, f32>, CheckpointError> {
+        let rows = shape.first().copied().unwrap_or(1);
+        if rows != self.experts.len() || shape.iter().skip(1).any(|&d| d != 1) {
+            return Err(CheckpointError::ShapeMismatch {
+                tensor: self.tensor.clone(),
+                shape: shape.to_vec(),
+                experts: self.experts.len(),
+            });
+        }
+        Ok(self.experts.ids().iter().cloned().zip(values).collect())
+    }
+}
+
+// Dispatches on the file extension: `.safetensors` or `.npz`.
+pub fn read_gate_weights(
+    path: impl AsRef<Path>,
+    manifest: &GateManifest,
+) -> Result<HashMap<ExpertId, f32>, CheckpointError> {
+    let path = path.as_ref();
+    let io = |e: std::io::Error| CheckpointError::Io(format!("{}: {}", path.display(), e));
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("safetensors") => read_safetensors(&std::fs::read(path).map_err(io)?, manifest),
+        Some("npz") => read_npz(std::fs::File::open(path).map_err(io)?, manifest),
+        _ => Err(CheckpointError::Format(format!(
+            "{}: expected a .safetensors or .npz file",
+            path.display()
+        ))),
+    }
+}
+
+pub fn read_safetensors(
+    bytes: &[u8],
+    manifest: &GateManifest,
+) -> Result<HashMap<ExpertId, f32>, CheckpointError> {
+    let format = |msg: &str| CheckpointError::Format(format!("safetensors: {}", msg));
+    let header_len = bytes
+        .get(..8)
+        .map(|b| u64::from_le_bytes(b.try_into().unwrap()) as usize)
+        .ok_or_else(|| format("truncated header"))?;
+    let data_start = 8usize
+        .checked_add(header_len)
+        .filter(|&end| end <= bytes.len())
+        .ok_or_else(|| format("truncated header"))?;
+    let header: serde_json::Value =
+        serde_json::from_slice(&bytes[8..data_start]).map_err(|e| format(&e.to_string()))?;
+    let entry = header
+        .get(&manifest.tensor)
+        .ok_or_else(|| CheckpointError::MissingTensor(manifest.tensor.clone()))?;
+
+    let dtype = entry["dtype"]
+        .as_str()
+        .ok_or_else(|| format("missing dtype"))?;
+    let shape: Vec<usize> = entry["shape"]
+        .as_array()
+        .and_then(|dims| {
+            dims.iter()
+                .map(|d| d.as_u64().map(|d| d as usize))
+                .collect()
+        })
+        .ok_or_else(|| format("bad shape"))?;
+    let offsets: Vec<usize> = entry["data_offsets"]
+        .as_array()
+        .and_then(|o| o.iter().map(|d| d.as_u64().map(|d| d as usize)).collect())
+        .filter(|o: &Vec<usize>| o.len() == 2 && o[0] <= o[1])
+        .ok_or_else(|| format("bad data_offsets"))?;
+    let data = data_start
+        .checked_add(offsets[1])
+        .and_then(|end| bytes.get(data_start + offsets[0]..end))
+        .ok_or_else(|| format("tensor data out of bounds"))?;
+
+    let dtype = match dtype {
+        "F16" => Dtype::F16,
+        "BF16" => Dtype::Bf16,
+        "F32" => Dtype::F32,
+        "F64" => Dtype::F64,
+        other => return Err(CheckpointError::UnsupportedDtype(other.to_string())),
+    };
+    manifest.weights(&shape, decode(dtype, data, &shape)?)
+}
+
+pub fn read_npz<R: Read + Seek>(
+    reader: R,
+    manifest: &GateManifest,
+) -> Result<HashMap<ExpertId, f32>, CheckpointError> {
+    let format = |e: zip::result::ZipError| CheckpointError::Format(format!("npz: {}", e));
+    let mut archive = zip::ZipArchive::new(reader).map_err(format)?;
+    let mut entry = match archive.by_name(&format!("{}.npy", manifest.tensor)) {
+        Ok(entry) => entry,
+        Err(zip::result::ZipError::FileNotFound) => {
+            return Err(CheckpointError::MissingTensor(manifest.tensor.clone()))
+        }
+        Err(e) => return Err(format(e)),
+    };
+    let mut bytes = Vec::with_capacity(entry.size() as usize);
+    entry
+        .read_to_end(&mut bytes)
+        .map_err(|e| CheckpointError::Io(e.to_string()))?;
+    let (dtype, shape, data) = parse_npy(&bytes)?;
+    manifest.weights(&shape, decode(dtype, data, &shape)?)
+}
+
+impl GatingRouter {
+    // Replaces all gate weights with the manifest's tensor, passed through
+    // the router's sanitizer if one is set. On error the current weights
+    // are left untouched.
+    pub fn load_gate_checkpoint(
+        &mut self,
+        path: impl AsRef<Path>,
+        manifest: &GateManifest,
+    ) -> Result<SanitationReport, CheckpointError> {
+        Ok(self.set_gate_weights(read_gate_weights(path, manifest)?))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Dtype {
+    F16,
+    Bf16,
+    F32,
+    F64,
+}
+
+impl Dtype {
+    fn size(self) -> usize {
+        match self {
+            Dtype::F16 | Dtype::Bf16 => 2,
+            Dtype::F32 => 4,
+            Dtype::F64 => 8,
+        }
+    }
+}
+
+// Little-endian only; both formats write little-endian on every platform
+// training runs on.
+fn decode(dtype: Dtype, data: &[u8], shape: &[usize]) -> Result<Vec<f32>, CheckpointError> {
+    let count = shape.iter().try_fold(1usize, |n, &d| n.checked_mul(d));
+    if count.and_then(|n| n.checked_mul(dtype.size())) != Some(data.len()) {
+        return Err(CheckpointError::Format(format!(
+            "{} bytes of data for shape {:?}",
+            data.len(),
+            shape
+        )));
+    }
+    let chunks = data.chunks_exact(dtype.size());
+    Ok(match dtype {
+        Dtype::F16 => chunks
+            .map(|c| f16_to_f32(u16::from_le_bytes([c[0], c[1]])))
+            .collect(),
+        Dtype::Bf16 => chunks
+            .map(|c| f32::from_bits((u16::from_le_bytes([c[0], c[1]]) as u32) << 16))
+            .collect(),
+        Dtype::F32 => chunks
+            .map(|c| f32::from_le_bytes(c.try_into().unwrap()))
+            .collect(),
+        Dtype::F64 => chunks
+            .map(|c| f64::from_le_bytes(c.try_into().unwrap()) as f32)
+            .collect(),
+    })
+}
+
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = ((bits >> 15) as u32) << 31;
+    let exponent = ((bits >> 10) & 0x1f) as u32;
+    let mantissa = (bits & 0x3ff) as u32;
+    let magnitude = match exponent {
+        // Subnormal (or zero): mantissa * 2^-24.
+        0 => (mantissa as f32 * 2f32.powi(-24)).to_bits(),
+        0x1f => 0x7f80_0000 | (mantissa << 13),
+        _ => ((exponent + 112) << 23) | (mantissa << 13),
+    };
+    f32::from_bits(sign | magnitude)
+}
+
+// .npy layout: magic, version, header length, then a Python dict literal
+// such as `{'descr': '<f4', 'fortran_order': False, 'shape': (8,), }`.
+fn parse_npy(bytes: &[u8]) -> Result<(Dtype, Vec<usize>, &[u8]), CheckpointError> {
+    let format = |msg: &str| CheckpointError::Format(format!("npy: {}", msg));
+    if bytes.get(..6) != Some(b"\x93NUMPY".as_slice()) {
+        return Err(format("bad magic"));
+    }
+    let (header_len, header_start) = match bytes.get(6) {
+        Some(1) => (
+            bytes
+                .get(8..10)
+                .map(|b| u16::from_le_bytes([b[0], b[1]]) as usize),
+            10,
+        ),
+        Some(2) | Some(3) => (
+            bytes
+                .get(8..12)
+                .map(|b| u32::from_le_bytes(b.try_into().unwrap()) as usize),
+            12,
+        ),
+        _ => return Err(format("unsupported version")),
+    };
+    let data_start = header_len
+        .map(|len| header_start + len)
+        .filter(|&end| end <= bytes.len())
+        .ok_or_else(|| format("truncated header"))?;
+    let header = std::str::from_utf8(&bytes[header_start..data_start])
+        .map_err(|_| format("header is not utf-8"))?;
+
+    let value = |key: &str| {
+        header
+            .find(&format!("'{}':", key))
+            .map(|at| header[at + key.len() + 3..].trim_start())
+            .ok_or_else(|| format(&format!("missing '{}'", key)))
+    };
+    let descr = value("descr")?;
+    let descr = descr
+        .strip_prefix('\'')
+        .and_then(|d| d.split('\'').next())
+        .ok_or_else(|| format("bad descr"))?;
+    let dtype = match descr {
+        "<f2" => Dtype::F16,
+        "<f4" => Dtype::F32,
+        "<f8" => Dtype::F64,
+        other => return Err(CheckpointError::UnsupportedDtype(other.to_string())),
+    };
+    let shape = value("shape")?;
+    let shape: Vec<usize> = shape
+        .strip_prefix('(')
+        .and_then(|s| s.split(')').next())
+        .ok_or_else(|| format("bad shape"))?
+        .split(',')
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .map(|d| d.parse().map_err(|_| format("bad shape")))
+        .collect::<Result<_, _>>()?;
+    if value("fortran_order")?.starts_with("True") && shape.iter().filter(|&&d| d > 1).count() > 1 {
+        return Err(format("fortran-ordered arrays are not supported"));
+    }
+    Ok((dtype, shape, &bytes[data_start..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, Router};
+    use auria_core::Tier;
+    use std::io::{Cursor, Write};
+
+    fn manifest(experts: u32) -> GateManifest {
+        GateManifest::new("router.gate", (0..experts).map(index_expert_id).collect())
+    }
+
+    fn safetensors(dtype: &str, shape: &[usize], data: &[u8]) -> Vec<u8> {
+        let header = serde_json::json!({
+            "__metadata__": {},
+            "router.gate": {"dtype": dtype, "shape": shape, "data_offsets": [0, data.len()]},
+        })
+        .to_string();
+        let mut bytes = (header.len() as u64).to_le_bytes().to_vec();
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    #[test]
+    fn test_read_safetensors_into_gating_router() {
+        let values = [0.1f32, 2.0, -1.0, 0.5];
+        let data: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let weights = read_safetensors(&safetensors("F32", &[4, 1], &data), &manifest(4)).unwrap();
+        assert_eq!(weights[&index_expert_id(1)], 2.0);
+
+        let mut router = GatingRouter::new_unchecked(1.0);
+        router.set_gate_weights(weights);
+        assert_eq!(
+            router.route(Tier::Nano, 0).expert_ids[0],
+            index_expert_id(1)
+        );
+
+        let half: Vec<u8> = [0x3c00u16, 0xc000]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+        let weights = read_safetensors(&safetensors("F16", &[2], &half), &manifest(2)).unwrap();
+        assert_eq!(weights[&index_expert_id(1)], -2.0);
+
+        let err = read_safetensors(&safetensors("F32", &[4], &data), &manifest(3)).unwrap_err();
+        assert!(matches!(
+            err,
+            CheckpointError::ShapeMismatch { experts: 3, .. }
+        ));
+        let err = read_safetensors(&safetensors("I8", &[4], &data), &manifest(4)).unwrap_err();
+        assert_eq!(err, CheckpointError::UnsupportedDtype("I8".into()));
+    }
+
+    #[test]
+    fn test_read_npz() {
+        let header = "{'descr': '<f8', 'fortran_order': False, 'shape': (3,), }";
+        let mut npy = b"\x93NUMPY\x01\x00".to_vec();
+        npy.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        npy.extend_from_slice(header.as_bytes());
+        npy.extend([0.25f64, -0.5, 4.0].iter().flat_map(|v| v.to_le_bytes()));
+
+        let mut archive = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        archive
+            .start_file("router.gate.npy", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        archive.write_all(&npy).unwrap();
+        let bytes = archive.finish().unwrap().into_inner();
+
+        let weights = read_npz(Cursor::new(&bytes), &manifest(3)).unwrap();
+        assert_eq!(weights[&index_expert_id(2)], 4.0);
+        assert_eq!(weights[&index_expert_id(1)], -0.5);
+
+        let other = GateManifest::new("router.bias", manifest(3).experts);
+        let err = read_npz(Cursor::new(&bytes), &other).unwrap_err();
+        assert_eq!(err, CheckpointError::MissingTensor("router.bias".into()));
+    }
+}
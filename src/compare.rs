@@ -0,0 +1,204 @@
+// File: compare.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Routing divergence comparison for AURIA Runtime Core.
+//     Runs two routers over the same synthetic or recorded workload and
+//     reports overlap@k, the first diverging step, and per-tier mismatch
+//     rates, so refactors can be certified not to change routing.
+//
+use crate::audit::AuditRecord;
+use crate::Router;
+use auria_core::{ExpertId, Tier};
+use std::collections::HashSet;
+
+#[derive(Clone, Debug, Default)]
+pub struct Workload {
+    pub steps: Vec<(Tier, u64)>,
+}
+
+impl Workload {
+    pub fn new(steps: Vec<(Tier, u64)>) -> Self {
+        Self { steps }
+    }
+
+    pub fn synthetic(tiers: &[Tier], tokens: std::ops::Range<u64>) -> Self {
+        let steps = tiers
+            .iter()
+            .flat_map(|&tier| tokens.clone().map(move |token| (tier, token)))
+            .collect();
+        Self { steps }
+    }
+
+    pub fn from_records(records: &[AuditRecord]) -> Self {
+        let steps = records
+            .iter()
+            .filter_map(|r| r.tier().map(|tier| (tier, r.token_index)))
+            .collect();
+        Self { steps }
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Divergence {
+    pub step: usize,
+    pub tier: Tier,
+    pub token_index: u64,
+    pub left: Vec<ExpertId>,
+    pub right: Vec<ExpertId>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierDivergence {
+    pub tier: Tier,
+    pub decisions: usize,
+    pub mismatches: usize,
+}
+
+impl TierDivergence {
+    pub fn mismatch_rate(&self) -> f64 {
+        if self.decisions == 0 {
+            0.0
+        } else {
+            self.mismatches as f64 / self.decisions as f64
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DivergenceReport {
+    pub decisions: usize,
+    pub mismatches: usize,
+    pub mean_overlap_at_k: f64,
+    pub first_divergence: Option<Divergence>,
+    pub per_tier: Vec<TierDivergence>,
+}
+
+impl DivergenceReport {
+    pub fn is_identical(&self) -> bool {
+        self.mismatches == 0
+    }
+
+    pub fn mismatch_rate(&self) -> f64 {
+        if self.decisions == 0 {
+            0.0
+        } else {
+            self.mismatches as f64 / self.decisions as f64
+        }
+    }
+}
+
+// Fraction of the larger selection that both routers agree on, ignoring order.
+pub fn overlap_at_k(left: &[ExpertId], right: &[ExpertId]) -> f64 {
+    let k = left.len().max(right.len());
+    if k == 0 {
+        return 1.0;
+    }
+    let left: HashSet<&ExpertId> = left.iter().collect();
+    let shared = right
+        .iter()
+        .collect::<HashSet<_>>()
+        .intersection(&left)
+        .count();
+    shared as f64 / k as f64
+}
+
+pub fn diff_routers<A: Router + ?Sized, B: Router + ?Sized>(
+    a: &A,
+    b: &B,
+    workload: &Workload,
+) -> DivergenceReport {
+    let mut per_tier: Vec<TierDivergence> = [Tier::Nano, Tier::Standard, Tier::Pro, Tier::Max]
+        .iter()
+        .map(|&tier| TierDivergence {
+            tier,
+            decisions: 0,
+            mismatches: 0,
+        })
+        .collect();
+    let mut mismatches = 0;
+    let mut overlap_sum = 0.0;
+    let mut first_divergence = None;
+
+    for (step, &(tier, token_index)) in workload.steps.iter().enumerate() {
+        let left = a.route(tier, token_index).expert_ids;
+        let right = b.route(tier, token_index).expert_ids;
+        overlap_sum += overlap_at_k(&left, &right);
+
+        let mismatch = left != right;
+        if let Some(stats) = per_tier.iter_mut().find(|s| s.tier == tier) {
+            stats.decisions += 1;
+            stats.mismatches += mismatch as usize;
+        }
+        if mismatch {
+            mismatches += 1;
+            if first_divergence.is_none() {
+                first_divergence = Some(Divergence {
+                    step,
+                    tier,
+                    token_index,
+                    left,
+                    right,
+                });
+            }
+        }
+    }
+
+    let decisions = workload.len();
+    DivergenceReport {
+        decisions,
+        mismatches,
+        mean_overlap_at_k: if decisions == 0 {
+            1.0
+        } else {
+            overlap_sum / decisions as f64
+        },
+        first_divergence,
+        per_tier,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DeterministicRouter, HashRouter};
+
+    #[test]
+    fn test_diff_identical_routers() {
+        let workload = Workload::synthetic(&[Tier::Nano, Tier::Max], 0..100);
+        let report = diff_routers(
+            &DeterministicRouter::new_unchecked(64),
+            &DeterministicRouter::new_unchecked(64),
+            &workload,
+        );
+        assert!(report.is_identical());
+        assert_eq!(report.decisions, 200);
+        assert_eq!(report.mean_overlap_at_k, 1.0);
+        assert!(report.first_divergence.is_none());
+    }
+
+    #[test]
+    fn test_diff_reports_first_divergence_and_tier_rates() {
+        let workload = Workload::synthetic(&[Tier::Nano, Tier::Standard], 0..50);
+        let report = diff_routers(
+            &DeterministicRouter::new_unchecked(64),
+            &HashRouter::new_unchecked(64, 1),
+            &workload,
+        );
+        assert!(!report.is_identical());
+        assert!(report.mean_overlap_at_k < 1.0);
+        assert_eq!(report.first_divergence.as_ref().unwrap().step, 0);
+
+        let nano = &report.per_tier[0];
+        assert_eq!(nano.decisions, 50);
+        assert!(nano.mismatch_rate() > 0.5);
+        assert_eq!(report.per_tier[3].decisions, 0);
+    }
+}
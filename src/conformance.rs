@@ -0,0 +1,303 @@
+// File: conformance.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Reusable conformance checks for Router implementations (feature
+//     "test-support"). ConformanceSuite drives a router over a seeded
+//     stream of contexts and checks determinism, tier-k monotonicity,
+//     duplicate-free decisions, mask respect and capacity respect, so
+//     downstream routers share one set of property tests.
+//
+use crate::{splitmix64, PriorityClass, Router, RoutingContext};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+const TIERS: [Tier; 4] = [Tier::Nano, Tier::Standard, Tier::Pro, Tier::Max];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Property {
+    Determinism,
+    TierMonotonicity,
+    Uniqueness,
+    MaskRespect,
+    CapacityRespect,
+}
+
+#[derive(Clone, Debug)]
+pub struct ConformanceFailure {
+    pub property: Property,
+    pub ctx: RoutingContext,
+    pub message: String,
+}
+
+impl fmt::Display for ConformanceFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} violated at {:?} token {} layer {}: {}",
+            self.property, self.ctx.tier, self.ctx.token_index, self.ctx.layer, self.message
+        )
+    }
+}
+
+impl std::error::Error for ConformanceFailure {}
+
+// Every check replays the same contexts for a given seed, so a failure is
+// reproduced by rerunning with the seed it was found at.
+#[derive(Clone, Debug)]
+pub struct ConformanceSuite {
+    cases: u64,
+    seed: u64,
+}
+
+impl Default for ConformanceSuite {
+    fn default() -> Self {
+        Self {
+            cases: 256,
+            seed: 0,
+        }
+    }
+}
+
+impl ConformanceSuite {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_cases(mut self, cases: u64) -> Self {
+        self.cases = cases.max(1);
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    // Contexts cycle through the tiers; tokens, layers, positions and
+    // priorities are drawn from the seed. No sequence ids are set, so
+    // stateful wrappers are exercised statelessly.
+    pub fn contexts(&self) -> impl Iterator<Item = RoutingContext> + '_ {
+        (0..self.cases).map(move |i| {
+            let r = splitmix64(self.seed ^ i.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+            let priority = match r % 3 {
+                0 => PriorityClass::Interactive,
+                1 => PriorityClass::Standard,
+                _ => PriorityClass::Batch,
+            };
+            RoutingContext::new(TIERS[(i % 4) as usize], splitmix64(r) >> 32)
+                .with_layer((r >> 8) as u32 % 32)
+                .with_sequence_position((r >> 16) % 4096)
+                .with_priority(priority)
+        })
+    }
+
+    // Determinism, tier-k monotonicity and uniqueness.
+    pub fn run<R: Router>(&self, build: impl Fn() -> R) -> Result<(), ConformanceFailure> {
+        self.check_determinism(&build)?;
+        let router = build();
+        self.check_tier_monotonicity(&router)?;
+        self.check_uniqueness(&router)
+    }
+
+    // Two fresh routers fed the same contexts make identical decisions,
+    // compared bitwise on experts and scores; timestamps are ignored.
+    pub fn check_determinism<R: Router>(
+        &self,
+        build: impl Fn() -> R,
+    ) -> Result<(), ConformanceFailure> {
+        let (a, b) = (build(), build());
+        for ctx in self.contexts() {
+            let (x, y) = (a.route_with_context(&ctx), b.route_with_context(&ctx));
+            if fingerprint(&x) != fingerprint(&y) {
+                return Err(failure(
+                    Property::Determinism,
+                    ctx,
+                    format!("{:?} then {:?}", x.expert_ids, y.expert_ids),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    // A higher tier never selects fewer experts than a lower one for the
+    // same context.
+    pub fn check_tier_monotonicity<R: Router + ?Sized>(
+        &self,
+        router: &R,
+    ) -> Result<(), ConformanceFailure> {
+        for ctx in self.contexts() {
+            let mut previous = 0;
+            for tier in TIERS {
+                let mut at = ctx.clone();
+                at.tier = tier;
+                let count = router.route_with_context(&at).expert_ids.len();
+                if count < previous {
+                    return Err(failure(
+                        Property::TierMonotonicity,
+                        at,
+                        format!("{} experts after {} at the tier below", count, previous),
+                    ));
+                }
+                previous = count;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn check_uniqueness<R: Router + ?Sized>(
+        &self,
+        router: &R,
+    ) -> Result<(), ConformanceFailure> {
+        for ctx in self.contexts() {
+            let decision = router.route_with_context(&ctx);
+            let mut seen = HashSet::with_capacity(decision.expert_ids.len());
+            if let Some(id) = decision.expert_ids.iter().find(|id| !seen.insert(*id)) {
+                return Err(failure(
+                    Property::Uniqueness,
+                    ctx,
+                    format!("expert {:?} selected twice", id),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    // `build` configures a router that must never select the experts it is
+    // given. Masks are seeded subsets of roughly a quarter of `experts`.
+    pub fn check_mask_respect<R: Router>(
+        &self,
+        experts: &[ExpertId],
+        build: impl Fn(&[ExpertId]) -> R,
+    ) -> Result<(), ConformanceFailure> {
+        for round in 0..4u64 {
+            let masked: Vec<ExpertId> = experts
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| {
+                    splitmix64(self.seed ^ (round << 32) ^ *i as u64).is_multiple_of(4)
+                })
+                .map(|(_, id)| id.clone())
+                .collect();
+            let router = build(&masked);
+            for ctx in self.contexts() {
+                let decision = router.route_with_context(&ctx);
+                if let Some(id) = decision.expert_ids.iter().find(|id| masked.contains(id)) {
+                    return Err(failure(
+                        Property::MaskRespect,
+                        ctx,
+                        format!("masked expert {:?} selected", id),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // `build` configures a router allowing each expert at most `capacity`
+    // selections; over one pass of the contexts none may exceed it.
+    pub fn check_capacity_respect<R: Router>(
+        &self,
+        capacity: u64,
+        build: impl Fn(u64) -> R,
+    ) -> Result<(), ConformanceFailure> {
+        let router = build(capacity);
+        let mut load: HashMap<ExpertId, u64> = HashMap::new();
+        for ctx in self.contexts() {
+            for id in router.route_with_context(&ctx).expert_ids {
+                let count = load.entry(id.clone()).or_insert(0);
+                *count += 1;
+                if *count > capacity {
+                    return Err(failure(
+                        Property::CapacityRespect,
+                        ctx,
+                        format!(
+                            "expert {:?} selected {} times (capacity {})",
+                            id, count, capacity
+                        ),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn fingerprint(decision: &RoutingDecision) -> (Vec<ExpertId>, Vec<u32>, Vec<u32>) {
+    (
+        decision.expert_ids.clone(),
+        decision
+            .confidence_scores
+            .iter()
+            .map(|c| c.to_bits())
+            .collect(),
+        decision
+            .gating_weights
+            .iter()
+            .map(|w| w.to_bits())
+            .collect(),
+    )
+}
+
+fn failure(property: Property, ctx: RoutingContext, message: String) -> ConformanceFailure {
+    ConformanceFailure {
+        property,
+        ctx,
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        index_expert_id, ChaosRouter, DeterministicRouter, FixedPoint, GatingRouter, PolicyEnv,
+        PolicyRouter,
+    };
+
+    #[test]
+    fn test_core_routers_conform() {
+        let suite = ConformanceSuite::new().with_seed(7);
+        suite
+            .run(|| DeterministicRouter::new_unchecked(64))
+            .unwrap();
+        suite
+            .run(|| {
+                // Float mode breaks gate ties in map order; integer mode
+                // breaks them by id.
+                let mut router = GatingRouter::new_unchecked(1.0);
+                router.set_integer_mode(Some(FixedPoint::default()));
+                for i in 0..32 {
+                    router.set_gate_weight(index_expert_id(i), (i % 7) as f32);
+                }
+                router
+            })
+            .unwrap();
+
+        let experts: Vec<ExpertId> = (0..64).map(index_expert_id).collect();
+        suite
+            .check_mask_respect(&experts, |masked| {
+                let env = PolicyEnv::new()
+                    .with_router("gate", DeterministicRouter::new_unchecked(64))
+                    .with_expert_set("masked", masked.to_vec());
+                PolicyRouter::compile("else topk(gate, exclude=masked)", env).unwrap()
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_violations_are_reported() {
+        let suite = ConformanceSuite::new();
+        let err = suite
+            .run(|| {
+                ChaosRouter::new(DeterministicRouter::new_unchecked(64), 1).with_duplicates(1.0)
+            })
+            .unwrap_err();
+        assert_eq!(err.property, Property::Uniqueness);
+
+        let err = suite
+            .check_capacity_respect(2, |_| DeterministicRouter::new_unchecked(4))
+            .unwrap_err();
+        assert_eq!(err.property, Property::CapacityRespect);
+    }
+}
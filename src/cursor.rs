@@ -0,0 +1,241 @@
+// File: cursor.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Sharded round-robin cursor for AURIA Runtime Core.
+//     A single shared counter is one contended cache line under many
+//     threads. ShardedCursor leases batches of positions from a global
+//     counter into cache-padded stripes; threads bump-allocate windows from
+//     their stripe, so the global counter is touched once per batch and
+//     windows handed to concurrent callers never overlap. Fairness is
+//     approximate: a stripe's unused tail is skipped when it leases again.
+//
+// Under `--cfg loom` the cursor's own atomics are modelled so loom can
+// explore claim interleavings; the thread-slot counter only picks a stripe.
+#[cfg(loom)]
+use loom::sync::atomic::AtomicU64;
+#[cfg(not(loom))]
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub const DEFAULT_SHARD_BATCH: u32 = 256;
+
+// Low bits of a stripe word count positions used in its batch; the rest is
+// the batch index.
+const USED_BITS: u32 = 16;
+const USED_MASK: u64 = (1 << USED_BITS) - 1;
+
+#[repr(align(64))]
+struct Stripe(AtomicU64);
+
+pub struct ShardedCursor {
+    global: AtomicU64,
+    stripes: Box<[Stripe]>,
+    batch: u64,
+}
+
+static NEXT_THREAD: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    // Threads are spread over stripes in arrival order.
+    static THREAD_SLOT: usize = NEXT_THREAD.fetch_add(1, Ordering::Relaxed);
+}
+
+impl ShardedCursor {
+    // `batch` is clamped to 1..=32768 positions.
+    pub fn new(stripes: usize, batch: u32) -> Self {
+        let batch = batch.clamp(1, 1 << (USED_BITS - 1)) as u64;
+        Self {
+            global: AtomicU64::new(0),
+            stripes: (0..stripes.max(1))
+                .map(|_| Stripe(AtomicU64::new(batch)))
+                .collect(),
+            batch,
+        }
+    }
+
+    pub fn stripes(&self) -> usize {
+        self.stripes.len()
+    }
+
+    // Claims `width` consecutive positions and returns the first.
+    pub fn claim(&self, width: usize) -> u64 {
+        let width = width as u64;
+        if width > self.batch {
+            let batches = width.div_ceil(self.batch);
+            return self.global.fetch_add(batches, Ordering::Relaxed) * self.batch;
+        }
+        let slot = THREAD_SLOT.with(|slot| *slot) % self.stripes.len();
+        let stripe = &self.stripes[slot].0;
+        let mut word = stripe.load(Ordering::Relaxed);
+        loop {
+            let used = word & USED_MASK;
+            if used + width <= self.batch {
+                match stripe.compare_exchange_weak(
+                    word,
+                    word + width,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return (word >> USED_BITS) * self.batch + used,
+                    Err(current) => word = current,
+                }
+            } else {
+                let leased = self.global.fetch_add(1, Ordering::Relaxed);
+                let fresh = (leased << USED_BITS) | width;
+                // If another thread refilled the stripe first, keep the head
+                // of the leased batch and let its tail go unused.
+                let _ = stripe.compare_exchange(word, fresh, Ordering::Relaxed, Ordering::Relaxed);
+                return leased * self.batch;
+            }
+        }
+    }
+
+    // Upper bound on positions handed out, suitable for RouterState.
+    pub fn position(&self) -> u64 {
+        self.global.load(Ordering::Relaxed) * self.batch
+    }
+
+    // Continues from `position`, rounded up to a batch boundary. Meant for
+    // restoring state while no thread is claiming: a concurrent claim may
+    // still hand out a window from a batch leased before the reset.
+    pub fn reset(&self, position: u64) {
+        self.global
+            .store(position.div_ceil(self.batch), Ordering::Relaxed);
+        for stripe in self.stripes.iter() {
+            stripe.0.store(self.batch, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use super::*;
+    use crate::{RoundRobinRouter, Router};
+    use auria_core::{ExpertId, Tier};
+
+    #[test]
+    fn test_concurrent_claims_are_disjoint() {
+        let cursor = ShardedCursor::new(4, 64);
+        let mut windows: Vec<(u64, u64)> = std::thread::scope(|s| {
+            let workers: Vec<_> = (0..8)
+                .map(|t| {
+                    let cursor = &cursor;
+                    s.spawn(move || {
+                        (0..2000)
+                            .map(|i| {
+                                let width = 1 + (t + i) % 5;
+                                (cursor.claim(width), width as u64)
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|w| w.join().unwrap())
+                .collect()
+        });
+        windows.sort();
+        for pair in windows.windows(2) {
+            assert!(pair[0].0 + pair[0].1 <= pair[1].0, "{:?}", pair);
+        }
+        let claimed: u64 = windows.iter().map(|w| w.1).sum();
+        assert!(cursor.position() >= claimed);
+    }
+
+    #[test]
+    fn test_sharded_round_robin_is_approximately_fair() {
+        let experts: Vec<ExpertId> = (0..16u8).map(|i| ExpertId([i; 32])).collect();
+        let router = RoundRobinRouter::new_unchecked(experts).with_shards(4);
+        let mut counts = [0usize; 16];
+        std::thread::scope(|s| {
+            let workers: Vec<_> = (0..4)
+                .map(|_| {
+                    let router = &router;
+                    s.spawn(move || {
+                        let mut counts = [0usize; 16];
+                        for token in 0..4096 {
+                            for id in router.route(Tier::Nano, token).expert_ids {
+                                counts[id.0[0] as usize] += 1;
+                            }
+                        }
+                        counts
+                    })
+                })
+                .collect();
+            for worker in workers {
+                for (total, n) in counts.iter_mut().zip(worker.join().unwrap()) {
+                    *total += n;
+                }
+            }
+        });
+        let mean = 4.0 * 4096.0 * 2.0 / 16.0;
+        for n in counts {
+            assert!((n as f64 - mean).abs() / mean < 0.05, "{:?}", counts);
+        }
+
+        let cursor = router.snapshot().round_robin_cursor.unwrap();
+        let restarted =
+            RoundRobinRouter::new_unchecked((0..16u8).map(|i| ExpertId([i; 32])).collect())
+                .with_shards(4);
+        restarted.restore(&router.snapshot());
+        assert_eq!(restarted.snapshot().round_robin_cursor, Some(cursor));
+    }
+}
+
+// RUSTFLAGS="--cfg loom" cargo test --release --lib cursor::loom_tests
+#[cfg(all(test, loom))]
+mod loom_tests {
+    use super::*;
+    use loom::sync::Arc;
+    use loom::thread;
+
+    fn disjoint(mut windows: Vec<(u64, u64)>) -> bool {
+        windows.sort();
+        windows.windows(2).all(|w| w[0].0 + w[0].1 <= w[1].0)
+    }
+
+    // One stripe, so both threads contend on it; widths that overflow the
+    // batch force the refill path, including a lost refill CAS.
+    #[test]
+    fn loom_concurrent_claims_are_disjoint() {
+        loom::model(|| {
+            let cursor = Arc::new(ShardedCursor::new(1, 4));
+            let first = cursor.claim(3);
+            let workers: Vec<_> = (0..2)
+                .map(|_| {
+                    let cursor = cursor.clone();
+                    thread::spawn(move || (cursor.claim(3), cursor.claim(1)))
+                })
+                .collect();
+            let mut windows = vec![(first, 3)];
+            for worker in workers {
+                let (a, b) = worker.join().unwrap();
+                windows.extend([(a, 3), (b, 1)]);
+            }
+            assert!(disjoint(windows));
+        });
+    }
+
+    #[test]
+    fn loom_claims_after_reset_start_at_position() {
+        loom::model(|| {
+            let cursor = Arc::new(ShardedCursor::new(1, 4));
+            cursor.claim(2);
+            cursor.reset(10);
+            assert_eq!(cursor.position(), 12);
+            let workers: Vec<_> = (0..2)
+                .map(|_| {
+                    let cursor = cursor.clone();
+                    thread::spawn(move || cursor.claim(2))
+                })
+                .collect();
+            let windows: Vec<(u64, u64)> = workers
+                .into_iter()
+                .map(|w| (w.join().unwrap(), 2))
+                .collect();
+            assert!(windows.iter().all(|(start, _)| *start >= 12));
+            assert!(disjoint(windows));
+        });
+    }
+}
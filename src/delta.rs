@@ -0,0 +1,194 @@
+// File: delta.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Incremental decision updates for AURIA Runtime Core.
+//     A DecisionDelta records which experts a decision adds and removes
+//     relative to the previous one (plus any weight changes), so the
+//     runtime only loads and unloads what changed between tokens. A
+//     DeltaTracker keeps the last decision per sequence and layer.
+//
+use crate::{decision_entries, decision_from_entries};
+use auria_core::{ExpertId, RoutingDecision};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DecisionDelta {
+    // (expert, confidence, gating weight) in the new decision's order.
+    pub experts_added: Vec<(ExpertId, f32, f32)>,
+    pub experts_removed: Vec<ExpertId>,
+    // Retained experts whose confidence or weight changed.
+    pub reweighted: Vec<(ExpertId, f32, f32)>,
+    pub timestamp: u64,
+}
+
+impl DecisionDelta {
+    pub fn between(previous: &RoutingDecision, next: &RoutingDecision) -> Self {
+        let before: HashMap<ExpertId, (f32, f32)> = decision_entries(previous.clone())
+            .into_iter()
+            .map(|(id, c, w)| (id, (c, w)))
+            .collect();
+        let mut delta = Self {
+            timestamp: next.timestamp,
+            ..Self::default()
+        };
+        for (id, confidence, weight) in decision_entries(next.clone()) {
+            match before.get(&id) {
+                None => delta.experts_added.push((id, confidence, weight)),
+                Some(&(c, w))
+                    if c.to_bits() != confidence.to_bits() || w.to_bits() != weight.to_bits() =>
+                {
+                    delta.reweighted.push((id, confidence, weight))
+                }
+                Some(_) => {}
+            }
+        }
+        delta.experts_removed = previous
+            .expert_ids
+            .iter()
+            .filter(|id| !next.expert_ids.contains(id))
+            .cloned()
+            .collect();
+        delta
+    }
+
+    // A delta that builds `decision` from an empty active set.
+    pub fn initial(decision: &RoutingDecision) -> Self {
+        Self {
+            experts_added: decision_entries(decision.clone()),
+            timestamp: decision.timestamp,
+            ..Self::default()
+        }
+    }
+
+    // True when the active expert set is unchanged, even if weights moved.
+    pub fn is_stable(&self) -> bool {
+        self.experts_added.is_empty() && self.experts_removed.is_empty()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.is_stable() && self.reweighted.is_empty()
+    }
+
+    // Retained experts keep their slots and additions are appended, so the
+    // result has the new decision's experts and weights but not
+    // necessarily its order.
+    pub fn apply(&self, previous: &RoutingDecision) -> RoutingDecision {
+        let mut entries: Vec<(ExpertId, f32, f32)> = decision_entries(previous.clone())
+            .into_iter()
+            .filter(|(id, _, _)| !self.experts_removed.contains(id))
+            .collect();
+        for (id, confidence, weight) in &self.reweighted {
+            if let Some(entry) = entries.iter_mut().find(|(e, _, _)| e == id) {
+                entry.1 = *confidence;
+                entry.2 = *weight;
+            }
+        }
+        entries.extend(self.experts_added.iter().cloned());
+        decision_from_entries(entries, self.timestamp)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DeltaTracker {
+    last: Mutex<HashMap<(u64, u32), RoutingDecision>>,
+}
+
+impl DeltaTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // The change from this sequence's previous decision at `layer`; the
+    // first decision arrives as a delta from nothing.
+    pub fn update(&self, seq_id: u64, layer: u32, decision: &RoutingDecision) -> DecisionDelta {
+        let mut last = self.last.lock().unwrap();
+        let delta = match last.get(&(seq_id, layer)) {
+            Some(previous) => DecisionDelta::between(previous, decision),
+            None => DecisionDelta::initial(decision),
+        };
+        last.insert((seq_id, layer), decision.clone());
+        delta
+    }
+
+    // Drops the sequence and returns every expert it still held, across
+    // layers, so the caller can unload them.
+    pub fn end_sequence(&self, seq_id: u64) -> Vec<ExpertId> {
+        let mut last = self.last.lock().unwrap();
+        let mut released = Vec::new();
+        last.retain(|(seq, _), decision| {
+            if *seq != seq_id {
+                return true;
+            }
+            for id in &decision.expert_ids {
+                if !released.contains(id) {
+                    released.push(id.clone());
+                }
+            }
+            false
+        });
+        released
+    }
+
+    pub fn tracked_sequences(&self) -> usize {
+        let last = self.last.lock().unwrap();
+        let mut seqs: Vec<u64> = last.keys().map(|(seq, _)| *seq).collect();
+        seqs.sort_unstable();
+        seqs.dedup();
+        seqs.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter, Router};
+    use auria_core::Tier;
+
+    #[test]
+    fn test_delta_roundtrips_expert_set() {
+        let router = DeterministicRouter::new_unchecked(64);
+        let a = router.route(Tier::Standard, 0);
+        let b = router.route(Tier::Standard, 2);
+
+        let delta = DecisionDelta::between(&a, &b);
+        assert_eq!(
+            delta.experts_removed,
+            vec![index_expert_id(0), index_expert_id(1)]
+        );
+        let added: Vec<ExpertId> = delta.experts_added.iter().map(|e| e.0.clone()).collect();
+        assert_eq!(added, vec![index_expert_id(4), index_expert_id(5)]);
+        assert!(delta.reweighted.is_empty());
+
+        let patched = delta.apply(&a);
+        let mut got = patched.expert_ids.clone();
+        let mut want = b.expert_ids.clone();
+        got.sort_by_key(|id| id.0);
+        want.sort_by_key(|id| id.0);
+        assert_eq!(got, want);
+        assert!(DecisionDelta::between(&b, &b).is_empty());
+    }
+
+    #[test]
+    fn test_tracker_scopes_by_sequence_and_layer() {
+        let router = DeterministicRouter::new_unchecked(64);
+        let tracker = DeltaTracker::new();
+        let first = tracker.update(1, 0, &router.route(Tier::Nano, 0));
+        assert_eq!(first.experts_added.len(), 2);
+        assert!(tracker
+            .update(1, 0, &router.route(Tier::Nano, 0))
+            .is_empty());
+        assert_eq!(
+            tracker
+                .update(1, 1, &router.route(Tier::Nano, 1))
+                .experts_added
+                .len(),
+            2
+        );
+        assert_eq!(tracker.tracked_sequences(), 1);
+
+        let released = tracker.end_sequence(1);
+        assert_eq!(released.len(), 3);
+        assert_eq!(tracker.tracked_sequences(), 0);
+    }
+}
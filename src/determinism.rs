@@ -0,0 +1,304 @@
+// File: determinism.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Routing determinism certification for AURIA Runtime Core.
+//     A replicated DeterminismGuard routes every call through two routers
+//     built the same way (by default in debug builds) and panics unless both
+//     are bitwise identical, and keeps a rolling digest of all decisions so a
+//     second machine's recorded digest can be cross-checked.
+//
+use crate::{RouteError, Router, RouterState, RoutingContext, RoutingOutcome, StatefulRouter};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecisionDigest {
+    pub decisions: u64,
+    pub hash: u64,
+}
+
+impl DecisionDigest {
+    // Timestamps are excluded: they are wall-clock, not routing output.
+    pub fn update(&mut self, tier: Tier, token_index: u64, decision: &RoutingDecision) {
+        let mut bytes = Vec::with_capacity(16 + decision.expert_ids.len() * 40);
+        bytes.push(match tier {
+            Tier::Nano => 0,
+            Tier::Standard => 1,
+            Tier::Pro => 2,
+            Tier::Max => 3,
+        });
+        bytes.extend_from_slice(&token_index.to_le_bytes());
+        bytes.extend_from_slice(&(decision.expert_ids.len() as u32).to_le_bytes());
+        for id in &decision.expert_ids {
+            bytes.extend_from_slice(&id.0);
+        }
+        for score in decision
+            .gating_weights
+            .iter()
+            .chain(&decision.confidence_scores)
+        {
+            bytes.extend_from_slice(&score.to_bits().to_le_bytes());
+        }
+        self.hash = xxhash_rust::xxh64::xxh64(&bytes, self.hash);
+        self.decisions += 1;
+    }
+}
+
+pub struct DeterminismGuard<R: Router> {
+    inner: R,
+    // A twin built the same way as `inner`; every call is replayed on it.
+    replica: Option<R>,
+    reexecute: bool,
+    digest: Mutex<DecisionDigest>,
+}
+
+impl<R: Router> DeterminismGuard<R> {
+    // Digest only: a single router cannot be re-executed faithfully, since
+    // state outside RouterState (load windows, token buckets, RNGs) would
+    // see every call twice.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            replica: None,
+            reexecute: false,
+            digest: Mutex::new(DecisionDigest::default()),
+        }
+    }
+
+    // Builds the router twice and checks both agree on every call, as
+    // ConformanceSuite::check_determinism does. `build` must not share
+    // mutable state between the two, e.g. one Arc<LoadWindow>.
+    pub fn replicated(build: impl Fn() -> R) -> Self {
+        Self {
+            inner: build(),
+            replica: Some(build()),
+            reexecute: cfg!(debug_assertions),
+            digest: Mutex::new(DecisionDigest::default()),
+        }
+    }
+
+    // Has no effect on a guard without a replica.
+    pub fn with_reexecution(mut self, reexecute: bool) -> Self {
+        self.reexecute = reexecute;
+        self
+    }
+
+    pub fn digest(&self) -> DecisionDigest {
+        *self.digest.lock().unwrap()
+    }
+
+    pub fn reset_digest(&self) {
+        *self.digest.lock().unwrap() = DecisionDigest::default();
+    }
+
+    pub fn check_against(&self, expected: &DecisionDigest) -> Result<(), RouteError> {
+        let actual = self.digest();
+        if actual == *expected {
+            Ok(())
+        } else {
+            Err(RouteError::DigestMismatch {
+                expected: *expected,
+                actual,
+            })
+        }
+    }
+
+    fn certify(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        run: impl Fn(&R) -> RoutingDecision,
+    ) -> RoutingDecision {
+        self.certify_with(tier, token_index, run, |decision| decision)
+    }
+
+    // `decision` picks the certified decision out of whatever `run` returns,
+    // so outcomes are certified from a single call per router.
+    fn certify_with<T>(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        run: impl Fn(&R) -> T,
+        decision: impl Fn(&T) -> &RoutingDecision,
+    ) -> T {
+        let result = run(&self.inner);
+        if let Some(replica) = self.replica.as_ref().filter(|_| self.reexecute) {
+            let twin = run(replica);
+            let (a, b) = (decision(&result), decision(&twin));
+            if !bitwise_eq(a, b) {
+                panic!(
+                    "routing determinism violated for {:?} token {}: {:?} vs {:?}",
+                    tier, token_index, a.expert_ids, b.expert_ids
+                );
+            }
+        }
+        self.digest
+            .lock()
+            .unwrap()
+            .update(tier, token_index, decision(&result));
+        result
+    }
+}
+
+fn bitwise_eq(a: &RoutingDecision, b: &RoutingDecision) -> bool {
+    let bits = |scores: &[f32]| scores.iter().map(|s| s.to_bits()).collect::<Vec<_>>();
+    a.expert_ids == b.expert_ids
+        && bits(&a.gating_weights) == bits(&b.gating_weights)
+        && bits(&a.confidence_scores) == bits(&b.confidence_scores)
+}
+
+impl<R: Router> Router for DeterminismGuard<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.certify(tier, token_index, |r| r.route(tier, token_index))
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        self.certify(tier, token_index, |r| {
+            r.route_with_weights(tier, token_index, weights)
+        })
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.certify(ctx.tier, ctx.token_index, |r| r.route_with_context(ctx))
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        self.certify_with(
+            ctx.tier,
+            ctx.token_index,
+            |r| r.route_outcome(ctx),
+            |outcome| &outcome.decision,
+        )
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state);
+        if let Some(replica) = &self.replica {
+            replica.restore(state);
+        }
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for DeterminismGuard<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.inner.begin_sequence(seq_id);
+        if let Some(replica) = &self.replica {
+            replica.begin_sequence(seq_id);
+        }
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.inner.end_sequence(seq_id);
+        if let Some(replica) = &self.replica {
+            replica.end_sequence(seq_id);
+        }
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.inner.active_sequences()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        index_expert_id, DeterministicRouter, FairRouter, RoundRobinRouter, ShareBounds,
+        TransformedRouter,
+    };
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    #[test]
+    fn test_digest_matches_across_replicas() {
+        let experts: Vec<ExpertId> = (0..16).map(index_expert_id).collect();
+        let a = DeterminismGuard::replicated(|| RoundRobinRouter::new_unchecked(experts.clone()))
+            .with_reexecution(true);
+        let b = DeterminismGuard::new(RoundRobinRouter::new_unchecked(experts.clone()));
+        for token in 0..50 {
+            assert_eq!(
+                a.route(Tier::Pro, token).expert_ids,
+                b.route(Tier::Pro, token).expert_ids
+            );
+        }
+        assert_eq!(a.digest().decisions, 50);
+        assert!(a.check_against(&b.digest()).is_ok());
+
+        b.route(Tier::Pro, 50);
+        assert!(matches!(
+            a.check_against(&b.digest()),
+            Err(RouteError::DigestMismatch { .. })
+        ));
+        let c = DeterminismGuard::new(DeterministicRouter::new_unchecked(16));
+        c.route(Tier::Pro, 0);
+        assert_ne!(
+            c.digest().hash,
+            DeterminismGuard::new(DeterministicRouter::new_unchecked(8))
+                .digest()
+                .hash
+        );
+    }
+
+    #[test]
+    fn test_outcomes_are_certified_once() {
+        let experts: Vec<ExpertId> = (0..16).map(index_expert_id).collect();
+        let a = DeterminismGuard::replicated(|| RoundRobinRouter::new_unchecked(experts.clone()))
+            .with_reexecution(true);
+        let b = DeterminismGuard::new(RoundRobinRouter::new_unchecked(experts.clone()));
+        for token in 0..8 {
+            let ctx = RoutingContext::new(Tier::Standard, token);
+            assert_eq!(
+                a.route_outcome(&ctx).decision.expert_ids,
+                b.route_with_context(&ctx).expert_ids
+            );
+        }
+        assert_eq!(a.digest(), b.digest());
+    }
+
+    #[test]
+    fn test_state_outside_snapshot_is_not_replayed() {
+        // The fairness window lives outside RouterState; each replica keeps
+        // its own, so ceiling replacements are not applied twice.
+        let guard = DeterminismGuard::replicated(|| {
+            let router = FairRouter::new(DeterministicRouter::new_unchecked(64), 32);
+            router.set_bounds(index_expert_id(0), ShareBounds::ceiling(0.1));
+            router
+        })
+        .with_reexecution(true);
+        for token in 0..64 {
+            guard.route_outcome(&RoutingContext::new(Tier::Standard, token % 4));
+        }
+        assert_eq!(guard.digest().decisions, 64);
+    }
+
+    #[test]
+    #[should_panic(expected = "routing determinism violated")]
+    fn test_reexecution_catches_nondeterminism() {
+        // Both replicas read one process-wide counter, like a wall clock.
+        static CALLS: AtomicU64 = AtomicU64::new(0);
+        let guard = DeterminismGuard::replicated(|| {
+            TransformedRouter::new(DeterministicRouter::new_unchecked(64)).with_transform(
+                |_: &RoutingContext, mut decision: RoutingDecision| {
+                    let shift = CALLS.fetch_add(1, Ordering::Relaxed) as usize;
+                    let len = decision.expert_ids.len();
+                    decision.expert_ids.rotate_left(shift % len);
+                    decision
+                },
+            )
+        })
+        .with_reexecution(true);
+        for token in 0..64 {
+            guard.route(Tier::Max, token);
+        }
+    }
+}
@@ -0,0 +1,349 @@
+// File: dispatch.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Dispatch planning for MoE kernels in AURIA Runtime Core.
+//     A DispatchPlanner streams in a batch of RoutingDecisions and emits the
+//     scatter/gather plan: per-expert token lists (with capacity truncation
+//     and padding to a kernel-friendly multiple) and, per token, where its
+//     expert outputs land so they can be combined. A DropPolicy chooses
+//     which assignments an over-capacity expert gives up.
+//
+use crate::ExpertIndexMap;
+use auria_core::{ExpertId, RoutingDecision};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExpertDispatch {
+    pub expert_id: ExpertId,
+    // Batch positions of the tokens this expert processes, in arrival order.
+    pub tokens: Vec<usize>,
+    pub gating_weights: Vec<f32>,
+    // `tokens.len()` rounded up to the planner's padding multiple.
+    pub padded_len: usize,
+}
+
+impl ExpertDispatch {
+    pub fn padding(&self) -> usize {
+        self.padded_len - self.tokens.len()
+    }
+}
+
+// Where one of a token's expert outputs lives: expert slot in the plan and
+// row within that expert's buffer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CombineEntry {
+    pub expert: usize,
+    pub row: usize,
+    pub weight: f32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DispatchPlan {
+    pub experts: Vec<ExpertDispatch>,
+    pub combine: Vec<Vec<CombineEntry>>,
+    // (batch position, expert) assignments cut by the capacity limit.
+    pub overflow: Vec<(usize, ExpertId)>,
+    // (batch position, original expert) assignments moved to the overflow
+    // expert under DropPolicy::OverflowExpert.
+    pub redirected: Vec<(usize, ExpertId)>,
+}
+
+impl DispatchPlan {
+    pub fn tokens(&self) -> usize {
+        self.combine.len()
+    }
+
+    pub fn total_padding(&self) -> usize {
+        self.experts.iter().map(ExpertDispatch::padding).sum()
+    }
+}
+
+// Which assignments an over-capacity expert gives up.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum DropPolicy {
+    #[default]
+    DropNewest,
+    DropLowestScore,
+    // Lowest priority class first, newest first within a class.
+    DropByPriority,
+    // Excess assignments go to this expert, which is exempt from capacity.
+    OverflowExpert(ExpertId),
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Assignment {
+    token: usize,
+    // Position within the token's decision, so combine order is preserved.
+    rank: usize,
+    weight: f32,
+    priority: u8,
+}
+
+#[derive(Clone, Debug)]
+pub struct DispatchPlanner {
+    capacity: Option<usize>,
+    pad_to: usize,
+    policy: DropPolicy,
+    slots: HashMap<ExpertId, usize>,
+    experts: Vec<ExpertId>,
+    queues: Vec<Vec<Assignment>>,
+    tokens: usize,
+}
+
+impl Default for DispatchPlanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DispatchPlanner {
+    pub fn new() -> Self {
+        Self {
+            capacity: None,
+            pad_to: 1,
+            policy: DropPolicy::default(),
+            slots: HashMap::new(),
+            experts: Vec::new(),
+            queues: Vec::new(),
+            tokens: 0,
+        }
+    }
+
+    // Experts receive slots in registry order (including idle experts)
+    // instead of first-appearance order.
+    pub fn with_registry(mut self, registry: &ExpertIndexMap) -> Self {
+        for id in registry.ids() {
+            self.slot(id);
+        }
+        self
+    }
+
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    pub fn with_padding(mut self, multiple: usize) -> Self {
+        self.pad_to = multiple.max(1);
+        self
+    }
+
+    pub fn with_drop_policy(mut self, policy: DropPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn push(&mut self, decision: &RoutingDecision) {
+        self.push_with_priority(decision, 0);
+    }
+
+    // Higher `priority` classes are kept first under DropPolicy::DropByPriority.
+    pub fn push_with_priority(&mut self, decision: &RoutingDecision, priority: u8) {
+        let token = self.tokens;
+        self.tokens += 1;
+        for (rank, id) in decision.expert_ids.iter().enumerate() {
+            let slot = self.slot(id);
+            self.queues[slot].push(Assignment {
+                token,
+                rank,
+                weight: decision.gating_weights.get(rank).copied().unwrap_or(1.0),
+                priority,
+            });
+        }
+    }
+
+    pub fn extend<'a>(&mut self, decisions: impl IntoIterator<Item = &'a RoutingDecision>) {
+        for decision in decisions {
+            self.push(decision);
+        }
+    }
+
+    pub fn finish(mut self) -> DispatchPlan {
+        let mut overflow = Vec::new();
+        let mut redirected = Vec::new();
+        if let Some(capacity) = self.capacity {
+            let spill_slot = match &self.policy {
+                DropPolicy::OverflowExpert(id) => Some(self.slot(&id.clone())),
+                _ => None,
+            };
+            for slot in 0..self.queues.len() {
+                if Some(slot) == spill_slot || self.queues[slot].len() <= capacity {
+                    continue;
+                }
+                let excess = self.shed(slot, capacity);
+                for assignment in excess {
+                    let id = self.experts[slot].clone();
+                    match spill_slot {
+                        Some(spill)
+                            if !self.queues[spill]
+                                .iter()
+                                .any(|a| a.token == assignment.token) =>
+                        {
+                            self.queues[spill].push(assignment);
+                            redirected.push((assignment.token, assignment.rank, id));
+                        }
+                        _ => overflow.push((assignment.token, assignment.rank, id)),
+                    }
+                }
+            }
+        }
+        // Report in batch order, then decision order within a token.
+        let ordered = |mut entries: Vec<(usize, usize, ExpertId)>| {
+            entries.sort_by_key(|(token, rank, _)| (*token, *rank));
+            entries
+                .into_iter()
+                .map(|(token, _, id)| (token, id))
+                .collect()
+        };
+
+        let mut combine: Vec<Vec<(usize, CombineEntry)>> = vec![Vec::new(); self.tokens];
+        let mut experts = Vec::with_capacity(self.experts.len());
+        for (slot, (expert_id, mut queue)) in self.experts.into_iter().zip(self.queues).enumerate()
+        {
+            queue.sort_by_key(|a| a.token);
+            for (row, a) in queue.iter().enumerate() {
+                combine[a.token].push((
+                    a.rank,
+                    CombineEntry {
+                        expert: slot,
+                        row,
+                        weight: a.weight,
+                    },
+                ));
+            }
+            experts.push(ExpertDispatch {
+                expert_id,
+                padded_len: queue.len().div_ceil(self.pad_to) * self.pad_to,
+                tokens: queue.iter().map(|a| a.token).collect(),
+                gating_weights: queue.iter().map(|a| a.weight).collect(),
+            });
+        }
+
+        DispatchPlan {
+            experts,
+            combine: combine
+                .into_iter()
+                .map(|mut entries| {
+                    entries.sort_by_key(|(rank, _)| *rank);
+                    entries.into_iter().map(|(_, entry)| entry).collect()
+                })
+                .collect(),
+            overflow: ordered(overflow),
+            redirected: ordered(redirected),
+        }
+    }
+
+    pub fn plan(mut self, decisions: &[RoutingDecision]) -> DispatchPlan {
+        self.extend(decisions);
+        self.finish()
+    }
+
+    // Keeps `capacity` assignments in the slot's queue per the drop policy
+    // and returns the rest.
+    fn shed(&mut self, slot: usize, capacity: usize) -> Vec<Assignment> {
+        let queue = &mut self.queues[slot];
+        match self.policy {
+            DropPolicy::DropNewest | DropPolicy::OverflowExpert(_) => {}
+            DropPolicy::DropLowestScore => queue
+                .sort_by(|a, b| crate::descending(a.weight, b.weight).then(a.token.cmp(&b.token))),
+            DropPolicy::DropByPriority => {
+                queue.sort_by(|a, b| b.priority.cmp(&a.priority).then(a.token.cmp(&b.token)))
+            }
+        }
+        queue.split_off(capacity)
+    }
+
+    fn slot(&mut self, id: &ExpertId) -> usize {
+        if let Some(&slot) = self.slots.get(id) {
+            return slot;
+        }
+        let slot = self.experts.len();
+        self.slots.insert(id.clone(), slot);
+        self.experts.push(id.clone());
+        self.queues.push(Vec::new());
+        slot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter, Router};
+    use auria_core::Tier;
+
+    #[test]
+    fn test_plan_transposes_decisions() {
+        let router = DeterministicRouter::new_unchecked(4);
+        let decisions: Vec<RoutingDecision> = (0..4).map(|t| router.route(Tier::Nano, t)).collect();
+        let plan = DispatchPlanner::new().with_padding(4).plan(&decisions);
+
+        assert_eq!(plan.tokens(), 4);
+        assert_eq!(plan.experts.len(), 4);
+        let first = &plan.experts[0];
+        assert_eq!(first.expert_id, index_expert_id(0));
+        assert_eq!(first.tokens, vec![0, 3]);
+        assert_eq!(first.padded_len, 4);
+        assert_eq!(plan.total_padding(), 8);
+
+        for (position, entries) in plan.combine.iter().enumerate() {
+            for entry in entries {
+                assert_eq!(plan.experts[entry.expert].tokens[entry.row], position);
+            }
+        }
+    }
+
+    #[test]
+    fn test_capacity_truncates_and_registry_orders() {
+        let registry: ExpertIndexMap = (0..8).rev().map(index_expert_id).collect();
+        let router = DeterministicRouter::new_unchecked(8);
+        let decisions = vec![router.route(Tier::Nano, 0); 3];
+
+        let mut planner = DispatchPlanner::new()
+            .with_registry(&registry)
+            .with_capacity(2);
+        planner.extend(&decisions);
+        let plan = planner.finish();
+
+        assert_eq!(plan.experts.len(), 8);
+        assert_eq!(plan.experts[7].expert_id, index_expert_id(0));
+        assert_eq!(plan.experts[7].tokens, vec![0, 1]);
+        assert_eq!(plan.overflow.len(), 2);
+        assert_eq!(plan.overflow[0], (2, index_expert_id(0)));
+        assert!(plan.combine[2].is_empty());
+    }
+
+    #[test]
+    fn test_drop_policies() {
+        let hot = index_expert_id(0);
+        let decision = |weight: f32| RoutingDecision {
+            expert_ids: vec![hot.clone()],
+            confidence_scores: vec![weight],
+            gating_weights: vec![weight],
+            timestamp: 0,
+        };
+        let decisions = [decision(0.9), decision(0.2), decision(0.7)];
+        let kept = |planner: DispatchPlanner| {
+            let mut planner = planner.with_capacity(2);
+            for (i, d) in decisions.iter().enumerate() {
+                planner.push_with_priority(d, [1, 2, 0][i]);
+            }
+            planner.finish()
+        };
+
+        assert_eq!(kept(DispatchPlanner::new()).experts[0].tokens, vec![0, 1]);
+        let lowest = kept(DispatchPlanner::new().with_drop_policy(DropPolicy::DropLowestScore));
+        assert_eq!(lowest.experts[0].tokens, vec![0, 2]);
+        assert_eq!(lowest.overflow, vec![(1, hot.clone())]);
+        let priority = kept(DispatchPlanner::new().with_drop_policy(DropPolicy::DropByPriority));
+        assert_eq!(priority.experts[0].tokens, vec![0, 1]);
+
+        let spill = index_expert_id(63);
+        let plan = kept(
+            DispatchPlanner::new().with_drop_policy(DropPolicy::OverflowExpert(spill.clone())),
+        );
+        assert!(plan.overflow.is_empty());
+        assert_eq!(plan.redirected, vec![(2, hot)]);
+        assert_eq!(plan.experts[1].expert_id, spill);
+        assert_eq!(plan.combine[2][0].expert, 1);
+    }
+}
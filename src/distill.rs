@@ -0,0 +1,236 @@
+// File: distill.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Gate distillation utilities for AURIA Runtime Core.
+//     Fits a cheap router (a seeded HashRouter or a bucketed LookupRouter)
+//     to a recorded trace of an expensive router's decisions and reports
+//     the agreement achieved, so edge tiers can switch to cheap routing
+//     with a measured overlap@k.
+//
+use crate::audit::AuditRecord;
+use crate::compare::overlap_at_k;
+use crate::{
+    decision_from_entries, HashRouter, KSelector, Router, RoutingContext, RoutingOutcome, TierK,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::HashMap;
+use std::ops::Range;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistillReport {
+    pub records: usize,
+    pub mean_overlap_at_k: f64,
+    // Fraction of records where the expert sets match, ignoring order.
+    pub exact_match_rate: f64,
+}
+
+// Replays every record with a known tier through `router` and measures
+// agreement with the recorded selection.
+pub fn evaluate<R: Router + ?Sized>(router: &R, trace: &[AuditRecord]) -> DistillReport {
+    let mut records = 0;
+    let mut overlap_sum = 0.0;
+    let mut exact = 0;
+    for record in trace {
+        let Some(tier) = record.tier() else {
+            continue;
+        };
+        let expected: Vec<ExpertId> = record.expert_ids.iter().map(|id| ExpertId(*id)).collect();
+        let actual = router.route(tier, record.token_index).expert_ids;
+        let overlap = overlap_at_k(&expected, &actual);
+        records += 1;
+        overlap_sum += overlap;
+        exact += (overlap == 1.0) as usize;
+    }
+    DistillReport {
+        records,
+        mean_overlap_at_k: if records == 0 {
+            1.0
+        } else {
+            overlap_sum / records as f64
+        },
+        exact_match_rate: if records == 0 {
+            1.0
+        } else {
+            exact as f64 / records as f64
+        },
+    }
+}
+
+// Picks the seed in `seeds` whose HashRouter agrees best with the trace.
+// Earlier seeds win ties, so the result is reproducible.
+pub fn fit_hash_router(
+    trace: &[AuditRecord],
+    expert_count: u32,
+    seeds: Range<u64>,
+) -> (HashRouter, DistillReport) {
+    let mut best: Option<(u64, DistillReport)> = None;
+    for seed in seeds {
+        let report = evaluate(&HashRouter::new_unchecked(expert_count, seed), trace);
+        if best
+            .as_ref()
+            .is_none_or(|(_, b)| report.mean_overlap_at_k > b.mean_overlap_at_k)
+        {
+            best = Some((seed, report));
+        }
+    }
+    let (seed, report) = best.unwrap_or_else(|| {
+        (
+            0,
+            evaluate(&HashRouter::new_unchecked(expert_count, 0), trace),
+        )
+    });
+    (HashRouter::new_unchecked(expert_count, seed), report)
+}
+
+pub fn fit_lookup_router(
+    trace: &[AuditRecord],
+    bucket_count: u64,
+) -> (LookupRouter, DistillReport) {
+    let router = LookupRouter::fit(trace, bucket_count);
+    let report = evaluate(&router, trace);
+    (router, report)
+}
+
+// Routes by `token_index % bucket_count` into a fitted, ranked expert table.
+pub struct LookupRouter {
+    // (expert, mean recorded gating weight), best first.
+    buckets: Vec<Vec<(ExpertId, f32)>>,
+    // Global ranking used to fill buckets that run short.
+    fallback: Vec<(ExpertId, f32)>,
+    k_selector: Box<dyn KSelector>,
+}
+
+#[derive(Default)]
+struct Votes {
+    score: HashMap<ExpertId, (f64, f32, u32)>,
+}
+
+impl Votes {
+    // Earlier ranks carry more weight, so records from larger tiers do not
+    // drown out the experts every tier agrees on.
+    fn add(&mut self, record: &AuditRecord) {
+        let k = record.expert_ids.len() as f64;
+        for (rank, id) in record.expert_ids.iter().enumerate() {
+            let weight = record.gating_weights.get(rank).copied().unwrap_or(1.0);
+            let entry = self.score.entry(ExpertId(*id)).or_insert((0.0, 0.0, 0));
+            entry.0 += (k - rank as f64) / k;
+            entry.1 += weight;
+            entry.2 += 1;
+        }
+    }
+
+    fn ranked(self) -> Vec<(ExpertId, f32)> {
+        let mut ranked: Vec<(ExpertId, f64, f32)> = self
+            .score
+            .into_iter()
+            .map(|(id, (score, weight_sum, n))| (id, score, weight_sum / n as f32))
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0 .0.cmp(&b.0 .0)));
+        ranked.into_iter().map(|(id, _, w)| (id, w)).collect()
+    }
+}
+
+impl LookupRouter {
+    pub fn fit(trace: &[AuditRecord], bucket_count: u64) -> Self {
+        let bucket_count = bucket_count.max(1);
+        let mut buckets: Vec<Votes> = (0..bucket_count).map(|_| Votes::default()).collect();
+        let mut global = Votes::default();
+        for record in trace.iter().filter(|r| r.tier().is_some()) {
+            buckets[(record.token_index % bucket_count) as usize].add(record);
+            global.add(record);
+        }
+        Self {
+            buckets: buckets.into_iter().map(Votes::ranked).collect(),
+            fallback: global.ranked(),
+            k_selector: Box::new(TierK),
+        }
+    }
+
+    pub fn with_k_selector(mut self, k_selector: impl KSelector + 'static) -> Self {
+        self.k_selector = Box::new(k_selector);
+        self
+    }
+
+    pub fn bucket_count(&self) -> u64 {
+        self.buckets.len() as u64
+    }
+
+    // The fitted ranking for a token's bucket, best first.
+    pub fn table(&self, token_index: u64) -> &[(ExpertId, f32)] {
+        &self.buckets[(token_index % self.bucket_count()) as usize]
+    }
+}
+
+impl Router for LookupRouter {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        let k = self.k_selector.k(tier, token_index) as usize;
+        let mut entries: Vec<(ExpertId, f32, f32)> = Vec::with_capacity(k);
+        for (id, weight) in self.table(token_index).iter().chain(&self.fallback) {
+            if entries.len() == k {
+                break;
+            }
+            if !entries.iter().any(|(e, _, _)| e == id) {
+                entries.push((id.clone(), 1.0, *weight));
+            }
+        }
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        decision_from_entries(entries, timestamp)
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        _weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        self.route(tier, token_index)
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        let k = self.k_selector.k(ctx.tier, ctx.token_index);
+        RoutingOutcome::new(self.route_with_context(ctx), k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DeterministicRouter;
+
+    fn trace() -> Vec<AuditRecord> {
+        let teacher = DeterministicRouter::new_unchecked(64);
+        [Tier::Nano, Tier::Standard]
+            .iter()
+            .flat_map(|&tier| (0..256).map(move |token| (tier, token)))
+            .map(|(tier, token)| {
+                AuditRecord::from_decision(tier, token, &teacher.route(tier, token))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_lookup_router_recovers_periodic_teacher() {
+        let trace = trace();
+        let (router, report) = fit_lookup_router(&trace, 64);
+        assert_eq!(report.records, 512);
+        assert_eq!(report.exact_match_rate, 1.0);
+        assert_eq!(router.bucket_count(), 64);
+
+        let (_, coarse) = fit_lookup_router(&trace, 8);
+        assert!(coarse.mean_overlap_at_k < report.mean_overlap_at_k);
+    }
+
+    #[test]
+    fn test_hash_fit_picks_best_seed() {
+        let trace = trace();
+        let (router, best) = fit_hash_router(&trace, 64, 0..16);
+        for seed in 0..16 {
+            let report = evaluate(&HashRouter::new_unchecked(64, seed), &trace);
+            assert!(report.mean_overlap_at_k <= best.mean_overlap_at_k);
+        }
+        assert_eq!(evaluate(&router, &trace), best);
+    }
+}
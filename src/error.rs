@@ -0,0 +1,319 @@
+// File: error.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Error types for AURIA Runtime Core routing.
+//
+use crate::DecisionDigest;
+use auria_core::{ExpertId, Tier};
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RouteError {
+    EmptyDecision {
+        tier: Tier,
+        token_index: u64,
+    },
+    DuplicateExpert {
+        tier: Tier,
+        token_index: u64,
+        expert_id: ExpertId,
+    },
+    TooManyExperts {
+        tier: Tier,
+        token_index: u64,
+        count: usize,
+        limit: usize,
+    },
+    ExpertSetOutOfRange {
+        observed: usize,
+        expected: usize,
+    },
+    DigestMismatch {
+        expected: DecisionDigest,
+        actual: DecisionDigest,
+    },
+}
+
+impl fmt::Display for RouteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RouteError::EmptyDecision { tier, token_index } => {
+                write!(
+                    f,
+                    "empty routing decision for {:?} token {}",
+                    tier, token_index
+                )
+            }
+            RouteError::DuplicateExpert {
+                tier,
+                token_index,
+                expert_id,
+            } => write!(
+                f,
+                "duplicate expert {:?} for {:?} token {}",
+                expert_id, tier, token_index
+            ),
+            RouteError::TooManyExperts {
+                tier,
+                token_index,
+                count,
+                limit,
+            } => write!(
+                f,
+                "{} experts selected for {:?} token {} (limit {})",
+                count, tier, token_index, limit
+            ),
+            RouteError::ExpertSetOutOfRange { observed, expected } => write!(
+                f,
+                "router produced {} distinct experts but only {} are expected",
+                observed, expected
+            ),
+            RouteError::DigestMismatch { expected, actual } => write!(
+                f,
+                "decision digest {:016x} after {} decisions does not match expected {:016x} after {}",
+                actual.hash, actual.decisions, expected.hash, expected.decisions
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RouteError {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum WeightVersionError {
+    UnknownVersion(u64),
+    VersionExists(u64),
+    VersionActive(u64),
+}
+
+impl fmt::Display for WeightVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeightVersionError::UnknownVersion(v) => {
+                write!(f, "weight version {} not published", v)
+            }
+            WeightVersionError::VersionExists(v) => {
+                write!(f, "weight version {} already published", v)
+            }
+            WeightVersionError::VersionActive(v) => {
+                write!(f, "weight version {} is active and cannot be retired", v)
+            }
+        }
+    }
+}
+
+impl std::error::Error for WeightVersionError {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum WireError {
+    BadMagic,
+    UnsupportedVersion(u8),
+    RegistryMismatch { expected: usize, actual: usize },
+    UnknownExpert(ExpertId),
+    IndexOutOfRange(u64),
+    UnexpectedCount(usize),
+    TrailingBytes(usize),
+    Truncated,
+}
+
+impl fmt::Display for WireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WireError::BadMagic => write!(f, "not a routing decision wire frame"),
+            WireError::UnsupportedVersion(v) => write!(f, "unsupported wire version {}", v),
+            WireError::RegistryMismatch { expected, actual } => write!(
+                f,
+                "frame encoded against {} experts but registry has {}",
+                actual, expected
+            ),
+            WireError::UnknownExpert(id) => write!(f, "expert {:?} not in registry", id),
+            WireError::IndexOutOfRange(i) => write!(f, "expert index {} out of range", i),
+            WireError::UnexpectedCount(n) => write!(f, "expected 1 decision, found {}", n),
+            WireError::TrailingBytes(n) => write!(f, "{} trailing bytes after frame", n),
+            WireError::Truncated => write!(f, "truncated wire frame"),
+        }
+    }
+}
+
+impl std::error::Error for WireError {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CheckpointError {
+    Io(String),
+    Format(String),
+    MissingTensor(String),
+    UnsupportedDtype(String),
+    ShapeMismatch {
+        tensor: String,
+        shape: Vec<usize>,
+        experts: usize,
+    },
+}
+
+impl fmt::Display for CheckpointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckpointError::Io(msg) => write!(f, "checkpoint read failed: {}", msg),
+            CheckpointError::Format(msg) => write!(f, "malformed checkpoint: {}", msg),
+            CheckpointError::MissingTensor(name) => {
+                write!(f, "tensor {:?} not found in checkpoint", name)
+            }
+            CheckpointError::UnsupportedDtype(dtype) => {
+                write!(f, "unsupported gate tensor dtype {}", dtype)
+            }
+            CheckpointError::ShapeMismatch {
+                tensor,
+                shape,
+                experts,
+            } => write!(
+                f,
+                "tensor {:?} has shape {:?} but the manifest lists {} experts",
+                tensor, shape, experts
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CheckpointError {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ManifestError {
+    Io(String),
+    Parse(String),
+    InvalidTier {
+        revision: String,
+        tier: Tier,
+        reason: String,
+    },
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManifestError::Io(msg) => write!(f, "manifest read failed: {}", msg),
+            ManifestError::Parse(msg) => write!(f, "malformed manifest: {}", msg),
+            ManifestError::InvalidTier {
+                revision,
+                tier,
+                reason,
+            } => write!(f, "revision {} tier {:?}: {}", revision, tier, reason),
+        }
+    }
+}
+
+impl std::error::Error for ManifestError {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BudgetError {
+    ActiveExperts { requested: usize, available: usize },
+    ActivationRate { requested: usize },
+}
+
+impl fmt::Display for BudgetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BudgetError::ActiveExperts {
+                requested,
+                available,
+            } => write!(
+                f,
+                "{} new experts requested but only {} active slots available",
+                requested, available
+            ),
+            BudgetError::ActivationRate { requested } => {
+                write!(f, "activation rate exhausted for {} experts", requested)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BudgetError {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SketchError {
+    PrecisionMismatch { left: u8, right: u8 },
+    Parse(String),
+}
+
+impl fmt::Display for SketchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SketchError::PrecisionMismatch { left, right } => write!(
+                f,
+                "cannot merge sketches of precision {} and {}",
+                left, right
+            ),
+            SketchError::Parse(msg) => write!(f, "malformed sketch: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SketchError {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigError {
+    NoExperts,
+    InvalidTemperature(f32),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::NoExperts => write!(f, "router configured with no experts"),
+            ConfigError::InvalidTemperature(t) => write!(
+                f,
+                "gate temperature {} is not a finite value of at least {}",
+                t,
+                crate::MIN_TEMPERATURE
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PolicyError {
+    Parse { offset: usize, message: String },
+    UnknownRouter(String),
+    UnknownExpertSet(String),
+    MissingDefault,
+}
+
+impl fmt::Display for PolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolicyError::Parse { offset, message } => {
+                write!(f, "policy syntax error at byte {}: {}", offset, message)
+            }
+            PolicyError::UnknownRouter(name) => write!(f, "policy names unknown router {}", name),
+            PolicyError::UnknownExpertSet(name) => {
+                write!(f, "policy names unknown expert set {}", name)
+            }
+            PolicyError::MissingDefault => write!(f, "policy has no else rule"),
+        }
+    }
+}
+
+impl std::error::Error for PolicyError {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum StoreError {
+    Io(String),
+    Corrupt { line: usize, message: String },
+    Unavailable(String),
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoreError::Io(msg) => write!(f, "sticky store i/o failed: {}", msg),
+            StoreError::Corrupt { line, message } => {
+                write!(f, "sticky store journal line {}: {}", line, message)
+            }
+            StoreError::Unavailable(msg) => write!(f, "sticky store unavailable: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
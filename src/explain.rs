@@ -0,0 +1,259 @@
+// File: explain.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Routing explanations for AURIA Runtime Core.
+//     Router::explain returns, per chosen expert, its raw gate logit and
+//     scored probability (when the base router has them), its rank, and the
+//     wrapper layer that introduced it, plus every layer that added or
+//     removed experts on the way out.
+//
+use crate::{DroppedExpert, RoutingContext, RoutingOutcome};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExpertExplanation {
+    pub expert_id: ExpertId,
+    pub rank: usize,
+    // Gate weight before biases; None for routers without learned logits.
+    pub raw_logit: Option<f32>,
+    // Scorer output (e.g. post-softmax); None for shared experts, which are
+    // not scored, and for routers without logits.
+    pub probability: Option<f32>,
+    pub gating_weight: f32,
+    pub shared: bool,
+    // Wrapper layer that placed this expert, if the base router did not.
+    pub introduced_by: Option<String>,
+}
+
+// One wrapper layer that changed the expert set.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExplanationStep {
+    pub layer: String,
+    pub added: Vec<ExpertId>,
+    pub removed: Vec<DroppedExpert>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RoutingExplanation {
+    pub tier: Tier,
+    pub token_index: u64,
+    pub experts: Vec<ExpertExplanation>,
+    // Innermost layer first.
+    pub steps: Vec<ExplanationStep>,
+    pub outcome: RoutingOutcome,
+    // Base-router (raw logit, probability) for every scored candidate, so
+    // experts promoted by a wrapper are still explained.
+    scores: HashMap<ExpertId, (f32, Option<f32>)>,
+}
+
+impl RoutingExplanation {
+    pub fn from_outcome(ctx: &RoutingContext, outcome: RoutingOutcome) -> Self {
+        Self::with_scores(ctx, outcome, HashMap::new())
+    }
+
+    pub(crate) fn with_scores(
+        ctx: &RoutingContext,
+        outcome: RoutingOutcome,
+        scores: HashMap<ExpertId, (f32, Option<f32>)>,
+    ) -> Self {
+        let mut explanation = Self {
+            tier: ctx.tier,
+            token_index: ctx.token_index,
+            experts: Vec::new(),
+            steps: Vec::new(),
+            outcome,
+            scores,
+        };
+        explanation.rebuild();
+        explanation
+    }
+
+    // Starting point for a layer that does not consult its inner router.
+    pub(crate) fn empty(ctx: &RoutingContext) -> Self {
+        let decision = RoutingDecision {
+            expert_ids: Vec::new(),
+            confidence_scores: Vec::new(),
+            gating_weights: Vec::new(),
+            timestamp: 0,
+        };
+        Self::from_outcome(ctx, RoutingOutcome::new(decision, 0))
+    }
+
+    // Records `outcome` as the result of `layer` applied to the current
+    // outcome. Drops the layer recorded are appended to `outcome.dropped`,
+    // so anything past the current length belongs to this layer.
+    pub fn layer(mut self, layer: &str, outcome: RoutingOutcome) -> Self {
+        let before = &self.outcome;
+        let added: Vec<ExpertId> = outcome
+            .decision
+            .expert_ids
+            .iter()
+            .filter(|id| !before.decision.expert_ids.contains(id))
+            .cloned()
+            .collect();
+        let mut removed: Vec<DroppedExpert> = outcome
+            .dropped
+            .get(before.dropped.len()..)
+            .unwrap_or_default()
+            .to_vec();
+        for id in &before.decision.expert_ids {
+            let gone = !outcome.decision.expert_ids.contains(id);
+            if gone && !removed.iter().any(|d| &d.expert_id == id) {
+                removed.push(DroppedExpert {
+                    expert_id: id.clone(),
+                    reason: crate::DropReason::Masked {
+                        mask: layer.to_string(),
+                    },
+                });
+            }
+        }
+        if !added.is_empty() || !removed.is_empty() {
+            self.steps.push(ExplanationStep {
+                layer: layer.to_string(),
+                added,
+                removed,
+            });
+        }
+        self.outcome = outcome;
+        self.rebuild();
+        self
+    }
+
+    pub fn expert(&self, expert_id: &ExpertId) -> Option<&ExpertExplanation> {
+        self.experts.iter().find(|e| &e.expert_id == expert_id)
+    }
+
+    // Why `expert_id` is absent, if some layer removed it.
+    pub fn removal(&self, expert_id: &ExpertId) -> Option<(&str, &DroppedExpert)> {
+        self.steps.iter().rev().find_map(|step| {
+            step.removed
+                .iter()
+                .find(|d| &d.expert_id == expert_id)
+                .map(|d| (step.layer.as_str(), d))
+        })
+    }
+
+    pub fn into_outcome(self) -> RoutingOutcome {
+        self.outcome
+    }
+
+    fn rebuild(&mut self) {
+        let decision = &self.outcome.decision;
+        let shared = self.outcome.shared_count as usize;
+        self.experts = decision
+            .expert_ids
+            .iter()
+            .enumerate()
+            .map(|(rank, id)| {
+                let scores = self.scores.get(id);
+                ExpertExplanation {
+                    expert_id: id.clone(),
+                    rank,
+                    raw_logit: scores.map(|s| s.0),
+                    probability: scores.and_then(|s| s.1),
+                    gating_weight: decision.gating_weights.get(rank).copied().unwrap_or(1.0),
+                    shared: rank < shared,
+                    introduced_by: self
+                        .steps
+                        .iter()
+                        .rev()
+                        .find(|step| step.added.contains(id))
+                        .map(|step| step.layer.clone()),
+                }
+            })
+            .collect();
+    }
+}
+
+fn optional(value: Option<f32>) -> String {
+    value.map_or_else(|| "-".to_string(), |v| format!("{:.4}", v))
+}
+
+impl fmt::Display for RoutingExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "token {} at {:?}", self.token_index, self.tier)?;
+        if let Some(from) = self.outcome.downgraded_from {
+            write!(f, " (downgraded from {:?})", from)?;
+        }
+        writeln!(f)?;
+        for e in &self.experts {
+            write!(
+                f,
+                "  #{} {:02x?} logit={} p={} w={:.4}",
+                e.rank,
+                &e.expert_id.0[..4],
+                optional(e.raw_logit),
+                optional(e.probability),
+                e.gating_weight
+            )?;
+            if e.shared {
+                write!(f, " shared")?;
+            }
+            if let Some(layer) = &e.introduced_by {
+                write!(f, " via {}", layer)?;
+            }
+            writeln!(f)?;
+        }
+        for step in &self.steps {
+            for d in &step.removed {
+                writeln!(
+                    f,
+                    "  {} removed {:02x?}: {:?}",
+                    step.layer,
+                    &d.expert_id.0[..4],
+                    d.reason
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        index_expert_id, DropReason, ExpertRateLimit, GatingRouter, PinMode, PinRule, PinnedRouter,
+        PinningPolicy, RateLimitedRouter, Router,
+    };
+
+    #[test]
+    fn test_explain_reports_scores_and_layers() {
+        let mut gating = GatingRouter::new_unchecked(1.0);
+        for i in 0..8 {
+            gating.set_gate_weight(index_expert_id(i), i as f32);
+        }
+        let ctx = RoutingContext::new(Tier::Nano, 4812);
+        let explanation = gating.explain(&ctx);
+        let top = &explanation.experts[0];
+        assert_eq!(top.expert_id, index_expert_id(7));
+        assert_eq!(top.raw_logit, Some(7.0));
+        assert!(top.probability.unwrap() > explanation.experts[1].probability.unwrap());
+        assert!(explanation.steps.is_empty());
+
+        let limited = RateLimitedRouter::new(gating);
+        limited.set_limit(index_expert_id(7), ExpertRateLimit::new(0.0, 1.0));
+        limited.route(Tier::Nano, 0);
+        let explanation = limited.explain(&ctx);
+        let (layer, dropped) = explanation.removal(&index_expert_id(7)).unwrap();
+        assert_eq!(layer, "rate_limit");
+        assert_eq!(dropped.reason, DropReason::RateLimited);
+        let promoted = explanation.expert(&index_expert_id(5)).unwrap();
+        assert_eq!(promoted.introduced_by.as_deref(), Some("rate_limit"));
+        assert_eq!(promoted.raw_logit, Some(5.0));
+
+        let pinned = PinnedRouter::new(
+            limited,
+            PinningPolicy::new()
+                .with_rule(PinRule::new(vec![index_expert_id(0)], PinMode::Include)),
+        );
+        let explanation = pinned.explain(&ctx);
+        let first = &explanation.experts[0];
+        assert_eq!(first.expert_id, index_expert_id(0));
+        assert_eq!(first.introduced_by.as_deref(), Some("pinning"));
+        assert_eq!(explanation.steps.len(), 2);
+        assert!(explanation.to_string().contains("via pinning"));
+    }
+}
@@ -0,0 +1,331 @@
+// File: fairness.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Traffic-share fairness constraints for AURIA Runtime Core.
+//     Enforces per-expert floors and ceilings on the share of expert
+//     assignments over a sliding window of decisions: experts at their
+//     ceiling are replaced by the next-best candidates, and experts below
+//     their floor are pulled into decisions until they catch up.
+//
+use crate::{
+    decision_entries, decision_from_entries, DropReason, LoadWindow, Router, RouterState,
+    RoutingContext, RoutingExplanation, RoutingOutcome, StatefulRouter,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShareBounds {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl ShareBounds {
+    // NaN bounds are treated as unconstrained.
+    pub fn new(min: f32, max: f32) -> Self {
+        let min = if min.is_nan() {
+            0.0
+        } else {
+            min.clamp(0.0, 1.0)
+        };
+        let max = if max.is_nan() {
+            1.0
+        } else {
+            max.clamp(min, 1.0)
+        };
+        Self { min, max }
+    }
+
+    pub fn floor(min: f32) -> Self {
+        Self::new(min, 1.0)
+    }
+
+    pub fn ceiling(max: f32) -> Self {
+        Self::new(0.0, max)
+    }
+}
+
+pub struct FairRouter<R: Router> {
+    inner: R,
+    bounds: Mutex<HashMap<ExpertId, ShareBounds>>,
+    shares: Arc<LoadWindow>,
+    // False when observing a shared window fed by a MeteredRouter.
+    records_load: bool,
+    ceiling_hits: AtomicU64,
+    floor_injections: AtomicU64,
+}
+
+impl<R: Router> FairRouter<R> {
+    // `window` is measured in decisions.
+    pub fn new(inner: R, window: usize) -> Self {
+        Self {
+            inner,
+            bounds: Mutex::new(HashMap::new()),
+            shares: Arc::new(LoadWindow::tokens(window)),
+            records_load: true,
+            ceiling_hits: AtomicU64::new(0),
+            floor_injections: AtomicU64::new(0),
+        }
+    }
+
+    // Reads shares from a window shared with other layers instead of its
+    // own. The router stops recording; a MeteredRouter must feed the window.
+    pub fn with_load_window(mut self, window: Arc<LoadWindow>) -> Self {
+        self.shares = window;
+        self.records_load = false;
+        self
+    }
+
+    pub fn set_bounds(&self, expert_id: ExpertId, bounds: ShareBounds) {
+        self.bounds.lock().unwrap().insert(expert_id, bounds);
+    }
+
+    pub fn clear_bounds(&self, expert_id: &ExpertId) {
+        self.bounds.lock().unwrap().remove(expert_id);
+    }
+
+    pub fn share(&self, expert_id: &ExpertId) -> f32 {
+        self.shares.share(expert_id)
+    }
+
+    pub fn ceiling_hits(&self) -> u64 {
+        self.ceiling_hits.load(Ordering::Relaxed)
+    }
+
+    pub fn floor_injections(&self) -> u64 {
+        self.floor_injections.load(Ordering::Relaxed)
+    }
+
+    // `candidates` yields (id, confidence, weight) in preference order and is
+    // only materialized when a ceiling forces a replacement.
+    fn enforce(
+        &self,
+        mut outcome: RoutingOutcome,
+        candidates: impl FnOnce() -> Vec<(ExpertId, f32, f32)>,
+    ) -> RoutingOutcome {
+        let bounds = self.bounds.lock().unwrap();
+        if bounds.is_empty() {
+            self.record(&outcome.decision);
+            return outcome;
+        }
+        // Shares are read up front: `candidates` may route through layers
+        // that use the same window.
+        let shares: HashMap<&ExpertId, f32> = {
+            let window = self.shares.lock();
+            bounds.keys().map(|id| (id, window.share(id))).collect()
+        };
+        let share = |id: &ExpertId| shares.get(id).copied().unwrap_or(0.0);
+        let at_ceiling = |id: &ExpertId| {
+            bounds
+                .get(id)
+                .is_some_and(|b| b.max < 1.0 && share(id) >= b.max)
+        };
+
+        let timestamp = outcome.decision.timestamp;
+        let target = outcome.decision.expert_ids.len();
+        let (mut selected, capped): (Vec<_>, Vec<_>) = decision_entries(outcome.decision.clone())
+            .into_iter()
+            .partition(|(id, _, _)| !at_ceiling(id));
+
+        if !capped.is_empty() {
+            self.ceiling_hits
+                .fetch_add(capped.len() as u64, Ordering::Relaxed);
+            for (id, confidence, weight) in candidates() {
+                if selected.len() == target {
+                    break;
+                }
+                if at_ceiling(&id)
+                    || capped.iter().any(|(c, _, _)| *c == id)
+                    || selected.iter().any(|(s, _, _)| *s == id)
+                {
+                    continue;
+                }
+                selected.push((id, confidence, weight));
+                outcome.fallback_used = true;
+            }
+        }
+
+        // Most under-served first; each injection displaces the lowest-ranked
+        // pick that has no floor of its own to defend.
+        let mut under: Vec<(&ExpertId, f32)> = bounds
+            .iter()
+            .filter(|(id, b)| b.min > 0.0 && !selected.iter().any(|(s, _, _)| s == *id))
+            .map(|(id, b)| (id, share(id) / b.min))
+            .filter(|(_, ratio)| *ratio < 1.0)
+            .collect();
+        under.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let mut displaced = Vec::new();
+        for (id, _) in under {
+            let Some(slot) = selected
+                .iter()
+                .rposition(|(s, _, _)| bounds.get(s).is_none_or(|b| b.min == 0.0))
+            else {
+                break;
+            };
+            let (confidence, weight) = (selected[slot].1, selected[slot].2);
+            let (previous, _, _) =
+                std::mem::replace(&mut selected[slot], (id.clone(), confidence, weight));
+            displaced.push(previous);
+            self.floor_injections.fetch_add(1, Ordering::Relaxed);
+            outcome.fallback_used = true;
+        }
+
+        outcome.decision = decision_from_entries(selected, timestamp);
+        self.record(&outcome.decision);
+        for (id, _, _) in capped {
+            outcome.record_drop(id, DropReason::ShareCeiling);
+        }
+        for id in displaced {
+            outcome.record_drop(id, DropReason::ShareFloor);
+        }
+        outcome
+    }
+
+    fn record(&self, decision: &RoutingDecision) {
+        if self.records_load {
+            self.shares.record(&decision.expert_ids);
+        }
+    }
+}
+
+impl<R: Router> Router for FairRouter<R> {
+    // As with rate limiting, the Max-tier selection is the ranked superset
+    // used for next-best replacements.
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        let decision = self.inner.route(tier, token_index);
+        let k = decision.expert_ids.len() as u32;
+        self.enforce(RoutingOutcome::new(decision, k), || {
+            decision_entries(self.inner.route(Tier::Max, token_index))
+        })
+        .decision
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        let decision = self.inner.route_with_weights(tier, token_index, weights);
+        let k = decision.expert_ids.len() as u32;
+        let outcome = self.enforce(RoutingOutcome::new(decision, k), || {
+            let mut ranked: Vec<(ExpertId, f32, f32)> = weights
+                .iter()
+                .map(|(id, w)| (id.clone(), 1.0, *w))
+                .collect();
+            ranked.sort_by(|a, b| crate::descending(a.2, b.2));
+            ranked
+        });
+        outcome.decision
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.route_outcome(ctx).decision
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        let outcome = self.inner.route_outcome(ctx);
+        self.enforce(outcome, || {
+            let mut wide = ctx.clone();
+            wide.tier = Tier::Max;
+            decision_entries(self.inner.route_with_context(&wide))
+        })
+    }
+
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        let explanation = self.inner.explain(ctx);
+        let outcome = self.enforce(explanation.outcome.clone(), || {
+            let mut wide = ctx.clone();
+            wide.tier = Tier::Max;
+            decision_entries(self.inner.route_with_context(&wide))
+        });
+        explanation.layer("fairness", outcome)
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for FairRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.inner.begin_sequence(seq_id)
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.inner.end_sequence(seq_id)
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.inner.active_sequences()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter};
+
+    #[test]
+    fn test_ceiling_redistributes_selections() {
+        // Token 0 always picks experts 0 and 1 at Nano.
+        let router = FairRouter::new(DeterministicRouter::new_unchecked(64), 100);
+        router.set_bounds(index_expert_id(0), ShareBounds::ceiling(0.25));
+
+        let hits = (0..100)
+            .filter(|_| {
+                router
+                    .route(Tier::Nano, 0)
+                    .expert_ids
+                    .contains(&index_expert_id(0))
+            })
+            .count();
+        assert!((45..=55).contains(&hits), "hits = {}", hits);
+        assert!(router.share(&index_expert_id(0)) <= 0.26);
+        assert!(router.ceiling_hits() > 0);
+
+        // A fresh window where expert 0 already holds half the assignments.
+        let router = FairRouter::new(DeterministicRouter::new_unchecked(64), 100);
+        router.set_bounds(index_expert_id(0), ShareBounds::ceiling(0.25));
+        router.route(Tier::Nano, 0);
+        let outcome = router.route_outcome(&RoutingContext::new(Tier::Nano, 0));
+        assert!(outcome.fallback_used);
+        assert_eq!(outcome.dropped.len(), 1);
+        assert_eq!(outcome.dropped[0].expert_id, index_expert_id(0));
+        assert_eq!(outcome.dropped[0].reason, DropReason::ShareCeiling);
+        assert_eq!(outcome.decision.expert_ids.len(), 2);
+        assert!(!outcome.decision.expert_ids.contains(&index_expert_id(0)));
+    }
+
+    #[test]
+    fn test_floor_includes_underserved_expert() {
+        let router = FairRouter::new(DeterministicRouter::new_unchecked(64), 50);
+        let cold = index_expert_id(40);
+        router.set_bounds(cold.clone(), ShareBounds::floor(0.1));
+
+        for token in 0..200 {
+            let decision = router.route(Tier::Standard, token % 8);
+            assert_eq!(decision.expert_ids.len(), 4);
+        }
+        let share = router.share(&cold);
+        assert!((0.1..0.2).contains(&share), "share = {}", share);
+        assert!(router.floor_injections() > 0);
+
+        let router = FairRouter::new(DeterministicRouter::new_unchecked(64), 50);
+        router.set_bounds(cold.clone(), ShareBounds::floor(0.1));
+        let first = router.route_outcome(&RoutingContext::new(Tier::Standard, 0));
+        assert!(first.decision.expert_ids.contains(&cold));
+        assert_eq!(first.dropped.len(), 1);
+        assert_eq!(first.dropped[0].reason, DropReason::ShareFloor);
+        assert!(!first
+            .decision
+            .expert_ids
+            .contains(&first.dropped[0].expert_id));
+    }
+}
@@ -0,0 +1,288 @@
+// File: feedback.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Online gate-weight estimation for AURIA Runtime Core.
+//     A FeedbackRouter tracks the decisions it hands out, accepts reward
+//     signals for them, and updates per-expert estimates with a configurable
+//     learning rule (EMA, UCB, or Thompson sampling) that drives selection.
+//
+use crate::{top_k_by_weight, KSelector, Router, RoutingContext, RoutingOutcome, TierK};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rand_distr::{Beta, Distribution};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+pub type DecisionId = u64;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LearningRule {
+    // Exponential moving average of rewards; greedy selection.
+    Ema { alpha: f32 },
+    // Upper confidence bound on the mean reward.
+    Ucb { exploration: f32 },
+    // Beta-Bernoulli Thompson sampling; rewards are clamped to [0, 1].
+    Thompson { seed: u64 },
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExpertStats {
+    pub estimate: f32,
+    pub pulls: u64,
+    pub reward_sum: f64,
+}
+
+struct FeedbackState {
+    stats: HashMap<ExpertId, ExpertStats>,
+    pending: HashMap<DecisionId, Vec<ExpertId>>,
+    pending_order: VecDeque<DecisionId>,
+    total_pulls: u64,
+    rng: StdRng,
+}
+
+pub struct FeedbackRouter {
+    rule: LearningRule,
+    max_pending: usize,
+    next_id: AtomicU64,
+    state: Mutex<FeedbackState>,
+    k_selector: Box<dyn KSelector>,
+}
+
+impl FeedbackRouter {
+    pub fn new(initial_weights: HashMap<ExpertId, f32>, rule: LearningRule) -> Self {
+        let seed = match rule {
+            LearningRule::Thompson { seed } => seed,
+            _ => 0,
+        };
+        let stats = initial_weights
+            .into_iter()
+            .map(|(id, w)| {
+                let stats = ExpertStats {
+                    estimate: w,
+                    ..ExpertStats::default()
+                };
+                (id, stats)
+            })
+            .collect();
+        Self {
+            rule,
+            max_pending: 65_536,
+            next_id: AtomicU64::new(1),
+            state: Mutex::new(FeedbackState {
+                stats,
+                pending: HashMap::new(),
+                pending_order: VecDeque::new(),
+                total_pulls: 0,
+                rng: StdRng::seed_from_u64(seed),
+            }),
+            k_selector: Box::new(TierK),
+        }
+    }
+
+    pub fn with_max_pending(mut self, max_pending: usize) -> Self {
+        self.max_pending = max_pending.max(1);
+        self
+    }
+
+    pub fn with_k_selector(mut self, k_selector: impl KSelector + 'static) -> Self {
+        self.k_selector = Box::new(k_selector);
+        self
+    }
+
+    pub fn gate_weights(&self) -> HashMap<ExpertId, f32> {
+        let state = self.state.lock().unwrap();
+        state
+            .stats
+            .iter()
+            .map(|(id, s)| (id.clone(), s.estimate))
+            .collect()
+    }
+
+    pub fn expert_stats(&self, expert_id: &ExpertId) -> Option<ExpertStats> {
+        self.state.lock().unwrap().stats.get(expert_id).cloned()
+    }
+
+    pub fn route_tracked(&self, tier: Tier, token_index: u64) -> (DecisionId, RoutingDecision) {
+        let k = self.k_selector.k(tier, token_index) as usize;
+        let mut state = self.state.lock().unwrap();
+        let state = &mut *state;
+
+        let total = state.total_pulls.max(1) as f32;
+        let mut scored: Vec<(&ExpertId, f32)> = Vec::with_capacity(state.stats.len());
+        for (id, s) in &state.stats {
+            let score = match self.rule {
+                LearningRule::Ema { .. } => s.estimate,
+                LearningRule::Ucb { exploration } => {
+                    if s.pulls == 0 {
+                        f32::INFINITY
+                    } else {
+                        s.estimate + exploration * (total.ln() / s.pulls as f32).sqrt()
+                    }
+                }
+                LearningRule::Thompson { .. } => {
+                    let successes = s.reward_sum.max(0.0);
+                    let failures = (s.pulls as f64 - s.reward_sum).max(0.0);
+                    Beta::new(1.0 + successes, 1.0 + failures)
+                        .map(|beta| beta.sample(&mut state.rng) as f32)
+                        .unwrap_or(0.0)
+                }
+            };
+            scored.push((id, score));
+        }
+
+        let selected: Vec<(ExpertId, f32)> = top_k_by_weight(&mut scored, k)
+            .iter()
+            .map(|(id, score)| ((*id).clone(), *score))
+            .collect();
+        let ids: Vec<ExpertId> = selected.iter().map(|(id, _)| id.clone()).collect();
+        let n = ids.len();
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        state.pending.insert(id, ids.clone());
+        state.pending_order.push_back(id);
+        while state.pending_order.len() > self.max_pending {
+            if let Some(old) = state.pending_order.pop_front() {
+                state.pending.remove(&old);
+            }
+        }
+
+        let decision = RoutingDecision {
+            expert_ids: ids,
+            confidence_scores: selected.iter().map(|(_, s)| *s).collect(),
+            gating_weights: vec![1.0 / n.max(1) as f32; n],
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+        };
+        (id, decision)
+    }
+
+    // Returns false when the decision is unknown, already reported, or older
+    // than the last `max_pending` tracked decisions.
+    pub fn report_outcome(&self, decision_id: DecisionId, score: f32) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let Some(experts) = state.pending.remove(&decision_id) else {
+            return false;
+        };
+
+        let reward = match self.rule {
+            LearningRule::Thompson { .. } => score.clamp(0.0, 1.0),
+            _ => score,
+        };
+        state.total_pulls += experts.len() as u64;
+        for expert in experts {
+            let Some(stats) = state.stats.get_mut(&expert) else {
+                continue;
+            };
+            stats.pulls += 1;
+            stats.reward_sum += reward as f64;
+            stats.estimate = match self.rule {
+                LearningRule::Ema { alpha } => {
+                    let alpha = alpha.clamp(0.0, 1.0);
+                    (1.0 - alpha) * stats.estimate + alpha * reward
+                }
+                _ => (stats.reward_sum / stats.pulls as f64) as f32,
+            };
+        }
+        true
+    }
+}
+
+impl Router for FeedbackRouter {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.route_tracked(tier, token_index).1
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        _weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        self.route(tier, token_index)
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        let k = self.k_selector.k(ctx.tier, ctx.token_index);
+        RoutingOutcome::new(self.route_with_context(ctx), k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, TierKTable};
+
+    fn experts(n: u32) -> HashMap<ExpertId, f32> {
+        (0..n).map(|i| (index_expert_id(i), 0.5)).collect()
+    }
+
+    fn train(router: &FeedbackRouter, good: &ExpertId, rounds: u64) {
+        for token in 0..rounds {
+            let (id, decision) = router.route_tracked(Tier::Nano, token);
+            let score = if decision.expert_ids.contains(good) {
+                1.0
+            } else {
+                0.0
+            };
+            assert!(router.report_outcome(id, score));
+        }
+    }
+
+    #[test]
+    fn test_ema_rule_learns_good_expert() {
+        let router = FeedbackRouter::new(experts(8), LearningRule::Ema { alpha: 0.5 });
+        let good = index_expert_id(3);
+        train(&router, &good, 50);
+
+        assert!(router.route(Tier::Nano, 0).expert_ids.contains(&good));
+        assert!(router.expert_stats(&good).unwrap().estimate > 0.9);
+    }
+
+    #[test]
+    fn test_bandit_rules_converge_on_best_expert() {
+        let good = index_expert_id(5);
+        for rule in [
+            LearningRule::Ucb { exploration: 0.5 },
+            LearningRule::Thompson { seed: 9 },
+        ] {
+            let router = FeedbackRouter::new(experts(8), rule);
+            train(&router, &good, 400);
+            let stats = router.expert_stats(&good).unwrap();
+            assert!(
+                stats.pulls > 100,
+                "{:?} pulled best expert {} times",
+                rule,
+                stats.pulls
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_and_duplicate_reports_are_rejected() {
+        let router =
+            FeedbackRouter::new(experts(4), LearningRule::Ema { alpha: 0.1 }).with_max_pending(1);
+        let (first, _) = router.route_tracked(Tier::Nano, 0);
+        let (second, _) = router.route_tracked(Tier::Nano, 1);
+        assert!(!router.report_outcome(first, 1.0));
+        assert!(router.report_outcome(second, 1.0));
+        assert!(!router.report_outcome(second, 1.0));
+    }
+
+    #[test]
+    fn test_outcome_reports_selector_k() {
+        let router = FeedbackRouter::new(experts(8), LearningRule::Ema { alpha: 0.1 })
+            .with_k_selector(TierKTable {
+                nano: 3,
+                standard: 4,
+                pro: 5,
+                max: 6,
+            });
+        let outcome = router.route_outcome(&RoutingContext::new(Tier::Nano, 0));
+        assert_eq!(outcome.requested_k, 3);
+        assert_eq!(outcome.decision.expert_ids.len(), 3);
+    }
+}
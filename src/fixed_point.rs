@@ -0,0 +1,155 @@
+// File: fixed_point.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Integer-only gate scoring for AURIA Runtime Core.
+//     FixedPoint quantizes temperature-scaled logits and evaluates softmax
+//     with integer arithmetic only, so gate scores (and the ranks and ties
+//     derived from them) are bit-identical on every architecture instead
+//     of depending on the platform's exp() and summation order.
+//
+use crate::Scorer;
+
+// log2(e) and the Taylor coefficients ln^n(2) / n! of 2^x, in Q32.
+const LOG2E_Q32: i128 = 6_196_328_019;
+const EXP2_POLY_Q32: [u64; 8] = [
+    2_977_044_472,
+    1_031_764_991,
+    238_388_332,
+    41_309_550,
+    5_726_720,
+    661_577,
+    65_510,
+    5_676,
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedPoint {
+    frac_bits: u8,
+}
+
+impl Default for FixedPoint {
+    fn default() -> Self {
+        Self { frac_bits: 16 }
+    }
+}
+
+impl FixedPoint {
+    // At most 24 fractional bits, so every score converts to f32 exactly.
+    pub fn new(frac_bits: u32) -> Self {
+        Self {
+            frac_bits: frac_bits.clamp(8, 24) as u8,
+        }
+    }
+
+    pub fn frac_bits(&self) -> u32 {
+        self.frac_bits as u32
+    }
+
+    // Rounds to the nearest step of 2^-frac_bits. NaN and -inf map to the
+    // lowest representable value, +inf to the highest.
+    pub fn quantize(&self, x: f32) -> i64 {
+        const LIMIT: f64 = (1u64 << 52) as f64;
+        if x.is_nan() {
+            return -LIMIT as i64;
+        }
+        let scaled = (x as f64 * (1u64 << self.frac_bits()) as f64).round();
+        scaled.clamp(-LIMIT, LIMIT) as i64
+    }
+
+    // Softmax over quantized logits; each output is a probability in
+    // Q(frac_bits) and the outputs sum to at most 1.
+    pub fn softmax_into(&self, quantized: &[i64], out: &mut Vec<u64>) {
+        out.clear();
+        let Some(&max) = quantized.iter().max() else {
+            return;
+        };
+        out.extend(quantized.iter().map(|&q| self.exp(q - max)));
+        let sum: u64 = out.iter().sum();
+        out.iter_mut()
+            .for_each(|e| *e = (((*e as u128) << self.frac_bits()) / sum as u128) as u64);
+    }
+
+    pub fn to_f32(&self, value: u64) -> f32 {
+        value as f32 / (1u64 << self.frac_bits()) as f32
+    }
+
+    // e^x for x <= 0 in Q(frac_bits), via 2^(x log2 e) split into an integer
+    // shift and a polynomial for the fractional part.
+    fn exp(&self, x: i64) -> u64 {
+        let f = self.frac_bits();
+        let y = ((x as i128 * LOG2E_Q32) >> 32) as i64;
+        let whole = y >> f;
+        if whole <= -64 {
+            return 0;
+        }
+        let frac = ((y - (whole << f)) as u64) << (32 - f);
+        let poly = EXP2_POLY_Q32.iter().rev().fold(0u64, |acc, &c| {
+            c + ((acc as u128 * frac as u128) >> 32) as u64
+        });
+        let pow2 = (1u64 << 32) + ((poly as u128 * frac as u128) >> 32) as u64;
+        (pow2 >> (32 - f)) >> (-whole) as u32
+    }
+}
+
+impl Scorer for FixedPoint {
+    fn score(&self, logits: &[f32], temperature: f32) -> Vec<f32> {
+        let mut out = Vec::with_capacity(logits.len());
+        self.score_into(logits, temperature, &mut out);
+        out
+    }
+
+    fn score_into(&self, logits: &[f32], temperature: f32, out: &mut Vec<f32>) {
+        let quantized: Vec<i64> = logits
+            .iter()
+            .map(|l| self.quantize(l / temperature))
+            .collect();
+        let mut probs = Vec::with_capacity(quantized.len());
+        self.softmax_into(&quantized, &mut probs);
+        out.clear();
+        out.extend(probs.into_iter().map(|p| self.to_f32(p)));
+    }
+
+    // No logarithm: integer mode does not sample.
+    fn sampling_key(&self, score: f32) -> f32 {
+        score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, GatingRouter, Router};
+    use auria_core::Tier;
+
+    #[test]
+    fn test_fixed_point_softmax_is_exact() {
+        let fixed = FixedPoint::default();
+        let scores = fixed.score(&[1.0, 0.0, -1.0], 1.0);
+        let expected = [0.665_240_9, 0.244_728_5, 0.090_030_6];
+        for (s, e) in scores.iter().zip(expected) {
+            assert!((s - e).abs() < 2e-4, "{} vs {}", s, e);
+        }
+        // Pinned bit patterns: any platform must reproduce these exactly.
+        let bits: Vec<u32> = scores.iter().map(|s| s.to_bits()).collect();
+        assert_eq!(bits, [1_059_736_832, 1_048_221_696, 1_035_493_376]);
+        assert_eq!(fixed.score(&[3.0; 4], 0.5), vec![0.25; 4]);
+    }
+
+    #[test]
+    fn test_integer_mode_breaks_ties_by_expert_id() {
+        let mut router = GatingRouter::new_unchecked(1.0);
+        for i in (0..16).rev() {
+            router.set_gate_weight(index_expert_id(i), if i < 8 { 1.0 } else { 0.0 });
+        }
+        router.set_integer_mode(Some(FixedPoint::default()));
+        let decision = router.route(Tier::Standard, 0);
+        assert_eq!(
+            decision.expert_ids,
+            (0..4).map(index_expert_id).collect::<Vec<_>>()
+        );
+        assert!(decision
+            .gating_weights
+            .iter()
+            .all(|&w| w == decision.gating_weights[0]));
+    }
+}
@@ -0,0 +1,291 @@
+// File: fuzzing.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     `arbitrary::Arbitrary` support for AURIA Runtime Core (feature
+//     "arbitrary"). Covers routing contexts, tier policies, weight tables
+//     and a RouterSpec that builds any core router wrapped in any stack of
+//     wrappers, so fuzz targets reach the composed code paths.
+//
+use crate::{
+    index_expert_id, AdaptiveTierRouter, BeamOverlap, BeamRouter, CanaryRouter, Dedupe,
+    DeterministicRouter, ExpertRateLimit, FairRouter, GatingRouter, HashRouter, PriorityClass,
+    PriorityRouter, RateLimitedRouter, RawLogits, RoundRobinRouter, Router, RouterConfig,
+    RoutingContext, ShareBounds, Sigmoid, Softmax, Sparsemax, TierKTable, TransformedRouter,
+    TruncateToK, ValidatingRouter,
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+use auria_core::{ExpertId, Tier};
+use std::collections::HashMap;
+
+pub fn arbitrary_tier(u: &mut Unstructured<'_>) -> Result<Tier> {
+    Ok(*u.choose(&[Tier::Nano, Tier::Standard, Tier::Pro, Tier::Max])?)
+}
+
+impl<'a> Arbitrary<'a> for RoutingContext {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut ctx = RoutingContext::new(arbitrary_tier(u)?, u.arbitrary()?)
+            .with_layer(u.arbitrary()?)
+            .with_sequence_position(u.arbitrary()?)
+            .with_beam(u.int_in_range(0..=7)?)
+            .with_priority(*u.choose(&[
+                PriorityClass::Interactive,
+                PriorityClass::Standard,
+                PriorityClass::Batch,
+            ])?);
+        if u.arbitrary()? {
+            ctx = ctx.with_profile(String::arbitrary(u)?);
+        }
+        if u.arbitrary()? {
+            ctx = ctx.with_sequence_id(u.arbitrary()?);
+        }
+        Ok(ctx)
+    }
+}
+
+impl<'a> Arbitrary<'a> for TierKTable {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(TierKTable {
+            nano: u.int_in_range(0..=16)?,
+            standard: u.int_in_range(0..=16)?,
+            pro: u.int_in_range(0..=32)?,
+            max: u.int_in_range(0..=64)?,
+        })
+    }
+}
+
+// Gate weights over a small expert index space; values are unconstrained
+// f32s, so NaN and infinities are reachable.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WeightTable(pub HashMap<ExpertId, f32>);
+
+impl<'a> Arbitrary<'a> for WeightTable {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.int_in_range(0..=64)?;
+        let mut weights = HashMap::with_capacity(len);
+        for _ in 0..len {
+            weights.insert(index_expert_id(u.int_in_range(0..=255)?), u.arbitrary()?);
+        }
+        Ok(WeightTable(weights))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Arbitrary)]
+pub enum ScorerSpec {
+    Softmax,
+    Sigmoid,
+    Sparsemax,
+    RawLogits,
+}
+
+#[derive(Clone, Debug, PartialEq, Arbitrary)]
+pub enum BaseSpec {
+    Deterministic {
+        expert_count: u8,
+    },
+    RoundRobin {
+        expert_count: u8,
+    },
+    Hash {
+        expert_count: u8,
+        seed: u64,
+    },
+    Gating {
+        temperature: f32,
+        weights: WeightTable,
+        scorer: ScorerSpec,
+        min_gate_prob: f32,
+        renormalize: bool,
+        sampling_seed: Option<u64>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Arbitrary)]
+pub enum WrapperSpec {
+    Canary {
+        target_share: f32,
+        ramp_steps: u16,
+    },
+    RateLimit {
+        expert: u8,
+        tokens_per_sec: f32,
+        burst: f32,
+    },
+    Fair {
+        window: u8,
+        expert: u8,
+        min: f32,
+        max: f32,
+    },
+    Dedupe,
+    Truncate(Option<u8>),
+    Adaptive {
+        pressure: f32,
+    },
+    Validating,
+    Beam {
+        search_width: u8,
+    },
+    Priority {
+        window: u8,
+        batch_k_fraction: f32,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Arbitrary)]
+pub struct RouterSpec {
+    pub base: BaseSpec,
+    pub k_table: Option<TierKTable>,
+    pub wrappers: Vec<WrapperSpec>,
+}
+
+impl RouterSpec {
+    pub fn build(&self) -> Box<dyn Router> {
+        let mut router = self.build_base();
+        for wrapper in &self.wrappers {
+            router = wrap(router, wrapper);
+        }
+        router
+    }
+
+    fn build_base(&self) -> Box<dyn Router> {
+        let k = self.k_table;
+        match &self.base {
+            BaseSpec::Deterministic { expert_count } => {
+                let router = DeterministicRouter::new_unchecked(*expert_count as u32);
+                Box::new(match k {
+                    Some(k) => router.with_k_selector(k),
+                    None => router,
+                })
+            }
+            BaseSpec::RoundRobin { expert_count } => {
+                let router = RoundRobinRouter::new_unchecked(
+                    (0..*expert_count as u32).map(index_expert_id).collect(),
+                );
+                Box::new(match k {
+                    Some(k) => router.with_k_selector(k),
+                    None => router,
+                })
+            }
+            BaseSpec::Hash { expert_count, seed } => {
+                let router = HashRouter::new_unchecked(*expert_count as u32, *seed);
+                Box::new(match k {
+                    Some(k) => router.with_k_selector(k),
+                    None => router,
+                })
+            }
+            BaseSpec::Gating {
+                temperature,
+                weights,
+                scorer,
+                min_gate_prob,
+                renormalize,
+                sampling_seed,
+            } => {
+                let mut router = GatingRouter::new_unchecked(*temperature);
+                router = match scorer {
+                    ScorerSpec::Softmax => router.with_scorer(Softmax),
+                    ScorerSpec::Sigmoid => router.with_scorer(Sigmoid),
+                    ScorerSpec::Sparsemax => router.with_scorer(Sparsemax),
+                    ScorerSpec::RawLogits => router.with_scorer(RawLogits),
+                };
+                if let Some(k) = k {
+                    router = router.with_k_selector(k);
+                }
+                router.set_gate_weights(weights.0.clone());
+                router.set_min_gate_prob(*min_gate_prob, *renormalize);
+                router.set_sampling_seed(*sampling_seed);
+                Box::new(router)
+            }
+        }
+    }
+}
+
+fn wrap(inner: Box<dyn Router>, spec: &WrapperSpec) -> Box<dyn Router> {
+    match *spec {
+        WrapperSpec::Canary {
+            target_share,
+            ramp_steps,
+        } => Box::new(CanaryRouter::new(
+            inner,
+            vec![ExpertId([0xCA; 32])],
+            target_share,
+            ramp_steps as u64,
+        )),
+        WrapperSpec::RateLimit {
+            expert,
+            tokens_per_sec,
+            burst,
+        } => {
+            let router = RateLimitedRouter::new(inner);
+            router.set_limit(
+                index_expert_id(expert as u32),
+                ExpertRateLimit::new(tokens_per_sec as f64, burst as f64),
+            );
+            Box::new(router)
+        }
+        WrapperSpec::Fair {
+            window,
+            expert,
+            min,
+            max,
+        } => {
+            let router = FairRouter::new(inner, window as usize);
+            router.set_bounds(index_expert_id(expert as u32), ShareBounds::new(min, max));
+            Box::new(router)
+        }
+        WrapperSpec::Dedupe => Box::new(TransformedRouter::new(inner).with_transform(Dedupe)),
+        WrapperSpec::Truncate(k) => {
+            Box::new(TransformedRouter::new(inner).with_transform(TruncateToK(k.map(u32::from))))
+        }
+        WrapperSpec::Adaptive { pressure } => {
+            let router = AdaptiveTierRouter::new(inner);
+            router.set_queue_pressure(pressure);
+            Box::new(router)
+        }
+        WrapperSpec::Validating => Box::new(
+            ValidatingRouter::new(inner, RouterConfig::new()).with_panic_on_violation(false),
+        ),
+        WrapperSpec::Beam { search_width } => Box::new(BeamRouter::new(
+            inner,
+            BeamOverlap::Maximize {
+                search_width: search_width as u32,
+            },
+        )),
+        WrapperSpec::Priority {
+            window,
+            batch_k_fraction,
+        } => Box::new(
+            PriorityRouter::new(inner, window as usize).with_batch_k_fraction(batch_k_fraction),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arbitrary_specs_route_without_panicking() {
+        let mut seed = 0x9E37_79B9_7F4A_7C15u64;
+        for _ in 0..200 {
+            let bytes: Vec<u8> = (0..512)
+                .map(|_| {
+                    seed = crate::splitmix64(seed);
+                    seed as u8
+                })
+                .collect();
+            let mut u = Unstructured::new(&bytes);
+            let Ok(spec) = RouterSpec::arbitrary(&mut u) else {
+                continue;
+            };
+            let router = spec.build();
+            while let Ok(ctx) = RoutingContext::arbitrary(&mut u) {
+                router.route_outcome(&ctx);
+                router.route_beams(ctx.tier, ctx.token_index, ctx.beam);
+                if u.is_empty() {
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+// File: grouped.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Group-limited top-k selection for AURIA Runtime Core.
+//     Partitions experts into groups and caps how many experts a single
+//     step may take from each group (and optionally how many groups it may
+//     touch), matching checkpoints trained with grouped routing.
+//
+use auria_core::{ExpertId, Tier};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug)]
+pub struct GroupedTopK {
+    groups: HashMap<ExpertId, u32>,
+    max_per_group: [u32; 4],
+    max_groups: [Option<u32>; 4],
+}
+
+fn tier_slot(tier: Tier) -> usize {
+    match tier {
+        Tier::Nano => 0,
+        Tier::Standard => 1,
+        Tier::Pro => 2,
+        Tier::Max => 3,
+    }
+}
+
+impl GroupedTopK {
+    pub fn new(groups: HashMap<ExpertId, u32>, max_per_group: u32) -> Self {
+        Self {
+            groups,
+            max_per_group: [max_per_group.max(1); 4],
+            max_groups: [None; 4],
+        }
+    }
+
+    // Contiguous groups of `group_size` over the given expert order.
+    pub fn contiguous(experts: &[ExpertId], group_size: usize, max_per_group: u32) -> Self {
+        let group_size = group_size.max(1);
+        let groups = experts
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (id.clone(), (i / group_size) as u32))
+            .collect();
+        Self::new(groups, max_per_group)
+    }
+
+    pub fn with_tier_limit(mut self, tier: Tier, max_per_group: u32) -> Self {
+        self.max_per_group[tier_slot(tier)] = max_per_group.max(1);
+        self
+    }
+
+    pub fn with_max_groups(mut self, tier: Tier, max_groups: u32) -> Self {
+        self.max_groups[tier_slot(tier)] = Some(max_groups.max(1));
+        self
+    }
+
+    pub fn group_of(&self, expert_id: &ExpertId) -> Option<u32> {
+        self.groups.get(expert_id).copied()
+    }
+
+    pub fn max_per_group(&self, tier: Tier) -> u32 {
+        self.max_per_group[tier_slot(tier)]
+    }
+
+    // Greedy selection over candidates already ranked best-first. Experts
+    // without a group assignment are not limited.
+    pub fn select<T>(
+        &self,
+        tier: Tier,
+        k: usize,
+        ranked: impl IntoIterator<Item = T>,
+        id_of: impl Fn(&T) -> &ExpertId,
+    ) -> Vec<T> {
+        let per_group = self.max_per_group(tier);
+        let max_groups = self.max_groups[tier_slot(tier)];
+        let mut taken: HashMap<u32, u32> = HashMap::new();
+        let mut selected = Vec::with_capacity(k);
+
+        for candidate in ranked {
+            if selected.len() >= k {
+                break;
+            }
+            if let Some(group) = self.group_of(id_of(&candidate)) {
+                let count = taken.get(&group).copied().unwrap_or(0);
+                if count >= per_group {
+                    continue;
+                }
+                if count == 0 && max_groups.is_some_and(|m| taken.len() as u32 >= m) {
+                    continue;
+                }
+                taken.insert(group, count + 1);
+            }
+            selected.push(candidate);
+        }
+        selected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, GatingRouter, Router};
+
+    #[test]
+    fn test_grouped_top_k_limits_per_group() {
+        let experts: Vec<ExpertId> = (0..8).map(index_expert_id).collect();
+        let grouped = GroupedTopK::contiguous(&experts, 4, 1).with_tier_limit(Tier::Pro, 2);
+
+        let mut router = GatingRouter::new_unchecked(1.0);
+        for (i, id) in experts.iter().enumerate() {
+            router.set_gate_weight(id.clone(), 10.0 - i as f32);
+        }
+        router.set_grouped_top_k(Some(grouped));
+
+        let nano = router.route(Tier::Nano, 0);
+        assert_eq!(
+            nano.expert_ids,
+            vec![index_expert_id(0), index_expert_id(4)]
+        );
+
+        let standard = router.route(Tier::Standard, 0);
+        assert_eq!(standard.expert_ids.len(), 2);
+
+        let pro = router.route(Tier::Pro, 0);
+        assert_eq!(pro.expert_ids.len(), 4);
+        assert_eq!(pro.expert_ids[1], index_expert_id(1));
+    }
+
+    #[test]
+    fn test_grouped_top_k_max_groups() {
+        let experts: Vec<ExpertId> = (0..12).map(index_expert_id).collect();
+        let grouped = GroupedTopK::contiguous(&experts, 4, 4).with_max_groups(Tier::Standard, 1);
+        let ranked = [0u32, 5, 1, 9, 2, 3].map(index_expert_id);
+
+        let selected = grouped.select(Tier::Standard, 4, ranked.iter(), |id| id);
+        let expected: Vec<ExpertId> = [0u32, 1, 2, 3].map(index_expert_id).to_vec();
+        assert_eq!(selected.into_iter().cloned().collect::<Vec<_>>(), expected);
+    }
+}
@@ -0,0 +1,117 @@
+// File: index_map.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     ExpertId interning for AURIA Runtime Core.
+//     Maps 32-byte ExpertIds to dense u32 indices so softmax and top-k math
+//     can run over flat f32 slices, converting back to ExpertIds only at
+//     the API boundary.
+//
+use auria_core::ExpertId;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Default)]
+pub struct ExpertIndexMap {
+    ids: Vec<ExpertId>,
+    index: HashMap<ExpertId, u32>,
+}
+
+impl ExpertIndexMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Returns the existing index, or assigns the next dense one.
+    pub fn intern(&mut self, expert_id: &ExpertId) -> u32 {
+        if let Some(&index) = self.index.get(expert_id) {
+            return index;
+        }
+        let index = self.ids.len() as u32;
+        self.ids.push(expert_id.clone());
+        self.index.insert(expert_id.clone(), index);
+        index
+    }
+
+    pub fn index_of(&self, expert_id: &ExpertId) -> Option<u32> {
+        self.index.get(expert_id).copied()
+    }
+
+    pub fn expert_id(&self, index: u32) -> Option<&ExpertId> {
+        self.ids.get(index as usize)
+    }
+
+    pub fn ids(&self) -> &[ExpertId] {
+        &self.ids
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    // Dense weight vector indexed by interned index; experts missing from
+    // `weights` get `fill`, and unknown experts in `weights` are ignored.
+    pub fn dense_weights(&self, weights: &HashMap<ExpertId, f32>, fill: f32) -> Vec<f32> {
+        let mut dense = vec![fill; self.ids.len()];
+        for (id, &w) in weights {
+            if let Some(&index) = self.index.get(id) {
+                dense[index as usize] = w;
+            }
+        }
+        dense
+    }
+
+    pub fn resolve(&self, indices: &[u32]) -> Vec<ExpertId> {
+        indices
+            .iter()
+            .filter_map(|&i| self.expert_id(i).cloned())
+            .collect()
+    }
+}
+
+impl FromIterator<ExpertId> for ExpertIndexMap {
+    fn from_iter<I: IntoIterator<Item = ExpertId>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for id in iter {
+            map.intern(&id);
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter, Router};
+    use auria_core::Tier;
+
+    #[test]
+    fn test_intern_round_trip() {
+        let mut map: ExpertIndexMap = (0..4).map(index_expert_id).collect();
+        assert_eq!(map.len(), 4);
+        assert_eq!(map.intern(&index_expert_id(2)), 2);
+        assert_eq!(map.intern(&index_expert_id(9)), 4);
+        assert_eq!(map.index_of(&index_expert_id(9)), Some(4));
+        assert_eq!(map.expert_id(4), Some(&index_expert_id(9)));
+        assert_eq!(
+            map.resolve(&[4, 0, 99]),
+            vec![index_expert_id(9), index_expert_id(0)]
+        );
+    }
+
+    #[test]
+    fn test_dense_weights_match_hashmap_routing() {
+        let weights: HashMap<ExpertId, f32> = (0..64)
+            .map(|i| (index_expert_id(i), ((i * 37) % 64) as f32))
+            .collect();
+        let map: ExpertIndexMap = (0..64).map(index_expert_id).collect();
+        let dense = map.dense_weights(&weights, f32::NEG_INFINITY);
+
+        let router = DeterministicRouter::new_unchecked(64);
+        let expected = router.route_with_weights(Tier::Max, 0, &weights);
+        let actual = router.route_with_dense_weights(Tier::Max, 0, &map, &dense);
+        assert_eq!(actual.expert_ids, expected.expert_ids);
+    }
+}
@@ -0,0 +1,110 @@
+// File: k_selector.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Expert-count (k) selection policies for AURIA Runtime Core.
+//     Decouples how many experts a step activates from the routers, so
+//     policies such as "k grows with sequence length" can be plugged in
+//     without patching each router implementation.
+//
+use auria_core::Tier;
+
+pub trait KSelector: Send + Sync {
+    fn k(&self, tier: Tier, token_index: u64) -> u32;
+}
+
+impl<F> KSelector for F
+where
+    F: Fn(Tier, u64) -> u32 + Send + Sync,
+{
+    fn k(&self, tier: Tier, token_index: u64) -> u32 {
+        self(tier, token_index)
+    }
+}
+
+// The standard mapping: Nano 2, Standard 4, Pro 8, Max 16.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TierK;
+
+impl KSelector for TierK {
+    fn k(&self, tier: Tier, _token_index: u64) -> u32 {
+        match tier {
+            Tier::Nano => 2,
+            Tier::Standard => 4,
+            Tier::Pro => 8,
+            Tier::Max => 16,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TierKTable {
+    pub nano: u32,
+    pub standard: u32,
+    pub pro: u32,
+    pub max: u32,
+}
+
+impl KSelector for TierKTable {
+    fn k(&self, tier: Tier, _token_index: u64) -> u32 {
+        match tier {
+            Tier::Nano => self.nano,
+            Tier::Standard => self.standard,
+            Tier::Pro => self.pro,
+            Tier::Max => self.max,
+        }
+    }
+}
+
+// Scales the tier's k once the token index passes a threshold, e.g.
+// halving k after 4096 tokens. The result never drops below 1.
+#[derive(Clone, Copy, Debug)]
+pub struct ThresholdK<S: KSelector> {
+    pub base: S,
+    pub after_token: u64,
+    pub numerator: u32,
+    pub denominator: u32,
+}
+
+impl<S: KSelector> ThresholdK<S> {
+    pub fn new(base: S, after_token: u64, numerator: u32, denominator: u32) -> Self {
+        Self {
+            base,
+            after_token,
+            numerator,
+            denominator: denominator.max(1),
+        }
+    }
+}
+
+impl<S: KSelector> KSelector for ThresholdK<S> {
+    fn k(&self, tier: Tier, token_index: u64) -> u32 {
+        let k = self.base.k(tier, token_index);
+        if token_index < self.after_token {
+            return k;
+        }
+        ((k as u64 * self.numerator as u64) / self.denominator as u64).max(1) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DeterministicRouter, Router};
+
+    #[test]
+    fn test_threshold_k_halves_after_limit() {
+        let selector = ThresholdK::new(TierK, 4096, 1, 2);
+        assert_eq!(selector.k(Tier::Pro, 4095), 8);
+        assert_eq!(selector.k(Tier::Pro, 4096), 4);
+        assert_eq!(selector.k(Tier::Nano, 10_000), 1);
+    }
+
+    #[test]
+    fn test_closure_selector_drives_router() {
+        let router = DeterministicRouter::new_unchecked(64)
+            .with_k_selector(|_tier: Tier, token: u64| 1 + (token / 100) as u32);
+        assert_eq!(router.route(Tier::Max, 0).expert_ids.len(), 1);
+        assert_eq!(router.route(Tier::Max, 250).expert_ids.len(), 3);
+        assert_eq!(router.route(Tier::Max, 250).gating_weights.len(), 3);
+    }
+}
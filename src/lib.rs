@@ -6,7 +6,197 @@
 //     for each inference step based on tier, token position, and gating weights.
 //
 use auria_core::{ExpertId, RoutingDecision, Tier};
+use scratch::topk_into;
 use std::collections::HashMap;
+use std::sync::Arc;
+
+pub mod adaptive;
+pub mod affinity;
+pub mod aggregate;
+pub mod audit;
+pub mod batch;
+pub mod beam;
+pub mod bias;
+pub mod budget;
+pub mod canary;
+#[cfg(feature = "test-support")]
+pub mod chaos;
+#[cfg(feature = "checkpoint")]
+pub mod checkpoint;
+pub mod compare;
+#[cfg(feature = "test-support")]
+pub mod conformance;
+pub mod cursor;
+pub mod delta;
+pub mod determinism;
+pub mod dispatch;
+pub mod distill;
+pub mod error;
+pub mod explain;
+pub mod fairness;
+pub mod feedback;
+pub mod fixed_point;
+#[cfg(feature = "arbitrary")]
+pub mod fuzzing;
+pub mod grouped;
+pub mod index_map;
+pub mod k_selector;
+pub mod load;
+pub mod maintenance;
+pub mod manifest;
+pub mod mixer;
+pub mod outcome;
+pub mod pinning;
+pub mod policy;
+pub mod priority;
+pub mod profiles;
+pub mod rate_limit;
+pub mod replica;
+pub mod reservation;
+pub mod residency;
+pub mod sanitize;
+pub mod scorer;
+pub mod scratch;
+pub mod self_check;
+pub mod session;
+pub mod shared;
+pub mod sim;
+pub mod sketch;
+pub mod state;
+#[cfg(feature = "tracing")]
+pub mod trace;
+pub mod transform;
+pub mod validate;
+pub mod versioned;
+pub mod wire;
+
+pub use adaptive::AdaptiveTierRouter;
+pub use affinity::{FileStickyStore, MemoryStickyStore, StickyRecord, StickyStore};
+pub use aggregate::{ExpertSetAggregator, ExpertSetGroup};
+pub use audit::{AuditConfig, AuditFormat, AuditLog, AuditedRouter};
+#[cfg(feature = "rayon")]
+pub use batch::par_route_batch;
+pub use batch::route_batch;
+pub use beam::{activated_experts, BeamOverlap, BeamRouter};
+pub use bias::BiasBalancing;
+pub use budget::{BudgetLease, BudgetPolicy, BudgetedRouter, RoutingBudget};
+pub use canary::CanaryRouter;
+#[cfg(feature = "test-support")]
+pub use chaos::{ChaosRouter, Fault};
+#[cfg(feature = "checkpoint")]
+pub use checkpoint::{read_gate_weights, read_npz, read_safetensors, GateManifest};
+pub use compare::{diff_routers, DivergenceReport, Workload};
+#[cfg(feature = "test-support")]
+pub use conformance::{ConformanceFailure, ConformanceSuite, Property};
+pub use cursor::{ShardedCursor, DEFAULT_SHARD_BATCH};
+pub use delta::{DecisionDelta, DeltaTracker};
+pub use determinism::{DecisionDigest, DeterminismGuard};
+pub use dispatch::{CombineEntry, DispatchPlan, DispatchPlanner, DropPolicy, ExpertDispatch};
+pub use distill::{fit_hash_router, fit_lookup_router, DistillReport, LookupRouter};
+pub use error::{
+    BudgetError, CheckpointError, ConfigError, ManifestError, PolicyError, RouteError, SketchError,
+    StoreError, WeightVersionError, WireError,
+};
+pub use explain::{ExpertExplanation, ExplanationStep, RoutingExplanation};
+pub use fairness::{FairRouter, ShareBounds};
+pub use feedback::{DecisionId, FeedbackRouter, LearningRule};
+pub use fixed_point::FixedPoint;
+#[cfg(feature = "arbitrary")]
+pub use fuzzing::{RouterSpec, WeightTable};
+pub use grouped::GroupedTopK;
+pub use index_map::ExpertIndexMap;
+pub use k_selector::{KSelector, ThresholdK, TierK, TierKTable};
+pub use load::{LoadSpan, LoadWindow, MeteredRouter};
+pub use maintenance::{MaintenanceClock, MaintenanceRouter, MaintenanceWindow};
+pub use manifest::{RevisionManifest, TierSpec};
+pub use mixer::{IdentityMix, MultiplyShiftMix, SplitMix64Mix, TokenMixer};
+pub use outcome::{DropReason, DroppedExpert, RoutingOutcome};
+pub use pinning::{PinMode, PinRule, PinnedRouter, PinningPolicy};
+pub use policy::{Policy, PolicyEnv, PolicyRouter};
+pub use priority::{PriorityClass, PriorityRouter};
+pub use profiles::ProfileRouter;
+pub use rate_limit::{ExpertRateLimit, RateLimitedRouter};
+pub use replica::{Replica, ReplicaGroups, ReplicaRouter, ReplicaStrategy, ReplicatedDecision};
+pub use reservation::{CapacityExcess, Reservation, ReservingRouter};
+pub use residency::{ResidencyBonus, ResidencySource, ResidentSet};
+pub use sanitize::{Clip, NonFinitePolicy, Normalization, SanitationReport, WeightSanitizer};
+pub use scorer::{RawLogits, Scorer, Sigmoid, Softmax, Sparsemax};
+pub use scratch::GateScratch;
+pub use self_check::SelfCheckReport;
+pub use session::{StatefulRouter, StickyRouter};
+pub use shared::SharedExperts;
+pub use sim::{SimConfig, SimReport, TokenDistribution};
+pub use sketch::{HistogramRouter, HistogramSketch, SequenceSketch};
+pub use state::{CanaryState, RouterState};
+#[cfg(feature = "tracing")]
+pub use trace::TracedRouter;
+pub use transform::{
+    AppendSharedExpert, DecisionTransform, Dedupe, ReorderByLocality, TransformedRouter,
+    TruncateToK,
+};
+pub use validate::{
+    validate_decision, validate_decision_for_tier, validate_outcome, RouterConfig,
+    ValidatingRouter, Violation,
+};
+pub use versioned::{SequencePin, VersionedRouter, VersionedWeights, WeightSnapshot};
+pub use wire::{decode_decision, decode_decisions, encode_decision, encode_decisions};
+
+#[derive(Clone, Debug)]
+pub struct RoutingContext {
+    pub tier: Tier,
+    pub token_index: u64,
+    pub layer: u32,
+    pub sequence_position: u64,
+    pub beam: u32,
+    pub profile: Option<String>,
+    pub priority: PriorityClass,
+    pub sequence_id: Option<u64>,
+}
+
+impl RoutingContext {
+    pub fn new(tier: Tier, token_index: u64) -> Self {
+        Self {
+            tier,
+            token_index,
+            layer: 0,
+            sequence_position: token_index,
+            beam: 0,
+            profile: None,
+            priority: PriorityClass::default(),
+            sequence_id: None,
+        }
+    }
+
+    pub fn with_layer(mut self, layer: u32) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    pub fn with_sequence_position(mut self, sequence_position: u64) -> Self {
+        self.sequence_position = sequence_position;
+        self
+    }
+
+    pub fn with_beam(mut self, beam: u32) -> Self {
+        self.beam = beam;
+        self
+    }
+
+    pub fn with_profile(mut self, profile: impl Into<String>) -> Self {
+        self.profile = Some(profile.into());
+        self
+    }
+
+    pub fn with_priority(mut self, priority: PriorityClass) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn with_sequence_id(mut self, sequence_id: u64) -> Self {
+        self.sequence_id = Some(sequence_id);
+        self
+    }
+}
 
 pub trait Router: Send + Sync {
     fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision;
@@ -16,24 +206,219 @@ pub trait Router: Send + Sync {
         token_index: u64,
         weights: &HashMap<ExpertId, f32>,
     ) -> RoutingDecision;
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.route(ctx.tier, ctx.token_index)
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        RoutingOutcome::new(self.route_with_context(ctx), tier_k(ctx.tier))
+    }
+
+    // route_outcome against explicit weights in place of the router's own.
+    // The default sees only the tier and token of `ctx`; context-aware
+    // routers override it so layer, profile and sequence still apply.
+    fn route_outcome_with_weights(
+        &self,
+        ctx: &RoutingContext,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingOutcome {
+        RoutingOutcome::new(
+            self.route_with_weights(ctx.tier, ctx.token_index, weights),
+            tier_k(ctx.tier),
+        )
+    }
+
+    // Routes exactly as route_outcome would (advancing the same state) and
+    // reports why each expert was chosen. Wrappers that add or remove
+    // experts override this to record themselves as a layer.
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        RoutingExplanation::from_outcome(ctx, self.route_outcome(ctx))
+    }
+
+    // One decision per beam, each routed independently from its own
+    // context. BeamRouter overrides this to share experts across beams.
+    fn route_beams(&self, tier: Tier, token_index: u64, beam_count: u32) -> Vec<RoutingDecision> {
+        (0..beam_count)
+            .map(|beam| {
+                self.route_with_context(&RoutingContext::new(tier, token_index).with_beam(beam))
+            })
+            .collect()
+    }
+
+    fn snapshot(&self) -> RouterState {
+        RouterState::default()
+    }
+
+    fn restore(&self, _state: &RouterState) {}
+
+    fn self_check(
+        &self,
+        expected_expert_count: u32,
+        tiers: &[Tier],
+    ) -> Result<SelfCheckReport, RouteError> {
+        self_check::run_self_check(self, expected_expert_count, tiers)
+    }
+}
+
+impl<R: Router + ?Sized> Router for Box<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        (**self).route(tier, token_index)
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        (**self).route_with_weights(tier, token_index, weights)
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        (**self).route_with_context(ctx)
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        (**self).route_outcome(ctx)
+    }
+
+    fn route_outcome_with_weights(
+        &self,
+        ctx: &RoutingContext,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingOutcome {
+        (**self).route_outcome_with_weights(ctx, weights)
+    }
+
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        (**self).explain(ctx)
+    }
+
+    fn route_beams(&self, tier: Tier, token_index: u64, beam_count: u32) -> Vec<RoutingDecision> {
+        (**self).route_beams(tier, token_index, beam_count)
+    }
+
+    fn snapshot(&self) -> RouterState {
+        (**self).snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        (**self).restore(state)
+    }
 }
 
 pub struct DeterministicRouter {
     expert_count: u32,
+    seed: u64,
+    mixer: Box<dyn TokenMixer>,
+    k_selector: Box<dyn KSelector>,
 }
 
 impl DeterministicRouter {
-    pub fn new(expert_count: u32) -> Self {
-        Self { expert_count }
+    pub fn new(expert_count: u32) -> Result<Self, ConfigError> {
+        if expert_count == 0 {
+            return Err(ConfigError::NoExperts);
+        }
+        Ok(Self::new_unchecked(expert_count))
+    }
+
+    // With no experts every decision is empty.
+    pub fn new_unchecked(expert_count: u32) -> Self {
+        Self {
+            expert_count,
+            seed: 0,
+            mixer: Box::new(IdentityMix),
+            k_selector: Box::new(TierK),
+        }
+    }
+
+    pub fn with_k_selector(mut self, k_selector: impl KSelector + 'static) -> Self {
+        self.k_selector = Box::new(k_selector);
+        self
+    }
+
+    // How token indices map to the first expert of the window; IdentityMix
+    // (consecutive tokens, consecutive experts) by default.
+    pub fn with_mixer(mut self, mixer: impl TokenMixer + 'static) -> Self {
+        self.mixer = Box::new(mixer);
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    // Slice form of route_with_weights: avoids building a HashMap per call
+    // and reuses a thread-local index buffer for the top-k selection.
+    pub fn route_with_weight_slice(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &[(ExpertId, f32)],
+    ) -> RoutingDecision {
+        let k = self.k_selector.k(tier, token_index);
+
+        let ids = TOP_K_SCRATCH.with(|scratch| {
+            let mut scratch = scratch.borrow_mut();
+            scratch.clear();
+            scratch.extend(weights.iter().enumerate().map(|(i, (_, w))| (i, *w)));
+            top_k_by_weight(&mut scratch, k as usize)
+                .iter()
+                .map(|&(i, _)| weights[i].0.clone())
+                .collect::<Vec<ExpertId>>()
+        });
+
+        Self::weighted_decision(ids)
+    }
+
+    // Interned form: `weights[i]` is the weight of `index_map.expert_id(i)`,
+    // so selection compares f32s only and resolves the k winners at the end.
+    pub fn route_with_dense_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        index_map: &ExpertIndexMap,
+        weights: &[f32],
+    ) -> RoutingDecision {
+        let k = self.k_selector.k(tier, token_index);
+
+        let ids = TOP_K_SCRATCH.with(|scratch| {
+            let mut scratch = scratch.borrow_mut();
+            scratch.clear();
+            scratch.extend(weights.iter().copied().enumerate());
+            top_k_by_weight(&mut scratch, k as usize)
+                .iter()
+                .filter_map(|&(i, _)| index_map.expert_id(i as u32).cloned())
+                .collect::<Vec<ExpertId>>()
+        });
+
+        Self::weighted_decision(ids)
+    }
+
+    fn weighted_decision(ids: Vec<ExpertId>) -> RoutingDecision {
+        let n = ids.len();
+        RoutingDecision {
+            expert_ids: ids,
+            confidence_scores: vec![1.0; n],
+            gating_weights: vec![1.0; n],
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+        }
     }
 
+    // Never wraps past `expert_count`: with fewer experts than k, every
+    // expert is returned once and the outcome reports the shortfall.
     fn get_top_k_experts(&self, token_index: u64, k: u32) -> Vec<ExpertId> {
+        let k = k.min(self.expert_count);
         let mut ids = Vec::with_capacity(k as usize);
+        let mixed = self.mixer.mix(token_index, self.seed);
         for i in 0..k {
-            let val = ((token_index as u32) + i) % self.expert_count.max(1);
-            let mut bytes = [0u8; 32];
-            bytes[0..4].copy_from_slice(&val.to_le_bytes());
-            ids.push(ExpertId(bytes));
+            let val = ((mixed % self.expert_count as u64) as u32 + i) % self.expert_count;
+            ids.push(index_expert_id(val));
         }
         ids
     }
@@ -41,17 +426,13 @@ impl DeterministicRouter {
 
 impl Router for DeterministicRouter {
     fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
-        let k = match tier {
-            Tier::Nano => 2,
-            Tier::Standard => 4,
-            Tier::Pro => 8,
-            Tier::Max => 16,
-        };
+        let k = self.k_selector.k(tier, token_index);
         let ids = self.get_top_k_experts(token_index, k);
+        let n = ids.len();
         RoutingDecision {
             expert_ids: ids,
-            confidence_scores: vec![1.0; k as usize],
-            gating_weights: vec![1.0; k as usize],
+            confidence_scores: vec![1.0; n],
+            gating_weights: vec![1.0; n],
             timestamp: std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
@@ -62,136 +443,521 @@ impl Router for DeterministicRouter {
     fn route_with_weights(
         &self,
         tier: Tier,
-        _token_index: u64,
+        token_index: u64,
         weights: &HashMap<ExpertId, f32>,
     ) -> RoutingDecision {
-        let k = match tier {
-            Tier::Nano => 2,
-            Tier::Standard => 4,
-            Tier::Pro => 8,
-            Tier::Max => 16,
-        };
+        let k = self.k_selector.k(tier, token_index);
 
-        let mut sorted: Vec<_> = weights.iter().collect();
-        sorted.sort_by(|a, b| b.1.partial_cmp(a.1).unwrap_or(std::cmp::Ordering::Equal));
-
-        let ids: Vec<ExpertId> = sorted
+        let mut entries: Vec<(&ExpertId, f32)> = weights.iter().map(|(id, w)| (id, *w)).collect();
+        let ids: Vec<ExpertId> = top_k_by_weight(&mut entries, k as usize)
             .iter()
-            .take(k as usize)
             .map(|(id, _)| (*id).clone())
             .collect();
 
-        RoutingDecision {
-            expert_ids: ids,
-            confidence_scores: vec![1.0; k as usize],
-            gating_weights: vec![1.0; k as usize],
-            timestamp: std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs(),
-        }
+        Self::weighted_decision(ids)
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        let k = self.k_selector.k(ctx.tier, ctx.token_index);
+        RoutingOutcome::new(self.route_with_context(ctx), k)
+    }
+
+    fn route_outcome_with_weights(
+        &self,
+        ctx: &RoutingContext,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingOutcome {
+        let k = self.k_selector.k(ctx.tier, ctx.token_index);
+        RoutingOutcome::new(
+            self.route_with_weights(ctx.tier, ctx.token_index, weights),
+            k,
+        )
     }
 }
 
+// Lowest gate temperature a GatingRouter accepts.
+pub const MIN_TEMPERATURE: f32 = 0.01;
+
 pub struct GatingRouter {
     gate_weights: HashMap<ExpertId, f32>,
     temperature: f32,
+    min_gate_prob: f32,
+    renormalize: bool,
+    sampling_seed: Option<u64>,
+    grouped: Option<GroupedTopK>,
+    shared: Option<Box<SharedExperts>>,
+    residency: Option<Box<ResidencyBonus>>,
+    sanitizer: Option<Box<WeightSanitizer>>,
+    revision: Option<Box<manifest::RevisionTiers>>,
+    integer: Option<FixedPoint>,
+    biases: Box<bias::ExpertBiases>,
+    scorer: Box<dyn Scorer>,
+    k_selector: Box<dyn KSelector>,
 }
 
 impl GatingRouter {
-    pub fn new(temperature: f32) -> Self {
+    pub fn new(temperature: f32) -> Result<Self, ConfigError> {
+        if !temperature.is_finite() || temperature < MIN_TEMPERATURE {
+            return Err(ConfigError::InvalidTemperature(temperature));
+        }
+        Ok(Self::new_unchecked(temperature))
+    }
+
+    // Temperatures below MIN_TEMPERATURE are raised to it.
+    pub fn new_unchecked(temperature: f32) -> Self {
         Self {
             gate_weights: HashMap::new(),
-            temperature: temperature.max(0.01),
+            temperature: temperature.max(MIN_TEMPERATURE),
+            min_gate_prob: 0.0,
+            renormalize: false,
+            sampling_seed: None,
+            grouped: None,
+            shared: None,
+            residency: None,
+            sanitizer: None,
+            revision: None,
+            integer: None,
+            biases: Box::default(),
+            scorer: Box::new(Softmax),
+            k_selector: Box::new(TierK),
         }
     }
 
+    pub fn with_k_selector(mut self, k_selector: impl KSelector + 'static) -> Self {
+        self.k_selector = Box::new(k_selector);
+        self
+    }
+
+    pub fn with_scorer(mut self, scorer: impl Scorer + 'static) -> Self {
+        self.scorer = Box::new(scorer);
+        self
+    }
+
+    pub fn set_sampling_seed(&mut self, seed: Option<u64>) {
+        self.sampling_seed = seed;
+    }
+
+    pub fn set_expert_bias(&self, expert_id: ExpertId, bias: f32) {
+        self.biases.set(expert_id, bias);
+    }
+
+    pub fn expert_bias(&self, expert_id: &ExpertId) -> f32 {
+        self.biases.get(expert_id)
+    }
+
+    pub fn expert_biases(&self) -> HashMap<ExpertId, f32> {
+        self.biases.all()
+    }
+
+    pub fn clear_expert_biases(&self) {
+        self.biases.clear();
+    }
+
+    pub fn set_bias_balancing(&mut self, balancing: Option<BiasBalancing>) {
+        self.biases.set_balancing(balancing);
+    }
+
+    // Bias balancing reads load from a shared window (fed by a
+    // MeteredRouter) instead of counting this router's own selections.
+    pub fn set_bias_load_window(&mut self, window: Option<Arc<LoadWindow>>) {
+        self.biases.set_load_window(window);
+    }
+
+    pub fn set_grouped_top_k(&mut self, grouped: Option<GroupedTopK>) {
+        self.grouped = grouped;
+    }
+
+    // Shared experts are always active, excluded from the routed softmax and
+    // placed ahead of the k routed picks.
+    pub fn set_shared_experts(&mut self, shared: Option<SharedExperts>) {
+        self.shared = shared.map(Box::new);
+    }
+
+    pub fn set_residency_bonus(&mut self, residency: Option<ResidencyBonus>) {
+        self.residency = residency.map(Box::new);
+    }
+
+    pub fn set_min_gate_prob(&mut self, min_gate_prob: f32, renormalize: bool) {
+        self.min_gate_prob = min_gate_prob.clamp(0.0, 1.0);
+        self.renormalize = renormalize;
+    }
+
     pub fn set_gate_weight(&mut self, expert_id: ExpertId, weight: f32) {
         self.gate_weights.insert(expert_id, weight);
     }
 
-    pub fn set_gate_weights(&mut self, weights: HashMap<ExpertId, f32>) {
+    // Applied to every set_gate_weights call; single-entry updates through
+    // set_gate_weight are not sanitized.
+    pub fn set_weight_sanitizer(&mut self, sanitizer: Option<WeightSanitizer>) {
+        self.sanitizer = sanitizer.map(Box::new);
+    }
+
+    pub fn set_gate_weights(&mut self, mut weights: HashMap<ExpertId, f32>) -> SanitationReport {
+        let report = match &self.sanitizer {
+            Some(sanitizer) => sanitizer.sanitize(&mut weights),
+            None => SanitationReport {
+                entries: weights.len(),
+                ..SanitationReport::default()
+            },
+        };
         self.gate_weights = weights;
+        report
     }
 
-    fn softmax(weights: &HashMap<ExpertId, f32>, temperature: f32) -> Vec<(ExpertId, f32)> {
-        let max_weight = weights.values().cloned().fold(f32::NEG_INFINITY, f32::max);
+    // Integer mode scores with fixed-point softmax in place of the scorer
+    // and breaks score ties by expert id, so decisions are bit-identical
+    // across architectures. Sampling is disabled: Gumbel noise needs a
+    // logarithm.
+    pub fn set_integer_mode(&mut self, fixed_point: Option<FixedPoint>) {
+        self.integer = fixed_point;
+    }
 
-        let exp_weights: Vec<(ExpertId, f32)> = weights
-            .iter()
-            .map(|(id, w)| (id.clone(), ((w - max_weight) / temperature).exp()))
-            .collect();
+    pub(crate) fn set_revision_tiers(&mut self, revision: Option<manifest::RevisionTiers>) {
+        self.revision = revision.map(Box::new);
+    }
+
+    // Gumbel(0, 1) noise keyed by (seed, token, expert) rather than drawn
+    // from a stream, so it does not depend on HashMap iteration order.
+    fn gumbel_noise(seed: u64, token_index: u64, expert_id: &ExpertId) -> f32 {
+        let h = xxhash_rust::xxh64::xxh64(&expert_id.0, seed ^ splitmix64(token_index));
+        let u = ((h >> 11) as f64 + 0.5) / (1u64 << 53) as f64;
+        -(-u.ln()).ln() as f32
+    }
+
+    fn score(&self, logits: &HashMap<ExpertId, f32>, temperature: f32) -> Vec<(ExpertId, f32)> {
+        let (ids, logits): (Vec<ExpertId>, Vec<f32>) =
+            logits.iter().map(|(id, l)| (id.clone(), *l)).unzip();
+        let scores = self.active_scorer().score(&logits, temperature);
+        ids.into_iter().zip(scores).collect()
+    }
 
-        let sum: f32 = exp_weights.iter().map(|(_, e)| e).sum();
+    fn active_scorer(&self) -> &dyn Scorer {
+        match &self.integer {
+            Some(fixed_point) => fixed_point,
+            None => self.scorer.as_ref(),
+        }
+    }
 
-        exp_weights
+    fn sampling_seed(&self) -> Option<u64> {
+        self.sampling_seed.filter(|_| self.integer.is_none())
+    }
+}
+
+impl GatingRouter {
+    // `gate_weights` is the stored table or a caller's replacement for it;
+    // `scores` collects (raw logit, probability) per candidate for explain.
+    fn select(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        gate_weights: &HashMap<ExpertId, f32>,
+        scores: Option<&mut HashMap<ExpertId, (f32, Option<f32>)>>,
+    ) -> RoutingOutcome {
+        let k = self.k_selector.k(tier, token_index);
+
+        let shared = self.shared.as_deref().map_or(&[][..], |s| s.for_tier(tier));
+
+        let revision = self.revision.as_deref();
+        let temperature = revision.map_or(self.temperature, |r| r.temperature(tier));
+        let allowed = revision.and_then(|r| r.allowed(tier));
+
+        let logits = self.biases.apply(gate_weights);
+        let probs = if shared.is_empty() && allowed.is_none() {
+            self.score(&logits, temperature)
+        } else {
+            let routed: HashMap<ExpertId, f32> = logits
+                .iter()
+                .filter(|(id, _)| !shared.contains(id))
+                .filter(|(id, _)| allowed.is_none_or(|a| a.contains(id)))
+                .map(|(id, w)| (id.clone(), *w))
+                .collect();
+            self.score(&routed, temperature)
+        };
+        if let Some(scores) = scores {
+            for id in shared {
+                if let Some(&logit) = gate_weights.get(id) {
+                    scores.insert(id.clone(), (logit, None));
+                }
+            }
+            for (id, p) in &probs {
+                scores.insert(id.clone(), (gate_weights[id], Some(*p)));
+            }
+        }
+
+        // Gumbel-top-k: perturbing log-probabilities with i.i.d. Gumbel noise
+        // and taking the k largest samples k experts without replacement.
+        let mut sorted: Vec<(ExpertId, f32, f32)> = probs
             .into_iter()
-            .map(|(id, e)| (id, e / sum))
+            .map(|(id, p)| {
+                let key = match self.sampling_seed() {
+                    Some(seed) => {
+                        self.scorer.sampling_key(p) + Self::gumbel_noise(seed, token_index, &id)
+                    }
+                    None => p,
+                };
+                (id, p, key)
+            })
+            .collect();
+        if self.integer.is_some() {
+            sorted.sort_by(|a, b| descending(a.2, b.2).then_with(|| a.0 .0.cmp(&b.0 .0)));
+        } else {
+            sorted.sort_by(|a, b| descending(a.2, b.2));
+        }
+        if let Some(residency) = &self.residency {
+            residency.apply(&mut sorted, k as usize);
+        }
+
+        let top_k = match &self.grouped {
+            Some(grouped) => grouped.select(tier, k as usize, sorted, |(id, _, _)| id),
+            None => sorted.into_iter().take(k as usize).collect(),
+        };
+        let (selected, below): (Vec<_>, Vec<_>) = top_k
+            .into_iter()
+            .partition(|(_, p, _)| *p >= self.min_gate_prob);
+        self.assemble(
+            shared,
+            k,
+            selected.into_iter().map(|(id, p, _)| (id, p)).collect(),
+            below.into_iter().map(|(id, _, _)| id),
+            gate_weights.keys(),
+        )
+    }
+
+    // Dense form of routing: `logits[i]` is this token's gate logit for
+    // `index_map.expert_id(i)` and stands in for the stored gate weights.
+    // Biases, revision tiers, shared experts, sampling and the gate
+    // threshold apply as in route; grouped top-k and residency do not.
+    pub fn route_dense_logits(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        index_map: &ExpertIndexMap,
+        logits: &[f32],
+        scratch: &mut GateScratch,
+    ) -> RoutingDecision {
+        self.stage_biases(index_map, scratch);
+        self.select_dense(tier, token_index, index_map, logits, scratch)
+            .decision
+    }
+
+    // `logits` is row-major with one `index_map.len()`-wide row per token;
+    // routing stops at whichever of tokens and rows runs out first.
+    pub fn route_logits_batch(
+        &self,
+        tier: Tier,
+        token_indices: &[u64],
+        index_map: &ExpertIndexMap,
+        logits: &[f32],
+        scratch: &mut GateScratch,
+    ) -> Vec<RoutingDecision> {
+        self.stage_biases(index_map, scratch);
+        token_indices
+            .iter()
+            .zip(logits.chunks_exact(index_map.len().max(1)))
+            .map(|(&token_index, row)| {
+                self.select_dense(tier, token_index, index_map, row, scratch)
+                    .decision
+            })
             .collect()
     }
-}
 
-impl Router for GatingRouter {
-    fn route(&self, tier: Tier, _token_index: u64) -> RoutingDecision {
-        let k = match tier {
-            Tier::Nano => 2,
-            Tier::Standard => 4,
-            Tier::Pro => 8,
-            Tier::Max => 16,
+    fn stage_biases(&self, index_map: &ExpertIndexMap, scratch: &mut GateScratch) {
+        let biases = self.biases.all();
+        scratch.bias.clear();
+        if !biases.is_empty() {
+            scratch.bias.extend(
+                index_map
+                    .ids()
+                    .iter()
+                    .map(|id| biases.get(id).copied().unwrap_or(0.0)),
+            );
+        }
+    }
+
+    fn select_dense(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        index_map: &ExpertIndexMap,
+        logits: &[f32],
+        scratch: &mut GateScratch,
+    ) -> RoutingOutcome {
+        let k = self.k_selector.k(tier, token_index);
+        let shared = self.shared.as_deref().map_or(&[][..], |s| s.for_tier(tier));
+        let revision = self.revision.as_deref();
+        let temperature = revision.map_or(self.temperature, |r| r.temperature(tier));
+        let allowed = revision.and_then(|r| r.allowed(tier));
+        let ids = index_map.ids();
+
+        scratch.eligible.clear();
+        scratch.logits.clear();
+        for (i, (&logit, id)) in logits.iter().zip(ids).enumerate() {
+            if shared.contains(id) || allowed.is_some_and(|a| !a.contains(id)) {
+                continue;
+            }
+            scratch.eligible.push(i as u32);
+            scratch
+                .logits
+                .push(logit + scratch.bias.get(i).copied().unwrap_or(0.0));
+        }
+        self.active_scorer()
+            .score_into(&scratch.logits, temperature, &mut scratch.probs);
+
+        // The logits buffer is done with; reuse it for the sampling keys.
+        let keys = match self.sampling_seed() {
+            Some(seed) => {
+                for (j, key) in scratch.logits.iter_mut().enumerate() {
+                    let id = &ids[scratch.eligible[j] as usize];
+                    *key = self.scorer.sampling_key(scratch.probs[j])
+                        + Self::gumbel_noise(seed, token_index, id);
+                }
+                &scratch.logits
+            }
+            None => &scratch.probs,
         };
+        topk_into(keys, k as usize, &mut scratch.order);
+
+        let mut selected = Vec::with_capacity(scratch.order.len());
+        let mut below = Vec::new();
+        for &(j, _) in &scratch.order {
+            let id = ids[scratch.eligible[j] as usize].clone();
+            let p = scratch.probs[j];
+            if p >= self.min_gate_prob {
+                selected.push((id, p));
+            } else {
+                below.push(id);
+            }
+        }
+        self.assemble(shared, k, selected, below, ids.iter())
+    }
 
-        let probs = Self::softmax(&self.gate_weights, self.temperature);
+    // Shared experts first, then the routed picks; `experts` is the full
+    // candidate set bias balancing measures load against.
+    fn assemble<'a>(
+        &self,
+        shared: &[ExpertId],
+        k: u32,
+        selected: Vec<(ExpertId, f32)>,
+        below: impl IntoIterator<Item = ExpertId>,
+        experts: impl Iterator<Item = &'a ExpertId>,
+    ) -> RoutingOutcome {
+        let (ids, mut gating_weights): (Vec<ExpertId>, Vec<f32>) = selected.into_iter().unzip();
 
-        let mut sorted = probs;
-        sorted.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        if self.renormalize {
+            let total: f32 = gating_weights.iter().sum();
+            if total > 0.0 {
+                gating_weights.iter_mut().for_each(|w| *w /= total);
+            }
+        }
 
-        let selected: Vec<_> = sorted.into_iter().take(k as usize).collect();
-        let ids: Vec<ExpertId> = selected.iter().map(|(id, _)| id.clone()).collect();
-        let gating_weights: Vec<f32> = selected.iter().map(|(_, w)| *w).collect();
+        self.biases.record_load(&ids, experts);
 
-        RoutingDecision {
-            expert_ids: ids,
-            confidence_scores: gating_weights.clone(),
-            gating_weights,
+        let shared_weight = self.shared.as_deref().map_or(1.0, |s| s.weight());
+        let mut confidence_scores = vec![1.0; shared.len()];
+        confidence_scores.extend_from_slice(&gating_weights);
+        let decision = RoutingDecision {
+            expert_ids: shared.iter().cloned().chain(ids).collect(),
+            confidence_scores,
+            gating_weights: std::iter::repeat_n(shared_weight, shared.len())
+                .chain(gating_weights)
+                .collect(),
             timestamp: std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_secs(),
+        };
+        let mut outcome = RoutingOutcome::new(decision, k + shared.len() as u32);
+        outcome.shared_count = shared.len() as u32;
+        for id in below {
+            outcome.record_drop(id, DropReason::BelowThreshold);
         }
+        outcome
+    }
+}
+
+impl Router for GatingRouter {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.select(tier, token_index, &self.gate_weights, None)
+            .decision
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        self.select(ctx.tier, ctx.token_index, &self.gate_weights, None)
     }
 
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        let mut scores = HashMap::new();
+        let outcome = self.select(
+            ctx.tier,
+            ctx.token_index,
+            &self.gate_weights,
+            Some(&mut scores),
+        );
+        RoutingExplanation::with_scores(ctx, outcome, scores)
+    }
+
+    // `weights` stand in for the stored gate weights; everything else about
+    // the gate (biases, shared experts, revision tiers) still applies.
     fn route_with_weights(
         &self,
         tier: Tier,
         token_index: u64,
-        _weights: &HashMap<ExpertId, f32>,
+        weights: &HashMap<ExpertId, f32>,
     ) -> RoutingDecision {
-        self.route(tier, token_index)
+        self.select(tier, token_index, weights, None).decision
+    }
+
+    fn route_outcome_with_weights(
+        &self,
+        ctx: &RoutingContext,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingOutcome {
+        self.select(ctx.tier, ctx.token_index, weights, None)
     }
 }
 
 pub struct RoundRobinRouter {
     experts: Vec<ExpertId>,
     current: std::sync::atomic::AtomicUsize,
+    shards: Option<ShardedCursor>,
+    k_selector: Box<dyn KSelector>,
 }
 
 impl RoundRobinRouter {
-    pub fn new(experts: Vec<ExpertId>) -> Self {
+    pub fn new(experts: Vec<ExpertId>) -> Result<Self, ConfigError> {
+        if experts.is_empty() {
+            return Err(ConfigError::NoExperts);
+        }
+        Ok(Self::new_unchecked(experts))
+    }
+
+    // With no experts every decision is empty.
+    pub fn new_unchecked(experts: Vec<ExpertId>) -> Self {
         Self {
             experts,
             current: std::sync::atomic::AtomicUsize::new(0),
+            shards: None,
+            k_selector: Box::new(TierK),
         }
     }
+
+    // Spreads the cursor over `stripes` cache-padded counters. Each call then
+    // takes the next k experts instead of advancing by one, so concurrent
+    // callers get disjoint windows; rotation is fair only approximately.
+    pub fn with_shards(mut self, stripes: usize) -> Self {
+        self.shards = Some(ShardedCursor::new(stripes, DEFAULT_SHARD_BATCH));
+        self
+    }
+
+    pub fn with_k_selector(mut self, k_selector: impl KSelector + 'static) -> Self {
+        self.k_selector = Box::new(k_selector);
+        self
+    }
 }
 
 impl Router for RoundRobinRouter {
-    fn route(&self, tier: Tier, _token_index: u64) -> RoutingDecision {
-        let k = match tier {
-            Tier::Nano => 2,
-            Tier::Standard => 4,
-            Tier::Pro => 8,
-            Tier::Max => 16,
-        };
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        let k = self.k_selector.k(tier, token_index);
 
         if self.experts.is_empty() {
             return RoutingDecision {
@@ -205,9 +971,12 @@ impl Router for RoundRobinRouter {
             };
         }
 
-        let start = self
-            .current
-            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let start = match &self.shards {
+            Some(shards) => (shards.claim(k as usize) % self.experts.len() as u64) as usize,
+            None => self
+                .current
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed),
+        };
         let ids: Vec<ExpertId> = (0..k)
             .map(|i| self.experts[(start + i as usize) % self.experts.len()].clone())
             .collect();
@@ -231,12 +1000,142 @@ impl Router for RoundRobinRouter {
     ) -> RoutingDecision {
         self.route(tier, token_index)
     }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        let k = self.k_selector.k(ctx.tier, ctx.token_index);
+        RoutingOutcome::new(self.route_with_context(ctx), k)
+    }
+
+    // Weights do not influence this router.
+    fn route_outcome_with_weights(
+        &self,
+        ctx: &RoutingContext,
+        _weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingOutcome {
+        self.route_outcome(ctx)
+    }
+
+    fn snapshot(&self) -> RouterState {
+        RouterState {
+            round_robin_cursor: Some(match &self.shards {
+                Some(shards) => shards.position(),
+                None => self.current.load(std::sync::atomic::Ordering::Relaxed) as u64,
+            }),
+            ..RouterState::default()
+        }
+    }
+
+    fn restore(&self, state: &RouterState) {
+        if let Some(cursor) = state.round_robin_cursor {
+            if let Some(shards) = &self.shards {
+                shards.reset(cursor);
+                return;
+            }
+            self.current
+                .store(cursor as usize, std::sync::atomic::Ordering::Relaxed);
+        }
+    }
+}
+
+pub struct HashRouter {
+    expert_count: u32,
+    seed: u64,
+    k_selector: Box<dyn KSelector>,
+}
+
+impl HashRouter {
+    pub fn new(expert_count: u32, seed: u64) -> Result<Self, ConfigError> {
+        if expert_count == 0 {
+            return Err(ConfigError::NoExperts);
+        }
+        Ok(Self::new_unchecked(expert_count, seed))
+    }
+
+    // An expert count of zero is treated as one.
+    pub fn new_unchecked(expert_count: u32, seed: u64) -> Self {
+        Self {
+            expert_count,
+            seed,
+            k_selector: Box::new(TierK),
+        }
+    }
+
+    pub fn with_k_selector(mut self, k_selector: impl KSelector + 'static) -> Self {
+        self.k_selector = Box::new(k_selector);
+        self
+    }
+
+    pub fn route_key(&self, tier: Tier, key: &[u8]) -> RoutingDecision {
+        self.route_key_at(tier, 0, key)
+    }
+
+    fn route_key_at(&self, tier: Tier, token_index: u64, key: &[u8]) -> RoutingDecision {
+        let k = self.k_selector.k(tier, token_index);
+        let ids = self.hash_experts(key, k);
+        let n = ids.len();
+        RoutingDecision {
+            expert_ids: ids,
+            confidence_scores: vec![1.0; n],
+            gating_weights: vec![1.0; n],
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+        }
+    }
+
+    // Each slot hashes the key under its own derived seed; collisions probe
+    // linearly so the k experts are distinct whenever expert_count >= k.
+    fn hash_experts(&self, key: &[u8], k: u32) -> Vec<ExpertId> {
+        let n = self.expert_count.max(1);
+        let k = k.min(n);
+        let mut chosen: Vec<u32> = Vec::with_capacity(k as usize);
+        for i in 0..k {
+            let h = xxhash_rust::xxh64::xxh64(key, self.seed.wrapping_add(i as u64));
+            let mut val = (h % n as u64) as u32;
+            while chosen.contains(&val) {
+                val = (val + 1) % n;
+            }
+            chosen.push(val);
+        }
+        chosen.into_iter().map(index_expert_id).collect()
+    }
+}
+
+impl Router for HashRouter {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.route_key_at(tier, token_index, &token_index.to_le_bytes())
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        _weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        self.route(tier, token_index)
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        let k = self.k_selector.k(ctx.tier, ctx.token_index);
+        RoutingOutcome::new(self.route_with_context(ctx), k)
+    }
+
+    // Weights do not influence this router.
+    fn route_outcome_with_weights(
+        &self,
+        ctx: &RoutingContext,
+        _weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingOutcome {
+        self.route_outcome(ctx)
+    }
 }
 
 pub enum AnyRouter {
     Deterministic(DeterministicRouter),
     Gating(GatingRouter),
     RoundRobin(RoundRobinRouter),
+    Hash(HashRouter),
 }
 
 impl Router for AnyRouter {
@@ -245,6 +1144,7 @@ impl Router for AnyRouter {
             AnyRouter::Deterministic(r) => r.route(tier, token_index),
             AnyRouter::Gating(r) => r.route(tier, token_index),
             AnyRouter::RoundRobin(r) => r.route(tier, token_index),
+            AnyRouter::Hash(r) => r.route(tier, token_index),
         }
     }
 
@@ -258,12 +1158,142 @@ impl Router for AnyRouter {
             AnyRouter::Deterministic(r) => r.route_with_weights(tier, token_index, weights),
             AnyRouter::Gating(r) => r.route_with_weights(tier, token_index, weights),
             AnyRouter::RoundRobin(r) => r.route_with_weights(tier, token_index, weights),
+            AnyRouter::Hash(r) => r.route_with_weights(tier, token_index, weights),
+        }
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        match self {
+            AnyRouter::Deterministic(r) => r.route_with_context(ctx),
+            AnyRouter::Gating(r) => r.route_with_context(ctx),
+            AnyRouter::RoundRobin(r) => r.route_with_context(ctx),
+            AnyRouter::Hash(r) => r.route_with_context(ctx),
+        }
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        match self {
+            AnyRouter::Deterministic(r) => r.route_outcome(ctx),
+            AnyRouter::Gating(r) => r.route_outcome(ctx),
+            AnyRouter::RoundRobin(r) => r.route_outcome(ctx),
+            AnyRouter::Hash(r) => r.route_outcome(ctx),
+        }
+    }
+
+    fn route_outcome_with_weights(
+        &self,
+        ctx: &RoutingContext,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingOutcome {
+        match self {
+            AnyRouter::Deterministic(r) => r.route_outcome_with_weights(ctx, weights),
+            AnyRouter::Gating(r) => r.route_outcome_with_weights(ctx, weights),
+            AnyRouter::RoundRobin(r) => r.route_outcome_with_weights(ctx, weights),
+            AnyRouter::Hash(r) => r.route_outcome_with_weights(ctx, weights),
+        }
+    }
+
+    fn snapshot(&self) -> RouterState {
+        match self {
+            AnyRouter::Deterministic(r) => r.snapshot(),
+            AnyRouter::Gating(r) => r.snapshot(),
+            AnyRouter::RoundRobin(r) => r.snapshot(),
+            AnyRouter::Hash(r) => r.snapshot(),
+        }
+    }
+
+    fn restore(&self, state: &RouterState) {
+        match self {
+            AnyRouter::Deterministic(r) => r.restore(state),
+            AnyRouter::Gating(r) => r.restore(state),
+            AnyRouter::RoundRobin(r) => r.restore(state),
+            AnyRouter::Hash(r) => r.restore(state),
         }
     }
 }
 
+thread_local! {
+    static TOP_K_SCRATCH: std::cell::RefCell<Vec<(usize, f32)>> =
+        const { std::cell::RefCell::new(Vec::new()) };
+}
+
+// Descending total order on weights with NaN ranked last, safe for sort_by.
+pub(crate) fn descending(a: f32, b: f32) -> std::cmp::Ordering {
+    let key = |w: f32| if w.is_nan() { f32::NEG_INFINITY } else { w };
+    key(b).total_cmp(&key(a))
+}
+
+// Partial selection of the k heaviest entries, returned in descending weight
+// order. NaN weights rank last so the comparator stays a total order.
+pub(crate) fn top_k_by_weight<T>(entries: &mut [(T, f32)], k: usize) -> &[(T, f32)] {
+    let cmp = |a: &(T, f32), b: &(T, f32)| descending(a.1, b.1);
+    let k = k.min(entries.len());
+    if k == 0 {
+        return &entries[..0];
+    }
+    if k < entries.len() {
+        entries.select_nth_unstable_by(k - 1, cmp);
+    }
+    entries[..k].sort_by(cmp);
+    &entries[..k]
+}
+
+pub(crate) fn tier_k(tier: Tier) -> u32 {
+    TierK.k(tier, 0)
+}
+
+// (expert, confidence, gating weight) triples, padding missing scores with 1.0.
+pub(crate) fn decision_entries(decision: RoutingDecision) -> Vec<(ExpertId, f32, f32)> {
+    let RoutingDecision {
+        expert_ids,
+        confidence_scores,
+        gating_weights,
+        ..
+    } = decision;
+    expert_ids
+        .into_iter()
+        .enumerate()
+        .map(|(i, id)| {
+            let confidence = confidence_scores.get(i).copied().unwrap_or(1.0);
+            let weight = gating_weights.get(i).copied().unwrap_or(1.0);
+            (id, confidence, weight)
+        })
+        .collect()
+}
+
+pub(crate) fn decision_from_entries(
+    entries: Vec<(ExpertId, f32, f32)>,
+    timestamp: u64,
+) -> RoutingDecision {
+    let mut decision = RoutingDecision {
+        expert_ids: Vec::with_capacity(entries.len()),
+        confidence_scores: Vec::with_capacity(entries.len()),
+        gating_weights: Vec::with_capacity(entries.len()),
+        timestamp,
+    };
+    for (id, confidence, weight) in entries {
+        decision.expert_ids.push(id);
+        decision.confidence_scores.push(confidence);
+        decision.gating_weights.push(weight);
+    }
+    decision
+}
+
+pub(crate) fn index_expert_id(index: u32) -> ExpertId {
+    let mut bytes = [0u8; 32];
+    bytes[0..4].copy_from_slice(&index.to_le_bytes());
+    ExpertId(bytes)
+}
+
+pub(crate) fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
 pub fn create_default_router() -> DeterministicRouter {
-    DeterministicRouter::new(1024)
+    DeterministicRouter::new_unchecked(1024)
 }
 
 #[cfg(test)]
@@ -273,14 +1303,46 @@ mod tests {
 
     #[test]
     fn test_deterministic_router() {
-        let router = DeterministicRouter::new(1024);
+        let router = DeterministicRouter::new_unchecked(1024);
         let decision = router.route(Tier::Standard, 0);
         assert_eq!(decision.expert_ids.len(), 4);
     }
 
+    #[test]
+    fn test_constructors_reject_misconfiguration() {
+        assert_eq!(
+            DeterministicRouter::new(0).err(),
+            Some(ConfigError::NoExperts)
+        );
+        assert_eq!(HashRouter::new(0, 1).err(), Some(ConfigError::NoExperts));
+        assert_eq!(
+            RoundRobinRouter::new(Vec::new()).err(),
+            Some(ConfigError::NoExperts)
+        );
+        assert_eq!(
+            GatingRouter::new(-5.0).err(),
+            Some(ConfigError::InvalidTemperature(-5.0))
+        );
+        assert!(GatingRouter::new(f32::NAN).is_err());
+        assert!(GatingRouter::new(MIN_TEMPERATURE).is_ok());
+        assert_eq!(
+            DeterministicRouter::new(8)
+                .unwrap()
+                .route(Tier::Nano, 0)
+                .expert_ids
+                .len(),
+            2
+        );
+
+        assert!(DeterministicRouter::new_unchecked(0)
+            .route(Tier::Nano, 0)
+            .expert_ids
+            .is_empty());
+    }
+
     #[test]
     fn test_gating_router() {
-        let mut router = GatingRouter::new(1.0);
+        let mut router = GatingRouter::new_unchecked(1.0);
         let mut weights = HashMap::new();
         weights.insert(ExpertId([1u8; 32]), 0.5);
         weights.insert(ExpertId([2u8; 32]), 0.3);
@@ -291,10 +1353,136 @@ mod tests {
         assert_eq!(decision.expert_ids.len(), 2);
     }
 
+    #[test]
+    fn test_gating_router_min_gate_prob() {
+        let mut router = GatingRouter::new_unchecked(1.0);
+        let mut weights = HashMap::new();
+        weights.insert(ExpertId([1u8; 32]), 4.0);
+        weights.insert(ExpertId([2u8; 32]), 3.0);
+        weights.insert(ExpertId([3u8; 32]), -4.0);
+        router.set_gate_weights(weights);
+
+        let decision = router.route(Tier::Standard, 0);
+        assert_eq!(decision.expert_ids.len(), 3);
+
+        router.set_min_gate_prob(0.01, false);
+        let decision = router.route(Tier::Standard, 0);
+        assert_eq!(decision.expert_ids.len(), 2);
+        assert!(!decision.expert_ids.contains(&ExpertId([3u8; 32])));
+        assert!(decision.gating_weights.iter().sum::<f32>() < 1.0);
+
+        router.set_min_gate_prob(0.01, true);
+        let decision = router.route(Tier::Standard, 0);
+        assert!((decision.gating_weights.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+
+        let outcome = router.route_outcome(&RoutingContext::new(Tier::Standard, 0));
+        assert!(outcome.is_degraded());
+        assert_eq!(outcome.requested_k, 4);
+        assert_eq!(
+            outcome.dropped,
+            vec![DroppedExpert {
+                expert_id: ExpertId([3u8; 32]),
+                reason: DropReason::BelowThreshold,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_hash_router_stable_and_seeded() {
+        let router = HashRouter::new_unchecked(64, 7);
+        let a = router.route(Tier::Pro, 12345);
+        let b = router.route(Tier::Pro, 12345);
+        assert_eq!(a.expert_ids, b.expert_ids);
+        assert_eq!(a.expert_ids.len(), 8);
+
+        let reseeded = HashRouter::new_unchecked(64, 8).route(Tier::Pro, 12345);
+        assert_ne!(a.expert_ids, reseeded.expert_ids);
+
+        let by_key = router.route_key(Tier::Pro, &12345u64.to_le_bytes());
+        assert_eq!(a.expert_ids, by_key.expert_ids);
+    }
+
+    #[test]
+    fn test_round_robin_snapshot_restore() {
+        let experts: Vec<ExpertId> = (0..8u8).map(|i| ExpertId([i; 32])).collect();
+        let router = RoundRobinRouter::new_unchecked(experts.clone());
+        for token in 0..5 {
+            router.route(Tier::Nano, token);
+        }
+        let json = router.snapshot().to_json().unwrap();
+
+        let restarted = RoundRobinRouter::new_unchecked(experts);
+        restarted.restore(&RouterState::from_json(&json).unwrap());
+        assert_eq!(
+            restarted.route(Tier::Nano, 0).expert_ids,
+            router.route(Tier::Nano, 0).expert_ids
+        );
+    }
+
+    #[test]
+    fn test_gating_router_sigmoid_scorer() {
+        let mut router = GatingRouter::new_unchecked(1.0).with_scorer(Sigmoid);
+        router.set_gate_weight(index_expert_id(0), 2.0);
+        router.set_gate_weight(index_expert_id(1), 1.0);
+        router.set_gate_weight(index_expert_id(2), 0.0);
+
+        let decision = router.route(Tier::Nano, 0);
+        assert_eq!(
+            decision.expert_ids,
+            vec![index_expert_id(0), index_expert_id(1)]
+        );
+        assert!((decision.gating_weights[0] - 0.880_797).abs() < 1e-5);
+        assert!((decision.gating_weights[1] - 0.731_059).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_gating_router_gumbel_sampling() {
+        let mut router = GatingRouter::new_unchecked(1.0);
+        let mut weights = HashMap::new();
+        for i in 0..8u8 {
+            weights.insert(ExpertId([i; 32]), i as f32 * 0.5);
+        }
+        router.set_gate_weights(weights);
+        router.set_sampling_seed(Some(42));
+
+        let a = router.route(Tier::Standard, 7);
+        let b = router.route(Tier::Standard, 7);
+        assert_eq!(a.expert_ids, b.expert_ids);
+        let unique: std::collections::HashSet<_> = a.expert_ids.iter().collect();
+        assert_eq!(unique.len(), 4);
+
+        let mut top_hits = 0;
+        let mut distinct_sets = std::collections::HashSet::new();
+        for token in 0..500 {
+            let decision = router.route(Tier::Nano, token);
+            if decision.expert_ids.contains(&ExpertId([7u8; 32])) {
+                top_hits += 1;
+            }
+            distinct_sets.insert(decision.expert_ids);
+        }
+        assert!(top_hits > 250);
+        assert!(distinct_sets.len() > 1);
+    }
+
+    #[test]
+    fn test_route_with_weight_slice_matches_map() {
+        let router = DeterministicRouter::new_unchecked(64);
+        let entries: Vec<(ExpertId, f32)> = (0..64u32)
+            .map(|i| (index_expert_id(i), ((i * 37) % 64) as f32))
+            .collect();
+        let map: HashMap<ExpertId, f32> = entries.iter().cloned().collect();
+
+        let from_slice = router.route_with_weight_slice(Tier::Pro, 0, &entries);
+        let from_map = router.route_with_weights(Tier::Pro, 0, &map);
+        assert_eq!(from_slice.expert_ids, from_map.expert_ids);
+        assert_eq!(from_slice.expert_ids.len(), 8);
+        assert_eq!(from_slice.expert_ids[0], index_expert_id(19));
+    }
+
     proptest! {
         #[test]
         fn test_deterministic_router_returns_valid_ids(num_experts in 1u32..256, tier in 0u8..4) {
-            let router = DeterministicRouter::new(num_experts);
+            let router = DeterministicRouter::new_unchecked(num_experts);
             let tier = match tier {
                 0 => Tier::Nano,
                 1 => Tier::Standard,
@@ -309,7 +1497,7 @@ mod tests {
 
         #[test]
         fn test_deterministic_router_deterministic(num_experts in 1u32..128, tier in 0u8..4, token in 0u64..1000u64) {
-            let router = DeterministicRouter::new(num_experts);
+            let router = DeterministicRouter::new_unchecked(num_experts);
             let tier = match tier {
                 0 => Tier::Nano,
                 1 => Tier::Standard,
@@ -323,9 +1511,22 @@ mod tests {
             prop_assert_eq!(decision1.expert_ids.len(), decision2.expert_ids.len());
         }
 
+        #[test]
+        fn test_deterministic_router_unique_experts(num_experts in 1u32..64, token in any::<u64>()) {
+            let router = DeterministicRouter::new_unchecked(num_experts);
+            for tier in [Tier::Nano, Tier::Standard, Tier::Pro, Tier::Max] {
+                let decision = router.route(tier, token);
+                let unique: std::collections::HashSet<_> = decision.expert_ids.iter().collect();
+
+                prop_assert_eq!(unique.len(), decision.expert_ids.len());
+                prop_assert_eq!(decision.expert_ids.len() as u32, tier_k(tier).min(num_experts));
+                prop_assert_eq!(decision.gating_weights.len(), decision.expert_ids.len());
+            }
+        }
+
         #[test]
         fn test_router_expert_count_scaling_by_tier(num_experts in 8u32..128) {
-            let router = DeterministicRouter::new(num_experts);
+            let router = DeterministicRouter::new_unchecked(num_experts);
 
             let nano = router.route(Tier::Nano, 0);
             let standard = router.route(Tier::Standard, 0);
@@ -339,7 +1540,7 @@ mod tests {
 
         #[test]
         fn test_gating_router_valid_weights(num_experts in 2u32..16, top_k in 1u32..4) {
-            let mut router = GatingRouter::new(top_k as f32);
+            let mut router = GatingRouter::new_unchecked(top_k as f32);
 
             let mut weights = HashMap::new();
             for i in 0..num_experts {
@@ -355,9 +1556,19 @@ mod tests {
             prop_assert!(decision.expert_ids.len() <= num_experts as usize);
         }
 
+        #[test]
+        fn test_hash_router_unique_experts(num_experts in 1u32..32, token in any::<u64>()) {
+            let router = HashRouter::new_unchecked(num_experts, 0);
+            let decision = router.route(Tier::Max, token);
+            let unique: std::collections::HashSet<_> = decision.expert_ids.iter().collect();
+
+            prop_assert_eq!(decision.expert_ids.len(), 16.min(num_experts as usize));
+            prop_assert_eq!(unique.len(), decision.expert_ids.len());
+        }
+
         #[test]
         fn test_router_different_tokens_produce_results(num_experts in 4u32..32, count in 1usize..10) {
-            let router = DeterministicRouter::new(num_experts);
+            let router = DeterministicRouter::new_unchecked(num_experts);
 
             let mut results: Vec<usize> = Vec::new();
             for token in 0..count {
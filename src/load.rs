@@ -0,0 +1,293 @@
+// File: load.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Sliding-window expert load estimation for AURIA Runtime Core.
+//     A LoadWindow counts per-expert assignments over the last N tokens or
+//     the last T of wall time. Wrappers that react to load (fairness,
+//     priority steering, bias balancing) can share one window behind an Arc,
+//     fed by a MeteredRouter at the top of the stack, so every layer and
+//     every utilization metric sees the same numbers.
+//
+use crate::{
+    Router, RouterState, RoutingContext, RoutingExplanation, RoutingOutcome, StatefulRouter,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoadSpan {
+    Tokens(usize),
+    Duration(Duration),
+}
+
+pub(crate) struct WindowState {
+    span: LoadSpan,
+    history: VecDeque<(Instant, Vec<ExpertId>)>,
+    counts: HashMap<ExpertId, u64>,
+    assignments: u64,
+}
+
+impl WindowState {
+    pub(crate) fn count(&self, id: &ExpertId) -> u64 {
+        self.counts.get(id).copied().unwrap_or(0)
+    }
+
+    // Fraction of all assignments in the window that went to `id`.
+    pub(crate) fn share(&self, id: &ExpertId) -> f32 {
+        if self.assignments == 0 {
+            return 0.0;
+        }
+        self.count(id) as f32 / self.assignments as f32
+    }
+
+    pub(crate) fn push(&mut self, ids: &[ExpertId], now: Instant) {
+        for id in ids {
+            *self.counts.entry(id.clone()).or_insert(0) += 1;
+        }
+        self.assignments += ids.len() as u64;
+        self.history.push_back((now, ids.to_vec()));
+        self.evict(now);
+    }
+
+    fn evict(&mut self, now: Instant) {
+        while let Some((at, _)) = self.history.front() {
+            let expired = match self.span {
+                LoadSpan::Tokens(n) => self.history.len() > n,
+                LoadSpan::Duration(d) => now.saturating_duration_since(*at) > d,
+            };
+            if !expired {
+                return;
+            }
+            let Some((_, evicted)) = self.history.pop_front() else {
+                return;
+            };
+            self.assignments -= evicted.len() as u64;
+            for id in evicted {
+                if let Some(count) = self.counts.get_mut(&id) {
+                    *count -= 1;
+                    if *count == 0 {
+                        self.counts.remove(&id);
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub struct LoadWindow {
+    state: Mutex<WindowState>,
+}
+
+impl LoadWindow {
+    pub fn new(span: LoadSpan) -> Self {
+        let span = match span {
+            LoadSpan::Tokens(n) => LoadSpan::Tokens(n.max(1)),
+            span => span,
+        };
+        Self {
+            state: Mutex::new(WindowState {
+                span,
+                history: VecDeque::new(),
+                counts: HashMap::new(),
+                assignments: 0,
+            }),
+        }
+    }
+
+    pub fn tokens(count: usize) -> Self {
+        Self::new(LoadSpan::Tokens(count))
+    }
+
+    pub fn duration(span: Duration) -> Self {
+        Self::new(LoadSpan::Duration(span))
+    }
+
+    pub fn span(&self) -> LoadSpan {
+        self.state.lock().unwrap().span
+    }
+
+    // Records one token's expert assignments.
+    pub fn record(&self, expert_ids: &[ExpertId]) {
+        self.state.lock().unwrap().push(expert_ids, Instant::now());
+    }
+
+    pub fn count(&self, expert_id: &ExpertId) -> u64 {
+        self.lock().count(expert_id)
+    }
+
+    pub fn share(&self, expert_id: &ExpertId) -> f32 {
+        self.lock().share(expert_id)
+    }
+
+    pub fn counts(&self) -> HashMap<ExpertId, u64> {
+        self.lock().counts.clone()
+    }
+
+    pub fn tokens_observed(&self) -> usize {
+        self.lock().history.len()
+    }
+
+    pub fn assignments(&self) -> u64 {
+        self.lock().assignments
+    }
+
+    // Fraction of `expert_count` experts with any load in the window.
+    pub fn utilization(&self, expert_count: usize) -> f32 {
+        if expert_count == 0 {
+            return 0.0;
+        }
+        self.lock().counts.len() as f32 / expert_count as f32
+    }
+
+    // Hottest expert's load over the mean a perfectly balanced router would
+    // give each of `expert_count` experts.
+    pub fn load_imbalance(&self, expert_count: usize) -> f32 {
+        let state = self.lock();
+        if expert_count == 0 || state.assignments == 0 {
+            return 0.0;
+        }
+        let max = state.counts.values().copied().max().unwrap_or(0);
+        max as f32 / (state.assignments as f32 / expert_count as f32)
+    }
+
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.history.clear();
+        state.counts.clear();
+        state.assignments = 0;
+    }
+
+    // Time-based windows are trimmed on every access, not only on record.
+    pub(crate) fn lock(&self) -> MutexGuard<'_, WindowState> {
+        let mut state = self.state.lock().unwrap();
+        state.evict(Instant::now());
+        state
+    }
+}
+
+// Records every decision it returns into a shared LoadWindow. Place it at
+// the top of a stack whose wrappers observe the same window.
+pub struct MeteredRouter<R: Router> {
+    inner: R,
+    window: Arc<LoadWindow>,
+}
+
+impl<R: Router> MeteredRouter<R> {
+    pub fn new(inner: R, window: Arc<LoadWindow>) -> Self {
+        Self { inner, window }
+    }
+
+    pub fn window(&self) -> &Arc<LoadWindow> {
+        &self.window
+    }
+
+    fn metered(&self, decision: RoutingDecision) -> RoutingDecision {
+        self.window.record(&decision.expert_ids);
+        decision
+    }
+}
+
+impl<R: Router> Router for MeteredRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.metered(self.inner.route(tier, token_index))
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        self.metered(self.inner.route_with_weights(tier, token_index, weights))
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.metered(self.inner.route_with_context(ctx))
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        let outcome = self.inner.route_outcome(ctx);
+        self.window.record(&outcome.decision.expert_ids);
+        outcome
+    }
+
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        let explanation = self.inner.explain(ctx);
+        self.window.record(&explanation.outcome.decision.expert_ids);
+        explanation
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for MeteredRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.inner.begin_sequence(seq_id)
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.inner.end_sequence(seq_id)
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.inner.active_sequences()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        index_expert_id, DeterministicRouter, FairRouter, PriorityClass, PriorityRouter,
+        ShareBounds,
+    };
+
+    #[test]
+    fn test_token_and_time_windows_evict() {
+        let window = LoadWindow::tokens(2);
+        let (a, b) = (index_expert_id(0), index_expert_id(1));
+        window.record(&[a.clone(), b.clone()]);
+        window.record(std::slice::from_ref(&a));
+        window.record(std::slice::from_ref(&a));
+        assert_eq!(window.count(&a), 2);
+        assert_eq!(window.count(&b), 0);
+        assert_eq!(window.share(&a), 1.0);
+        assert_eq!(window.utilization(4), 0.25);
+
+        let window = LoadWindow::duration(Duration::from_millis(20));
+        window.record(std::slice::from_ref(&a));
+        assert_eq!(window.count(&a), 1);
+        std::thread::sleep(Duration::from_millis(40));
+        assert_eq!(window.count(&a), 0);
+        assert_eq!(window.tokens_observed(), 0);
+    }
+
+    #[test]
+    fn test_wrappers_share_one_window() {
+        let window = Arc::new(LoadWindow::tokens(64));
+        let fair = FairRouter::new(DeterministicRouter::new_unchecked(64), 64)
+            .with_load_window(window.clone());
+        fair.set_bounds(index_expert_id(0), ShareBounds::ceiling(0.2));
+        let router = MeteredRouter::new(
+            PriorityRouter::new(fair, 64).with_load_window(window.clone()),
+            window.clone(),
+        );
+
+        let ctx = RoutingContext::new(Tier::Nano, 0).with_priority(PriorityClass::Interactive);
+        for _ in 0..20 {
+            router.route_with_context(&ctx);
+        }
+        assert_eq!(window.tokens_observed(), 20);
+        assert_eq!(window.assignments(), 40);
+        assert!(window.share(&index_expert_id(0)) <= 0.21);
+        assert!(window.load_imbalance(64) > 1.0);
+    }
+}
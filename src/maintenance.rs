@@ -0,0 +1,382 @@
+// File: maintenance.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Scheduled expert maintenance for AURIA Runtime Core.
+//     A MaintenanceWindow takes an expert out of rotation between a start
+//     and an end point. Ahead of the window, new sequences are drained away
+//     from the expert in a growing share; during it the expert is excluded
+//     outright; afterwards its traffic ramps back, so taking an expert down
+//     never causes an abrupt quality cliff.
+//
+use crate::{
+    decision_entries, decision_from_entries, splitmix64, DropReason, Router, RouterState,
+    RoutingContext, RoutingExplanation, RoutingOutcome, StatefulRouter,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+const MASK: &str = "maintenance";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MaintenanceClock {
+    // Windows are given in seconds since the Unix epoch.
+    UnixSeconds,
+    // Windows are given in tokens routed through the MaintenanceRouter.
+    Tokens,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MaintenanceWindow {
+    pub expert_id: ExpertId,
+    pub start: u64,
+    pub end: u64,
+    // Lead before `start` over which new sequences are drained away.
+    pub drain: u64,
+    // Span after `end` over which traffic returns.
+    pub ramp: u64,
+}
+
+impl MaintenanceWindow {
+    pub fn new(expert_id: ExpertId, window: Range<u64>) -> Self {
+        Self {
+            expert_id,
+            start: window.start,
+            end: window.end.max(window.start),
+            drain: 0,
+            ramp: 0,
+        }
+    }
+
+    pub fn with_drain(mut self, drain: u64) -> Self {
+        self.drain = drain;
+        self
+    }
+
+    pub fn with_ramp(mut self, ramp: u64) -> Self {
+        self.ramp = ramp;
+        self
+    }
+
+    pub fn is_active(&self, now: u64) -> bool {
+        (self.start..self.end).contains(&now)
+    }
+
+    // Share of traffic kept away from the expert at `now`: rising linearly
+    // to 1 over the drain, 1 during the window, falling over the ramp.
+    pub fn exclusion(&self, now: u64) -> f32 {
+        if self.is_active(now) {
+            1.0
+        } else if now < self.start {
+            let lead = self.start - now;
+            if lead > self.drain {
+                0.0
+            } else {
+                1.0 - lead as f32 / self.drain as f32
+            }
+        } else {
+            let since = now - self.end;
+            if since >= self.ramp {
+                0.0
+            } else {
+                1.0 - since as f32 / self.ramp as f32
+            }
+        }
+    }
+}
+
+pub struct MaintenanceRouter<R: Router> {
+    inner: R,
+    clock: MaintenanceClock,
+    windows: Mutex<Vec<MaintenanceWindow>>,
+    tokens: AtomicU64,
+    // Experts each live sequence was drained away from when it began.
+    sequences: Mutex<HashMap<u64, Vec<ExpertId>>>,
+}
+
+impl<R: Router> MaintenanceRouter<R> {
+    pub fn new(inner: R, clock: MaintenanceClock) -> Self {
+        Self {
+            inner,
+            clock,
+            windows: Mutex::new(Vec::new()),
+            tokens: AtomicU64::new(0),
+            sequences: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn schedule(&self, window: MaintenanceWindow) {
+        self.windows.lock().unwrap().push(window);
+    }
+
+    // Cancels every window scheduled for `expert_id`.
+    pub fn cancel(&self, expert_id: &ExpertId) {
+        self.windows
+            .lock()
+            .unwrap()
+            .retain(|w| &w.expert_id != expert_id);
+    }
+
+    pub fn windows(&self) -> Vec<MaintenanceWindow> {
+        self.windows.lock().unwrap().clone()
+    }
+
+    pub fn now(&self) -> u64 {
+        match self.clock {
+            MaintenanceClock::UnixSeconds => std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            MaintenanceClock::Tokens => self.tokens.load(Ordering::Relaxed),
+        }
+    }
+
+    // Windows finished ramping back are dropped as a side effect.
+    fn exclusions(&self, now: u64) -> Vec<(ExpertId, f32)> {
+        let mut windows = self.windows.lock().unwrap();
+        windows.retain(|w| now < w.end.saturating_add(w.ramp));
+        windows
+            .iter()
+            .map(|w| (w.expert_id.clone(), w.exclusion(now)))
+            .filter(|(_, level)| *level > 0.0)
+            .collect()
+    }
+
+    fn draw(key: u64, expert_id: &ExpertId) -> f32 {
+        let h = splitmix64(key ^ xxhash_rust::xxh64::xxh64(&expert_id.0, 0));
+        (h >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    // Routing advances the Tokens clock once per call.
+    fn tick(&self) -> u64 {
+        match self.clock {
+            MaintenanceClock::Tokens => self.tokens.fetch_add(1, Ordering::Relaxed),
+            MaintenanceClock::UnixSeconds => self.now(),
+        }
+    }
+
+    // Live sequences keep the drain choice made when they began; other
+    // traffic draws per token.
+    fn excluded(&self, ctx: &RoutingContext, now: u64) -> Vec<ExpertId> {
+        let exclusions = self.exclusions(now);
+        if exclusions.is_empty() {
+            return Vec::new();
+        }
+        let sequences = self.sequences.lock().unwrap();
+        let drained = ctx.sequence_id.and_then(|seq_id| sequences.get(&seq_id));
+        exclusions
+            .into_iter()
+            .filter(|(id, level)| match drained {
+                _ if *level >= 1.0 => true,
+                Some(drained) => drained.contains(id),
+                None => Self::draw(ctx.token_index, id) < *level,
+            })
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    fn enforce(
+        &self,
+        excluded: &[ExpertId],
+        mut outcome: RoutingOutcome,
+        candidates: impl FnOnce() -> Vec<(ExpertId, f32, f32)>,
+    ) -> RoutingOutcome {
+        if !outcome
+            .decision
+            .expert_ids
+            .iter()
+            .any(|id| excluded.contains(id))
+        {
+            return outcome;
+        }
+        let timestamp = outcome.decision.timestamp;
+        let target = outcome.decision.expert_ids.len();
+        let (mut kept, removed): (Vec<_>, Vec<_>) = decision_entries(outcome.decision.clone())
+            .into_iter()
+            .partition(|(id, _, _)| !excluded.contains(id));
+        for entry in candidates() {
+            if kept.len() == target {
+                break;
+            }
+            if excluded.contains(&entry.0) || kept.iter().any(|(k, _, _)| *k == entry.0) {
+                continue;
+            }
+            kept.push(entry);
+            outcome.fallback_used = true;
+        }
+        outcome.decision = decision_from_entries(kept, timestamp);
+        for (id, _, _) in removed {
+            outcome.record_drop(
+                id,
+                DropReason::Masked {
+                    mask: MASK.to_string(),
+                },
+            );
+        }
+        outcome
+    }
+
+    fn wide(&self, ctx: &RoutingContext) -> Vec<(ExpertId, f32, f32)> {
+        let mut wide = ctx.clone();
+        wide.tier = Tier::Max;
+        decision_entries(self.inner.route_with_context(&wide))
+    }
+}
+
+impl<R: Router> Router for MaintenanceRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.route_with_context(&RoutingContext::new(tier, token_index))
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        let excluded = self.excluded(&RoutingContext::new(tier, token_index), self.tick());
+        let allowed: HashMap<ExpertId, f32> = weights
+            .iter()
+            .filter(|(id, _)| !excluded.contains(id))
+            .map(|(id, w)| (id.clone(), *w))
+            .collect();
+        self.inner.route_with_weights(tier, token_index, &allowed)
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.route_outcome(ctx).decision
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        let excluded = self.excluded(ctx, self.tick());
+        self.enforce(&excluded, self.inner.route_outcome(ctx), || self.wide(ctx))
+    }
+
+    // Explaining reads the clock without advancing it.
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        let excluded = self.excluded(ctx, self.now());
+        let explanation = self.inner.explain(ctx);
+        let outcome = self.enforce(&excluded, explanation.outcome.clone(), || self.wide(ctx));
+        explanation.layer(MASK, outcome)
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for MaintenanceRouter<R> {
+    // The drain decision is made once, when the sequence begins, so a
+    // sequence never loses an expert mid-flight before its window starts.
+    fn begin_sequence(&self, seq_id: u64) {
+        let drained = self
+            .exclusions(self.now())
+            .into_iter()
+            .filter(|(id, level)| Self::draw(splitmix64(seq_id), id) < *level)
+            .map(|(id, _)| id)
+            .collect();
+        self.sequences.lock().unwrap().insert(seq_id, drained);
+        self.inner.begin_sequence(seq_id);
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.sequences.lock().unwrap().remove(&seq_id);
+        self.inner.end_sequence(seq_id);
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.sequences.lock().unwrap().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter};
+
+    #[test]
+    fn test_window_drains_excludes_and_ramps_back() {
+        let window = MaintenanceWindow::new(index_expert_id(0), 100..200)
+            .with_drain(50)
+            .with_ramp(50);
+        assert_eq!(window.exclusion(40), 0.0);
+        assert_eq!(window.exclusion(75), 0.5);
+        assert_eq!(window.exclusion(150), 1.0);
+        assert_eq!(window.exclusion(225), 0.5);
+        assert_eq!(window.exclusion(250), 0.0);
+
+        let router = MaintenanceRouter::new(
+            DeterministicRouter::new_unchecked(64),
+            MaintenanceClock::Tokens,
+        );
+        router.schedule(window);
+        let hits: Vec<bool> = (0..260)
+            .map(|_| {
+                let outcome = router.route_outcome(&RoutingContext::new(Tier::Nano, 0));
+                assert_eq!(outcome.decision.expert_ids.len(), 2);
+                outcome.decision.expert_ids.contains(&index_expert_id(0))
+            })
+            .collect();
+        assert!(hits[..50].iter().all(|&h| h));
+        assert!(hits[100..200].iter().all(|&h| !h));
+        assert!(hits[250..].iter().all(|&h| h));
+        let draining = hits[50..100].iter().filter(|&&h| h).count();
+        assert!((10..40).contains(&draining), "draining = {}", draining);
+        assert!(router.windows().is_empty());
+    }
+
+    #[test]
+    fn test_sequences_keep_their_drain_decision() {
+        let router = MaintenanceRouter::new(
+            DeterministicRouter::new_unchecked(64),
+            MaintenanceClock::Tokens,
+        );
+        router.schedule(MaintenanceWindow::new(index_expert_id(0), 1000..2000).with_drain(1000));
+        for _ in 0..500 {
+            router.route(Tier::Nano, 1);
+        }
+        // Half-way through the drain, about half the new sequences avoid
+        // expert 0, and each one sticks with its choice.
+        let mut drained = 0;
+        for seq in 0..40 {
+            router.begin_sequence(seq);
+            let ctx = RoutingContext::new(Tier::Nano, 0).with_sequence_id(seq);
+            let uses: Vec<bool> = (0..5)
+                .map(|_| {
+                    router
+                        .route_with_context(&ctx)
+                        .expert_ids
+                        .contains(&index_expert_id(0))
+                })
+                .collect();
+            assert!(uses.iter().all(|&u| u == uses[0]));
+            drained += !uses[0] as usize;
+            router.end_sequence(seq);
+        }
+        assert!((8..32).contains(&drained), "drained = {}", drained);
+        assert_eq!(router.active_sequences(), 0);
+    }
+
+    #[test]
+    fn test_explain_does_not_advance_clock() {
+        let router = MaintenanceRouter::new(
+            DeterministicRouter::new_unchecked(64),
+            MaintenanceClock::Tokens,
+        );
+        router.schedule(MaintenanceWindow::new(index_expert_id(0), 10..20));
+        let ctx = RoutingContext::new(Tier::Nano, 0);
+        for _ in 0..50 {
+            router.explain(&ctx);
+        }
+        assert_eq!(router.now(), 0);
+        assert_eq!(router.windows().len(), 1);
+        router.route_outcome(&ctx);
+        assert_eq!(router.now(), 1);
+    }
+}
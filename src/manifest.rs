@@ -0,0 +1,230 @@
+// File: manifest.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Per-model-revision tier manifests for AURIA Runtime Core.
+//     A RevisionManifest maps each Tier to its k, gate temperature and
+//     allowed expert set for one model revision, so a single process can
+//     serve revisions with different MoE widths: each revision's manifest
+//     configures its own GatingRouter, and a ProfileRouter keyed by
+//     revision name selects between them.
+//
+use crate::{GatingRouter, ManifestError, TierKTable};
+use auria_core::{ExpertId, Tier};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+fn default_temperature() -> f32 {
+    1.0
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TierSpec {
+    pub k: u32,
+    #[serde(default = "default_temperature")]
+    pub temperature: f32,
+    // Empty means every expert with a gate weight is eligible.
+    #[serde(
+        default,
+        with = "crate::audit::hex_ids",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub allowed_experts: Vec<[u8; 32]>,
+}
+
+impl TierSpec {
+    pub fn new(k: u32) -> Self {
+        Self {
+            k,
+            temperature: default_temperature(),
+            allowed_experts: Vec::new(),
+        }
+    }
+
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = temperature;
+        self
+    }
+
+    pub fn with_allowed_experts(mut self, experts: impl IntoIterator<Item = ExpertId>) -> Self {
+        self.allowed_experts = experts.into_iter().map(|id| id.0).collect();
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RevisionManifest {
+    pub revision: String,
+    pub nano: TierSpec,
+    pub standard: TierSpec,
+    pub pro: TierSpec,
+    pub max: TierSpec,
+}
+
+impl RevisionManifest {
+    pub fn from_json(json: &str) -> Result<Self, ManifestError> {
+        let manifest: Self =
+            serde_json::from_str(json).map_err(|e| ManifestError::Parse(e.to_string()))?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, ManifestError> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| ManifestError::Io(format!("{}: {}", path.display(), e)))?;
+        Self::from_json(&json)
+    }
+
+    pub fn tier(&self, tier: Tier) -> &TierSpec {
+        match tier {
+            Tier::Nano => &self.nano,
+            Tier::Standard => &self.standard,
+            Tier::Pro => &self.pro,
+            Tier::Max => &self.max,
+        }
+    }
+
+    pub fn k_table(&self) -> TierKTable {
+        TierKTable {
+            nano: self.nano.k,
+            standard: self.standard.k,
+            pro: self.pro.k,
+            max: self.max.k,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ManifestError> {
+        for tier in [Tier::Nano, Tier::Standard, Tier::Pro, Tier::Max] {
+            let spec = self.tier(tier);
+            let invalid = |reason: &str| ManifestError::InvalidTier {
+                revision: self.revision.clone(),
+                tier,
+                reason: reason.to_string(),
+            };
+            if spec.k == 0 {
+                return Err(invalid("k must be at least 1"));
+            }
+            if !(spec.temperature.is_finite() && spec.temperature > 0.0) {
+                return Err(invalid("temperature must be finite and positive"));
+            }
+            if !spec.allowed_experts.is_empty() && (spec.allowed_experts.len() as u32) < spec.k {
+                return Err(invalid("fewer allowed experts than k"));
+            }
+        }
+        Ok(())
+    }
+
+    // A copy of `base` configured for this revision: the manifest's k table,
+    // per-tier temperatures and allowed expert sets replace base's.
+    pub fn configure(&self, base: GatingRouter) -> GatingRouter {
+        let mut router = base.with_k_selector(self.k_table());
+        router.set_revision_tiers(Some(RevisionTiers::from(self)));
+        router
+    }
+}
+
+pub(crate) struct RevisionTiers {
+    temperature: [f32; 4],
+    allowed: [HashSet<ExpertId>; 4],
+}
+
+fn tier_slot(tier: Tier) -> usize {
+    match tier {
+        Tier::Nano => 0,
+        Tier::Standard => 1,
+        Tier::Pro => 2,
+        Tier::Max => 3,
+    }
+}
+
+impl RevisionTiers {
+    pub(crate) fn temperature(&self, tier: Tier) -> f32 {
+        self.temperature[tier_slot(tier)]
+    }
+
+    pub(crate) fn allowed(&self, tier: Tier) -> Option<&HashSet<ExpertId>> {
+        Some(&self.allowed[tier_slot(tier)]).filter(|set| !set.is_empty())
+    }
+}
+
+impl From<&RevisionManifest> for RevisionTiers {
+    fn from(manifest: &RevisionManifest) -> Self {
+        let specs = [
+            &manifest.nano,
+            &manifest.standard,
+            &manifest.pro,
+            &manifest.max,
+        ];
+        Self {
+            temperature: specs.map(|s| s.temperature.max(0.01)),
+            allowed: specs.map(|s| s.allowed_experts.iter().map(|id| ExpertId(*id)).collect()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, ProfileRouter, Router, RoutingContext};
+
+    fn base() -> GatingRouter {
+        let mut router = GatingRouter::new_unchecked(1.0);
+        for i in 0..16 {
+            router.set_gate_weight(index_expert_id(i), i as f32);
+        }
+        router
+    }
+
+    #[test]
+    fn test_revisions_with_different_widths() {
+        let narrow = RevisionManifest {
+            revision: "r1".into(),
+            nano: TierSpec::new(1).with_allowed_experts((0..8).map(index_expert_id)),
+            standard: TierSpec::new(2).with_allowed_experts((0..8).map(index_expert_id)),
+            pro: TierSpec::new(2).with_allowed_experts((0..8).map(index_expert_id)),
+            max: TierSpec::new(4).with_temperature(0.5),
+        };
+        let json = narrow.to_json().unwrap();
+        assert_eq!(RevisionManifest::from_json(&json).unwrap(), narrow);
+        let wide = RevisionManifest::from_json(
+            r#"{"revision": "r2", "nano": {"k": 2}, "standard": {"k": 4},
+                "pro": {"k": 8}, "max": {"k": 16}}"#,
+        )
+        .unwrap();
+
+        let router = ProfileRouter::new("r1", narrow.configure(base()));
+        router.set_profile("r2", wide.configure(base()));
+
+        let r1 =
+            router.route_with_context(&RoutingContext::new(Tier::Standard, 0).with_profile("r1"));
+        assert_eq!(r1.expert_ids, vec![index_expert_id(7), index_expert_id(6)]);
+        let r2 =
+            router.route_with_context(&RoutingContext::new(Tier::Standard, 0).with_profile("r2"));
+        assert_eq!(r2.expert_ids.len(), 4);
+        assert_eq!(r2.expert_ids[0], index_expert_id(15));
+    }
+
+    #[test]
+    fn test_invalid_manifest_rejected() {
+        let err = RevisionManifest::from_json(
+            r#"{"revision": "bad", "nano": {"k": 0}, "standard": {"k": 4},
+                "pro": {"k": 8}, "max": {"k": 16}}"#,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ManifestError::InvalidTier {
+                tier: Tier::Nano,
+                ..
+            }
+        ));
+        assert!(matches!(
+            RevisionManifest::from_json("{}").unwrap_err(),
+            ManifestError::Parse(_)
+        ));
+    }
+}
@@ -0,0 +1,93 @@
+// File: mixer.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Token-index mixing for AURIA Runtime Core.
+//     A TokenMixer maps a token index and seed to the value the
+//     DeterministicRouter reduces modulo the expert count, so consecutive
+//     tokens need not land on consecutive experts and batch strides that
+//     share factors with the expert count do not produce striped hot spots.
+//
+pub trait TokenMixer: Send + Sync {
+    fn mix(&self, token_index: u64, seed: u64) -> u64;
+}
+
+impl<F> TokenMixer for F
+where
+    F: Fn(u64, u64) -> u64 + Send + Sync,
+{
+    fn mix(&self, token_index: u64, seed: u64) -> u64 {
+        self(token_index, seed)
+    }
+}
+
+// The original scheme: the seed only offsets the starting expert.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IdentityMix;
+
+impl TokenMixer for IdentityMix {
+    fn mix(&self, token_index: u64, seed: u64) -> u64 {
+        token_index.wrapping_add(seed)
+    }
+}
+
+// Fibonacci hashing; the high half is folded down because the result is
+// reduced modulo an arbitrary expert count.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MultiplyShiftMix;
+
+impl TokenMixer for MultiplyShiftMix {
+    fn mix(&self, token_index: u64, seed: u64) -> u64 {
+        let h = (token_index ^ seed).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        h ^ (h >> 32)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SplitMix64Mix;
+
+impl TokenMixer for SplitMix64Mix {
+    fn mix(&self, token_index: u64, seed: u64) -> u64 {
+        crate::splitmix64(token_index ^ seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter, Router};
+    use auria_core::Tier;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_mixers_break_stride_stripes() {
+        // A stride of 8 over 64 experts only ever starts at 8 experts
+        // without mixing.
+        let starts = |router: &DeterministicRouter| {
+            let mut counts: HashMap<_, usize> = HashMap::new();
+            for token in (0..4096).step_by(8) {
+                *counts
+                    .entry(router.route(Tier::Nano, token).expert_ids[0].clone())
+                    .or_default() += 1;
+            }
+            counts.len()
+        };
+        assert_eq!(starts(&DeterministicRouter::new_unchecked(64)), 8);
+        assert!(starts(&DeterministicRouter::new_unchecked(64).with_mixer(MultiplyShiftMix)) > 48);
+        assert!(starts(&DeterministicRouter::new_unchecked(64).with_mixer(SplitMix64Mix)) > 48);
+
+        let seeded = DeterministicRouter::new_unchecked(64).with_seed(3);
+        assert_eq!(
+            seeded.route(Tier::Nano, 0).expert_ids[0],
+            index_expert_id(3)
+        );
+        let a = DeterministicRouter::new_unchecked(64)
+            .with_mixer(SplitMix64Mix)
+            .with_seed(1);
+        let b = DeterministicRouter::new_unchecked(64)
+            .with_mixer(SplitMix64Mix)
+            .with_seed(2);
+        assert!(
+            (0..16).any(|t| a.route(Tier::Nano, t).expert_ids != b.route(Tier::Nano, t).expert_ids)
+        );
+    }
+}
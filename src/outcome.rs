@@ -0,0 +1,85 @@
+// File: outcome.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Routing outcomes with drop and fallback metadata for AURIA Runtime Core.
+//     Wraps a RoutingDecision with the k that was requested, the experts
+//     that were dropped and why, whether a fallback was used, and which
+//     masks removed candidates, so degraded decisions can be told apart
+//     from deliberate ones.
+//
+use auria_core::{ExpertId, RoutingDecision, Tier};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DropReason {
+    Capacity,
+    RateLimited,
+    ShareCeiling,
+    // Displaced to pull an expert below its share floor into the decision.
+    ShareFloor,
+    BelowThreshold,
+    Duplicate,
+    Deprioritized,
+    Masked { mask: String },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DroppedExpert {
+    pub expert_id: ExpertId,
+    pub reason: DropReason,
+}
+
+#[derive(Clone, Debug)]
+pub struct RoutingOutcome {
+    pub decision: RoutingDecision,
+    pub requested_k: u32,
+    // Leading always-active experts; routed picks follow them.
+    pub shared_count: u32,
+    pub dropped: Vec<DroppedExpert>,
+    pub fallback_used: bool,
+    pub masks_applied: Vec<String>,
+    // Requested tier when the decision was served at a lower one.
+    pub downgraded_from: Option<Tier>,
+}
+
+impl RoutingOutcome {
+    pub fn new(decision: RoutingDecision, requested_k: u32) -> Self {
+        Self {
+            decision,
+            requested_k,
+            shared_count: 0,
+            dropped: Vec::new(),
+            fallback_used: false,
+            masks_applied: Vec::new(),
+            downgraded_from: None,
+        }
+    }
+
+    pub fn record_drop(&mut self, expert_id: ExpertId, reason: DropReason) {
+        if let DropReason::Masked { mask } = &reason {
+            if !self.masks_applied.contains(mask) {
+                self.masks_applied.push(mask.clone());
+            }
+        }
+        self.dropped.push(DroppedExpert { expert_id, reason });
+    }
+
+    pub fn routed_experts(&self) -> &[ExpertId] {
+        let shared = (self.shared_count as usize).min(self.decision.expert_ids.len());
+        &self.decision.expert_ids[shared..]
+    }
+
+    pub fn shortfall(&self) -> u32 {
+        self.requested_k
+            .saturating_sub(self.decision.expert_ids.len() as u32)
+    }
+
+    // A decision is degraded when anything was dropped, a fallback was
+    // needed, or fewer experts than requested were activated.
+    pub fn is_degraded(&self) -> bool {
+        !self.dropped.is_empty() || self.fallback_used || self.shortfall() > 0
+    }
+
+    pub fn into_decision(self) -> RoutingDecision {
+        self.decision
+    }
+}
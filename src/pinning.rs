@@ -0,0 +1,315 @@
+// File: pinning.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Expert pinning rules for AURIA Runtime Core.
+//     A PinningPolicy is evaluated before the inner router and can force
+//     fixed experts for given layers and sequence positions, e.g. a
+//     generalist expert for early layers or the first tokens of a sequence.
+//
+use crate::{
+    Router, RouterState, RoutingContext, RoutingExplanation, RoutingOutcome, StatefulRouter,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::HashMap;
+use std::ops::Range;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PinMode {
+    // Use only the pinned experts; the inner router is not consulted.
+    Exclusive,
+    // Place the pinned experts first, then fill with the inner router's picks.
+    Include,
+}
+
+#[derive(Clone, Debug)]
+pub struct PinRule {
+    pub layers: Option<Range<u32>>,
+    pub positions: Option<Range<u64>>,
+    pub experts: Vec<ExpertId>,
+    pub mode: PinMode,
+}
+
+impl PinRule {
+    pub fn new(experts: Vec<ExpertId>, mode: PinMode) -> Self {
+        Self {
+            layers: None,
+            positions: None,
+            experts,
+            mode,
+        }
+    }
+
+    pub fn for_layers(mut self, layers: Range<u32>) -> Self {
+        self.layers = Some(layers);
+        self
+    }
+
+    pub fn for_positions(mut self, positions: Range<u64>) -> Self {
+        self.positions = Some(positions);
+        self
+    }
+
+    pub fn matches(&self, ctx: &RoutingContext) -> bool {
+        let layer_ok = self.layers.as_ref().is_none_or(|r| r.contains(&ctx.layer));
+        let position_ok = self
+            .positions
+            .as_ref()
+            .is_none_or(|r| r.contains(&ctx.sequence_position));
+        layer_ok && position_ok
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PinningPolicy {
+    rules: Vec<PinRule>,
+}
+
+impl PinningPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_rule(&mut self, rule: PinRule) {
+        self.rules.push(rule);
+    }
+
+    pub fn with_rule(mut self, rule: PinRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn rules(&self) -> &[PinRule] {
+        &self.rules
+    }
+
+    // First matching rule wins.
+    pub fn evaluate(&self, ctx: &RoutingContext) -> Option<&PinRule> {
+        self.rules.iter().find(|rule| rule.matches(ctx))
+    }
+}
+
+pub struct PinnedRouter<R: Router> {
+    inner: R,
+    policy: PinningPolicy,
+}
+
+impl<R: Router> PinnedRouter<R> {
+    pub fn new(inner: R, policy: PinningPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    pub fn policy(&self) -> &PinningPolicy {
+        &self.policy
+    }
+
+    fn pinned_decision(rule: &PinRule, inner: Option<RoutingDecision>) -> RoutingDecision {
+        let mut expert_ids = rule.experts.clone();
+        let mut confidence_scores = vec![1.0; expert_ids.len()];
+        let mut gating_weights = vec![1.0; expert_ids.len()];
+        let mut timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        if let Some(inner) = inner {
+            let budget = inner.expert_ids.len().max(expert_ids.len());
+            for (i, id) in inner.expert_ids.iter().enumerate() {
+                if expert_ids.len() >= budget {
+                    break;
+                }
+                if !expert_ids.contains(id) {
+                    expert_ids.push(id.clone());
+                    confidence_scores.push(inner.confidence_scores.get(i).copied().unwrap_or(1.0));
+                    gating_weights.push(inner.gating_weights.get(i).copied().unwrap_or(1.0));
+                }
+            }
+            timestamp = inner.timestamp;
+        }
+
+        RoutingDecision {
+            expert_ids,
+            confidence_scores,
+            gating_weights,
+            timestamp,
+        }
+    }
+
+    // Starts from the inner outcome so its drops, fallbacks and masks are
+    // kept; a pin may ask for more experts than the inner router did.
+    fn pinned_outcome(rule: &PinRule, inner: Option<RoutingOutcome>) -> RoutingOutcome {
+        let pinned = rule.experts.len() as u32;
+        match inner {
+            Some(mut outcome) => {
+                outcome.requested_k = outcome.requested_k.max(pinned);
+                outcome.decision = Self::pinned_decision(rule, Some(outcome.decision));
+                outcome
+            }
+            None => RoutingOutcome::new(Self::pinned_decision(rule, None), pinned),
+        }
+    }
+}
+
+impl<R: Router> Router for PinnedRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.route_with_context(&RoutingContext::new(tier, token_index))
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        let ctx = RoutingContext::new(tier, token_index);
+        match self.policy.evaluate(&ctx) {
+            Some(rule) if rule.mode == PinMode::Exclusive => Self::pinned_decision(rule, None),
+            Some(rule) => Self::pinned_decision(
+                rule,
+                Some(self.inner.route_with_weights(tier, token_index, weights)),
+            ),
+            None => self.inner.route_with_weights(tier, token_index, weights),
+        }
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        match self.policy.evaluate(ctx) {
+            Some(rule) if rule.mode == PinMode::Exclusive => Self::pinned_decision(rule, None),
+            Some(rule) => Self::pinned_decision(rule, Some(self.inner.route_with_context(ctx))),
+            None => self.inner.route_with_context(ctx),
+        }
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        match self.policy.evaluate(ctx) {
+            Some(rule) if rule.mode == PinMode::Exclusive => Self::pinned_outcome(rule, None),
+            Some(rule) => Self::pinned_outcome(rule, Some(self.inner.route_outcome(ctx))),
+            None => self.inner.route_outcome(ctx),
+        }
+    }
+
+    fn route_outcome_with_weights(
+        &self,
+        ctx: &RoutingContext,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingOutcome {
+        match self.policy.evaluate(ctx) {
+            Some(rule) if rule.mode == PinMode::Exclusive => Self::pinned_outcome(rule, None),
+            Some(rule) => Self::pinned_outcome(
+                rule,
+                Some(self.inner.route_outcome_with_weights(ctx, weights)),
+            ),
+            None => self.inner.route_outcome_with_weights(ctx, weights),
+        }
+    }
+
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        let Some(rule) = self.policy.evaluate(ctx) else {
+            return self.inner.explain(ctx);
+        };
+        let (explanation, inner) = match rule.mode {
+            PinMode::Exclusive => (RoutingExplanation::empty(ctx), None),
+            PinMode::Include => {
+                let explanation = self.inner.explain(ctx);
+                let decision = explanation.outcome.decision.clone();
+                (explanation, Some(decision))
+            }
+        };
+        let requested_k = explanation
+            .outcome
+            .requested_k
+            .max(rule.experts.len() as u32);
+        let outcome = RoutingOutcome::new(Self::pinned_decision(rule, inner), requested_k);
+        explanation.layer("pinning", outcome)
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for PinnedRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.inner.begin_sequence(seq_id)
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.inner.end_sequence(seq_id)
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.inner.active_sequences()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        index_expert_id, DeterministicRouter, GatingRouter, RouterConfig, ValidatingRouter,
+    };
+
+    fn generalist() -> ExpertId {
+        ExpertId([0xEE; 32])
+    }
+
+    #[test]
+    fn test_pinning_by_layer_and_position() {
+        let policy = PinningPolicy::new()
+            .with_rule(PinRule::new(vec![generalist()], PinMode::Exclusive).for_layers(0..1))
+            .with_rule(PinRule::new(vec![generalist()], PinMode::Include).for_positions(0..4));
+        let router = PinnedRouter::new(DeterministicRouter::new_unchecked(64), policy);
+
+        let layer0 = router.route_with_context(&RoutingContext::new(Tier::Standard, 100));
+        assert_eq!(layer0.expert_ids, vec![generalist()]);
+
+        let early =
+            router.route_with_context(&RoutingContext::new(Tier::Standard, 2).with_layer(3));
+        assert_eq!(early.expert_ids.len(), 4);
+        assert_eq!(early.expert_ids[0], generalist());
+
+        let late =
+            router.route_with_context(&RoutingContext::new(Tier::Standard, 100).with_layer(3));
+        assert!(!late.expert_ids.contains(&generalist()));
+        assert_eq!(late.expert_ids.len(), 4);
+    }
+
+    #[test]
+    fn test_exclusive_pin_is_not_degraded() {
+        let policy = PinningPolicy::new()
+            .with_rule(PinRule::new(vec![generalist()], PinMode::Exclusive).for_layers(0..1));
+        let router = ValidatingRouter::new(
+            PinnedRouter::new(DeterministicRouter::new_unchecked(64), policy),
+            RouterConfig::new(),
+        );
+        let pinned = router.route_outcome(&RoutingContext::new(Tier::Standard, 7));
+        assert_eq!(pinned.requested_k, 1);
+        assert_eq!(pinned.decision.expert_ids, vec![generalist()]);
+        assert!(!pinned.is_degraded());
+
+        let routed = router.route_outcome(&RoutingContext::new(Tier::Standard, 7).with_layer(2));
+        assert_eq!(routed.requested_k, 4);
+        assert_eq!(router.violation_count(), 0);
+    }
+
+    #[test]
+    fn test_include_pin_keeps_inner_metadata() {
+        let policy = PinningPolicy::new()
+            .with_rule(PinRule::new(vec![generalist()], PinMode::Include).for_positions(0..4));
+        let mut inner = GatingRouter::new_unchecked(1.0);
+        inner.set_min_gate_prob(0.2, false);
+        let router = PinnedRouter::new(inner, policy);
+        let weights: HashMap<ExpertId, f32> =
+            (0..8).map(|i| (index_expert_id(i), i as f32)).collect();
+        let ctx = RoutingContext::new(Tier::Standard, 1);
+        let inner = router.inner.route_outcome_with_weights(&ctx, &weights);
+        let pinned = router.route_outcome_with_weights(&ctx, &weights);
+        assert!(!inner.dropped.is_empty());
+        assert_eq!(pinned.dropped, inner.dropped);
+        assert_eq!(pinned.requested_k, inner.requested_k);
+        assert_eq!(pinned.decision.expert_ids[0], generalist());
+    }
+}
@@ -0,0 +1,770 @@
+// File: policy.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Routing policy language for AURIA Runtime Core.
+//     A policy is a list of rules, parsed at load time and interpreted per
+//     decision, so routing changes ship as configuration instead of a
+//     release:
+//
+//         # shared generalist for the first layers
+//         if layer < 2 -> pin(shared);
+//         if tier == nano and priority == batch -> topk(hash, k=1);
+//         else topk(gate, k=tier.k, exclude=maintenance)
+//
+//     Conditions compare layer, position, token or beam with integers, and
+//     tier, priority or profile with names; they combine with and, or, not
+//     and parentheses. pin(set) activates a named expert set; topk(router,
+//     k=N|tier.k, exclude=set) takes the best k of a named router. Names
+//     are resolved against a PolicyEnv when the policy is compiled.
+//
+use crate::{
+    decision_entries, decision_from_entries, DropReason, PolicyError, PriorityClass, Router,
+    RoutingContext, RoutingOutcome,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Tok {
+    Ident(String),
+    Int(u64),
+    Str(String),
+    Sym(&'static str),
+}
+
+const SYMBOLS: [&str; 13] = [
+    "<=", ">=", "==", "!=", "->", "<", ">", "(", ")", ",", "=", ";", ".",
+];
+
+fn lex(src: &str) -> Result<Vec<(usize, Tok)>, PolicyError> {
+    let mut toks = Vec::new();
+    let mut rest = src;
+    while let Some(c) = rest.chars().next() {
+        let offset = src.len() - rest.len();
+        if c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+        } else if c == '#' {
+            rest = rest.find('\n').map_or("", |i| &rest[i..]);
+        } else if c.is_ascii_digit() {
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let value = rest[..end].parse().map_err(|_| PolicyError::Parse {
+                offset,
+                message: "integer out of range".to_string(),
+            })?;
+            toks.push((offset, Tok::Int(value)));
+            rest = &rest[end..];
+        } else if c.is_alphabetic() || c == '_' {
+            let end = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+                .unwrap_or(rest.len());
+            toks.push((offset, Tok::Ident(rest[..end].to_string())));
+            rest = &rest[end..];
+        } else if c == '"' {
+            let end = rest[1..].find('"').ok_or_else(|| PolicyError::Parse {
+                offset,
+                message: "unterminated string".to_string(),
+            })?;
+            toks.push((offset, Tok::Str(rest[1..=end].to_string())));
+            rest = &rest[end + 2..];
+        } else {
+            let sym = SYMBOLS
+                .iter()
+                .find(|s| rest.starts_with(*s))
+                .ok_or_else(|| PolicyError::Parse {
+                    offset,
+                    message: format!("unexpected character {:?}", c),
+                })?;
+            toks.push((offset, Tok::Sym(sym)));
+            rest = &rest[sym.len()..];
+        }
+    }
+    Ok(toks)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Field {
+    Layer,
+    Position,
+    Token,
+    Beam,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Op {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl Op {
+    fn test<T: PartialOrd>(self, left: T, right: T) -> bool {
+        match self {
+            Op::Lt => left < right,
+            Op::Le => left <= right,
+            Op::Gt => left > right,
+            Op::Ge => left >= right,
+            Op::Eq => left == right,
+            Op::Ne => left != right,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Cond {
+    Cmp(Field, Op, u64),
+    Tier(Op, Tier),
+    Priority(Op, PriorityClass),
+    Profile(Op, String),
+    Not(Box<Cond>),
+    And(Box<Cond>, Box<Cond>),
+    Or(Box<Cond>, Box<Cond>),
+}
+
+impl Cond {
+    fn eval(&self, ctx: &RoutingContext) -> bool {
+        match self {
+            Cond::Cmp(field, op, value) => {
+                let actual = match field {
+                    Field::Layer => ctx.layer as u64,
+                    Field::Position => ctx.sequence_position,
+                    Field::Token => ctx.token_index,
+                    Field::Beam => ctx.beam as u64,
+                };
+                op.test(actual, *value)
+            }
+            Cond::Tier(op, tier) => op.test(tier_rank(ctx.tier), tier_rank(*tier)),
+            Cond::Priority(op, priority) => op.test(ctx.priority == *priority, true),
+            Cond::Profile(op, profile) => op.test(ctx.profile.as_deref() == Some(profile), true),
+            Cond::Not(inner) => !inner.eval(ctx),
+            Cond::And(a, b) => a.eval(ctx) && b.eval(ctx),
+            Cond::Or(a, b) => a.eval(ctx) || b.eval(ctx),
+        }
+    }
+}
+
+fn tier_rank(tier: Tier) -> u8 {
+    match tier {
+        Tier::Nano => 0,
+        Tier::Standard => 1,
+        Tier::Pro => 2,
+        Tier::Max => 3,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum KArg {
+    Tier,
+    Fixed(u32),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Action {
+    Pin(String),
+    TopK {
+        router: String,
+        k: KArg,
+        exclude: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Rule {
+    cond: Option<Cond>,
+    action: Action,
+}
+
+struct Parser {
+    toks: Vec<(usize, Tok)>,
+    pos: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Tok> {
+        self.toks.get(self.pos).map(|(_, t)| t)
+    }
+
+    fn offset(&self) -> usize {
+        self.toks.get(self.pos).map_or(self.end, |(o, _)| *o)
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, PolicyError> {
+        Err(PolicyError::Parse {
+            offset: self.offset(),
+            message: message.into(),
+        })
+    }
+
+    fn next(&mut self) -> Option<Tok> {
+        let tok = self.toks.get(self.pos).map(|(_, t)| t.clone());
+        self.pos += 1;
+        tok
+    }
+
+    fn eat(&mut self, sym: &str) -> bool {
+        if self.peek() == Some(&Tok::Sym(SYMBOLS.iter().find(|s| **s == sym).unwrap())) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_word(&mut self, word: &str) -> bool {
+        if matches!(self.peek(), Some(Tok::Ident(w)) if w == word) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, sym: &str) -> Result<(), PolicyError> {
+        if self.eat(sym) {
+            Ok(())
+        } else {
+            self.error(format!("expected `{}`", sym))
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, PolicyError> {
+        match self.peek() {
+            Some(Tok::Ident(name)) => {
+                let name = name.clone();
+                self.pos += 1;
+                Ok(name)
+            }
+            _ => self.error("expected a name"),
+        }
+    }
+
+    fn rules(&mut self) -> Result<Vec<Rule>, PolicyError> {
+        let mut rules: Vec<Rule> = Vec::new();
+        while self.peek().is_some() {
+            if rules.last().is_some_and(|r| r.cond.is_none()) {
+                return self.error("rules after else are unreachable");
+            }
+            let cond = if self.eat_word("if") {
+                let cond = self.or()?;
+                self.expect("->")?;
+                Some(cond)
+            } else if self.eat_word("else") {
+                None
+            } else {
+                return self.error("expected `if` or `else`");
+            };
+            rules.push(Rule {
+                cond,
+                action: self.action()?,
+            });
+            if !self.eat(";") && self.peek().is_some() {
+                return self.error("expected `;`");
+            }
+        }
+        Ok(rules)
+    }
+
+    fn or(&mut self) -> Result<Cond, PolicyError> {
+        let mut cond = self.and()?;
+        while self.eat_word("or") {
+            cond = Cond::Or(Box::new(cond), Box::new(self.and()?));
+        }
+        Ok(cond)
+    }
+
+    fn and(&mut self) -> Result<Cond, PolicyError> {
+        let mut cond = self.atom()?;
+        while self.eat_word("and") {
+            cond = Cond::And(Box::new(cond), Box::new(self.atom()?));
+        }
+        Ok(cond)
+    }
+
+    fn atom(&mut self) -> Result<Cond, PolicyError> {
+        if self.eat("(") {
+            let cond = self.or()?;
+            self.expect(")")?;
+            return Ok(cond);
+        }
+        if self.eat_word("not") {
+            return Ok(Cond::Not(Box::new(self.atom()?)));
+        }
+        let field = self.ident()?;
+        let op = match self.next() {
+            Some(Tok::Sym("<")) => Op::Lt,
+            Some(Tok::Sym("<=")) => Op::Le,
+            Some(Tok::Sym(">")) => Op::Gt,
+            Some(Tok::Sym(">=")) => Op::Ge,
+            Some(Tok::Sym("==")) => Op::Eq,
+            Some(Tok::Sym("!=")) => Op::Ne,
+            _ => {
+                self.pos -= 1;
+                return self.error("expected a comparison");
+            }
+        };
+        let numeric = match field.as_str() {
+            "layer" => Some(Field::Layer),
+            "position" => Some(Field::Position),
+            "token" => Some(Field::Token),
+            "beam" => Some(Field::Beam),
+            _ => None,
+        };
+        if let Some(field) = numeric {
+            return match self.next() {
+                Some(Tok::Int(value)) => Ok(Cond::Cmp(field, op, value)),
+                _ => {
+                    self.pos -= 1;
+                    self.error("expected an integer")
+                }
+            };
+        }
+        let equality_only = |p: &Self| {
+            if matches!(op, Op::Eq | Op::Ne) {
+                Ok(())
+            } else {
+                p.error(format!("{} only supports == and !=", field))
+            }
+        };
+        match field.as_str() {
+            "tier" => {
+                let tier = match self.ident()?.as_str() {
+                    "nano" => Tier::Nano,
+                    "standard" => Tier::Standard,
+                    "pro" => Tier::Pro,
+                    "max" => Tier::Max,
+                    _ => {
+                        self.pos -= 1;
+                        return self.error("expected nano, standard, pro or max");
+                    }
+                };
+                Ok(Cond::Tier(op, tier))
+            }
+            "priority" => {
+                equality_only(self)?;
+                let priority = match self.ident()?.as_str() {
+                    "interactive" => PriorityClass::Interactive,
+                    "standard" => PriorityClass::Standard,
+                    "batch" => PriorityClass::Batch,
+                    _ => {
+                        self.pos -= 1;
+                        return self.error("expected interactive, standard or batch");
+                    }
+                };
+                Ok(Cond::Priority(op, priority))
+            }
+            "profile" => {
+                equality_only(self)?;
+                match self.next() {
+                    Some(Tok::Str(s)) | Some(Tok::Ident(s)) => Ok(Cond::Profile(op, s)),
+                    _ => {
+                        self.pos -= 1;
+                        self.error("expected a profile name")
+                    }
+                }
+            }
+            _ => {
+                self.pos -= 2;
+                self.error(format!("unknown field {}", field))
+            }
+        }
+    }
+
+    fn action(&mut self) -> Result<Action, PolicyError> {
+        let start = self.pos;
+        let name = self.ident()?;
+        self.expect("(")?;
+        let mut positional = Vec::new();
+        let mut named: Vec<(String, Tok)> = Vec::new();
+        while !self.eat(")") {
+            if !positional.is_empty() || !named.is_empty() {
+                self.expect(",")?;
+            }
+            let arg = self.ident()?;
+            if self.eat("=") {
+                let value = match self.next() {
+                    Some(Tok::Int(v)) => Tok::Int(v),
+                    Some(Tok::Ident(v)) if v == "tier" => {
+                        self.expect(".")?;
+                        if !self.eat_word("k") {
+                            return self.error("expected `tier.k`");
+                        }
+                        Tok::Sym("tier.k")
+                    }
+                    Some(Tok::Ident(v)) => Tok::Ident(v),
+                    _ => {
+                        self.pos -= 1;
+                        return self.error("expected a value");
+                    }
+                };
+                named.push((arg, value));
+            } else {
+                positional.push(arg);
+            }
+        }
+        let bad = |p: &mut Self, message: &str| {
+            p.pos = start;
+            p.error(message.to_string())
+        };
+        match name.as_str() {
+            "pin" => match (positional.as_slice(), named.is_empty()) {
+                ([set], true) => Ok(Action::Pin(set.clone())),
+                _ => bad(self, "pin takes one expert set"),
+            },
+            "topk" => {
+                let [router] = positional.as_slice() else {
+                    return bad(self, "topk takes one router");
+                };
+                let mut k = KArg::Tier;
+                let mut exclude = None;
+                for (key, value) in named {
+                    match (key.as_str(), value) {
+                        ("k", Tok::Int(n)) => k = KArg::Fixed(n.min(u32::MAX as u64) as u32),
+                        ("k", Tok::Sym("tier.k")) => k = KArg::Tier,
+                        ("exclude", Tok::Ident(set)) => exclude = Some(set),
+                        _ => return bad(self, "topk accepts k=N|tier.k and exclude=set"),
+                    }
+                }
+                Ok(Action::TopK {
+                    router: router.clone(),
+                    k,
+                    exclude,
+                })
+            }
+            _ => bad(self, "unknown action; expected pin or topk"),
+        }
+    }
+}
+
+// A parsed policy, not yet bound to routers and expert sets.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Policy {
+    rules: Vec<Rule>,
+}
+
+impl Policy {
+    pub fn parse(src: &str) -> Result<Self, PolicyError> {
+        let mut parser = Parser {
+            toks: lex(src)?,
+            pos: 0,
+            end: src.len(),
+        };
+        Ok(Self {
+            rules: parser.rules()?,
+        })
+    }
+
+    pub fn rule_count(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn compile(self, env: PolicyEnv) -> Result<PolicyRouter, PolicyError> {
+        if self.rules.last().is_none_or(|r| r.cond.is_some()) {
+            return Err(PolicyError::MissingDefault);
+        }
+        let PolicyEnv {
+            routers: available,
+            sets,
+        } = env;
+        let mut available: HashMap<String, Box<dyn Router>> = available.into_iter().collect();
+        let mut routers: Vec<Box<dyn Router>> = Vec::new();
+        let mut router_names: Vec<String> = Vec::new();
+        let set = |name: &String| {
+            sets.get(name)
+                .map(|ids| (name.clone(), ids.clone()))
+                .ok_or_else(|| PolicyError::UnknownExpertSet(name.clone()))
+        };
+        let mut rules = Vec::with_capacity(self.rules.len());
+        for rule in self.rules {
+            let action = match rule.action {
+                Action::Pin(name) => Compiled::Pin(set(&name)?.1),
+                Action::TopK { router, k, exclude } => {
+                    let index = match router_names.iter().position(|n| *n == router) {
+                        Some(index) => index,
+                        None => {
+                            let r = available
+                                .remove(&router)
+                                .ok_or_else(|| PolicyError::UnknownRouter(router.clone()))?;
+                            routers.push(r);
+                            router_names.push(router);
+                            routers.len() - 1
+                        }
+                    };
+                    Compiled::TopK {
+                        router: index,
+                        k,
+                        exclude: exclude.as_ref().map(set).transpose()?,
+                    }
+                }
+            };
+            rules.push((rule.cond, action));
+        }
+        Ok(PolicyRouter { rules, routers })
+    }
+}
+
+#[derive(Default)]
+pub struct PolicyEnv {
+    routers: Vec<(String, Box<dyn Router>)>,
+    sets: HashMap<String, Vec<ExpertId>>,
+}
+
+impl PolicyEnv {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_router(mut self, name: impl Into<String>, router: impl Router + 'static) -> Self {
+        self.routers.push((name.into(), Box::new(router)));
+        self
+    }
+
+    pub fn with_expert_set(mut self, name: impl Into<String>, experts: Vec<ExpertId>) -> Self {
+        self.sets.insert(name.into(), experts);
+        self
+    }
+}
+
+enum Compiled {
+    Pin(Vec<ExpertId>),
+    TopK {
+        router: usize,
+        k: KArg,
+        exclude: Option<(String, Vec<ExpertId>)>,
+    },
+}
+
+pub struct PolicyRouter {
+    rules: Vec<(Option<Cond>, Compiled)>,
+    routers: Vec<Box<dyn Router>>,
+}
+
+impl PolicyRouter {
+    pub fn compile(src: &str, env: PolicyEnv) -> Result<Self, PolicyError> {
+        Policy::parse(src)?.compile(env)
+    }
+
+    // Compilation guarantees a trailing else, so some rule always matches.
+    fn action(&self, ctx: &RoutingContext) -> &Compiled {
+        &self
+            .rules
+            .iter()
+            .find(|(cond, _)| cond.as_ref().is_none_or(|c| c.eval(ctx)))
+            .expect("policy ends with an else rule")
+            .1
+    }
+
+    fn pinned(experts: &[ExpertId]) -> RoutingOutcome {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let entries = experts.iter().map(|id| (id.clone(), 1.0, 1.0)).collect();
+        RoutingOutcome::new(
+            decision_from_entries(entries, timestamp),
+            experts.len() as u32,
+        )
+    }
+
+    fn top_k(
+        &self,
+        ctx: &RoutingContext,
+        router: &dyn Router,
+        k: KArg,
+        exclude: &Option<(String, Vec<ExpertId>)>,
+    ) -> RoutingOutcome {
+        let outcome = router.route_outcome(ctx);
+        let k = match k {
+            KArg::Tier if exclude.is_none() => return outcome,
+            KArg::Tier => outcome.decision.expert_ids.len(),
+            KArg::Fixed(k) => k as usize,
+        };
+        let excluded = exclude.as_ref().map_or(&[][..], |(_, ids)| ids.as_slice());
+        let timestamp = outcome.decision.timestamp;
+        let mut candidates = decision_entries(outcome.decision.clone());
+        if candidates.len() < k + excluded.len() {
+            let mut wide = ctx.clone();
+            wide.tier = Tier::Max;
+            for entry in decision_entries(router.route_with_context(&wide)) {
+                if !candidates.iter().any(|(c, _, _)| *c == entry.0) {
+                    candidates.push(entry);
+                }
+            }
+        }
+        let kept: Vec<_> = candidates
+            .into_iter()
+            .filter(|(id, _, _)| !excluded.contains(id))
+            .take(k)
+            .collect();
+        let mut result = RoutingOutcome::new(decision_from_entries(kept, timestamp), k as u32);
+        result.shared_count = outcome.shared_count;
+        if let Some((mask, _)) = exclude {
+            for id in outcome.decision.expert_ids {
+                if excluded.contains(&id) {
+                    result.record_drop(id, DropReason::Masked { mask: mask.clone() });
+                }
+            }
+        }
+        result
+    }
+}
+
+impl Router for PolicyRouter {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.route_with_context(&RoutingContext::new(tier, token_index))
+    }
+
+    // Only tier and token are known here: rules are matched against
+    // `RoutingContext::new(tier, token_index)`, i.e. layer 0, position
+    // `token_index`, standard priority and no profile. Callers with a full
+    // context use route_outcome_with_weights.
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        self.route_outcome_with_weights(&RoutingContext::new(tier, token_index), weights)
+            .decision
+    }
+
+    // Excluded experts are removed from `weights` before the named router
+    // sees them.
+    fn route_outcome_with_weights(
+        &self,
+        ctx: &RoutingContext,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingOutcome {
+        match self.action(ctx) {
+            Compiled::Pin(experts) => Self::pinned(experts),
+            Compiled::TopK { router, k, exclude } => {
+                let excluded = exclude.as_ref().map_or(&[][..], |(_, ids)| ids.as_slice());
+                let allowed: HashMap<ExpertId, f32> = weights
+                    .iter()
+                    .filter(|(id, _)| !excluded.contains(id))
+                    .map(|(id, w)| (id.clone(), *w))
+                    .collect();
+                let mut outcome = self.routers[*router].route_outcome_with_weights(ctx, &allowed);
+                if let KArg::Fixed(k) = k {
+                    let timestamp = outcome.decision.timestamp;
+                    let entries = decision_entries(outcome.decision)
+                        .into_iter()
+                        .take(*k as usize)
+                        .collect();
+                    outcome.decision = decision_from_entries(entries, timestamp);
+                    outcome.requested_k = *k;
+                }
+                outcome
+            }
+        }
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.route_outcome(ctx).decision
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        match self.action(ctx) {
+            Compiled::Pin(experts) => Self::pinned(experts),
+            Compiled::TopK { router, k, exclude } => {
+                self.top_k(ctx, self.routers[*router].as_ref(), *k, exclude)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter, HashRouter};
+
+    const POLICY: &str = "
+        # shared generalist for the first layers
+        if layer < 2 -> pin(shared);
+        if tier == nano and priority == batch -> topk(hash, k=1);
+        else topk(gate, k=tier.k, exclude=maintenance)
+    ";
+
+    fn env() -> PolicyEnv {
+        PolicyEnv::new()
+            .with_router("gate", DeterministicRouter::new_unchecked(64))
+            .with_router("hash", HashRouter::new_unchecked(64, 1))
+            .with_expert_set("shared", vec![ExpertId([0xEE; 32])])
+            .with_expert_set("maintenance", vec![index_expert_id(0)])
+    }
+
+    #[test]
+    fn test_policy_routes_by_rule() {
+        let router = PolicyRouter::compile(POLICY, env()).unwrap();
+
+        let early = router.route_with_context(&RoutingContext::new(Tier::Pro, 0));
+        assert_eq!(early.expert_ids, vec![ExpertId([0xEE; 32])]);
+
+        let ctx = RoutingContext::new(Tier::Nano, 0).with_layer(4);
+        let outcome = router.route_outcome(&ctx);
+        assert_eq!(
+            outcome.decision.expert_ids,
+            vec![index_expert_id(1), index_expert_id(2)]
+        );
+        assert_eq!(outcome.masks_applied, vec!["maintenance".to_string()]);
+
+        let batch = router.route_with_context(&ctx.with_priority(PriorityClass::Batch));
+        assert_eq!(batch.expert_ids.len(), 1);
+    }
+
+    #[test]
+    fn test_policy_with_weights() {
+        let router = PolicyRouter::compile(POLICY, env()).unwrap();
+        let weights: HashMap<ExpertId, f32> =
+            (0..8).map(|i| (index_expert_id(i), i as f32)).collect();
+
+        let ctx = RoutingContext::new(Tier::Nano, 0).with_layer(4);
+        let outcome = router.route_outcome_with_weights(&ctx, &weights);
+        assert_eq!(
+            outcome.decision.expert_ids,
+            vec![index_expert_id(7), index_expert_id(6)]
+        );
+
+        // Without a context, layer conditions see layer 0.
+        let decision = router.route_with_weights(Tier::Nano, 0, &weights);
+        assert_eq!(decision.expert_ids, vec![ExpertId([0xEE; 32])]);
+
+        let batch = ctx.with_priority(PriorityClass::Batch);
+        let outcome = router.route_outcome_with_weights(&batch, &weights);
+        assert_eq!(outcome.decision.expert_ids.len(), 1);
+        assert_ne!(outcome.decision.timestamp, 0);
+    }
+
+    #[test]
+    fn test_policy_errors() {
+        let err = Policy::parse("if layer < -> pin(shared); else topk(gate)").unwrap_err();
+        assert!(
+            matches!(err, PolicyError::Parse { offset: 11, .. }),
+            "{:?}",
+            err
+        );
+        assert_eq!(
+            PolicyRouter::compile("if layer < 2 -> pin(shared)", env())
+                .err()
+                .unwrap(),
+            PolicyError::MissingDefault
+        );
+        assert_eq!(
+            PolicyRouter::compile("else topk(gating)", env())
+                .err()
+                .unwrap(),
+            PolicyError::UnknownRouter("gating".to_string())
+        );
+        assert_eq!(
+            PolicyRouter::compile("else topk(gate, exclude=drained)", env())
+                .err()
+                .unwrap(),
+            PolicyError::UnknownExpertSet("drained".to_string())
+        );
+        assert!(Policy::parse("else pin(shared); if layer < 1 -> pin(shared)").is_err());
+    }
+}
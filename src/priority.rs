@@ -0,0 +1,218 @@
+// File: priority.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Priority-class routing for AURIA Runtime Core.
+//     Interactive and standard traffic keeps the inner router's full top-k,
+//     while batch traffic is routed with a smaller k and steered toward the
+//     experts with the lowest recent load, so background jobs stop
+//     competing for the popular experts.
+//
+use crate::{
+    decision_entries, decision_from_entries, DropReason, LoadWindow, Router, RouterState,
+    RoutingContext, RoutingExplanation, RoutingOutcome, StatefulRouter,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PriorityClass {
+    Interactive,
+    #[default]
+    Standard,
+    Batch,
+}
+
+pub struct PriorityRouter<R: Router> {
+    inner: R,
+    batch_k_fraction: f32,
+    search_width: usize,
+    load: Arc<LoadWindow>,
+    // False when observing a shared window fed by a MeteredRouter.
+    records_load: bool,
+}
+
+impl<R: Router> PriorityRouter<R> {
+    // `window` is the number of recent decisions load is measured over.
+    pub fn new(inner: R, window: usize) -> Self {
+        Self {
+            inner,
+            batch_k_fraction: 0.5,
+            search_width: 16,
+            load: Arc::new(LoadWindow::tokens(window)),
+            records_load: true,
+        }
+    }
+
+    // Batch traffic gets `ceil(k * fraction)` experts, at least one.
+    pub fn with_batch_k_fraction(mut self, fraction: f32) -> Self {
+        self.batch_k_fraction = if fraction.is_nan() {
+            1.0
+        } else {
+            fraction.clamp(0.0, 1.0)
+        };
+        self
+    }
+
+    // How many of the inner router's best candidates batch traffic may be
+    // steered among.
+    pub fn with_search_width(mut self, search_width: usize) -> Self {
+        self.search_width = search_width.max(1);
+        self
+    }
+
+    // Steers by a window shared with other layers instead of its own. The
+    // router stops recording; a MeteredRouter must feed the window.
+    pub fn with_load_window(mut self, window: Arc<LoadWindow>) -> Self {
+        self.load = window;
+        self.records_load = false;
+        self
+    }
+
+    // Share of recent assignments (all classes) that went to `expert_id`.
+    pub fn load(&self, expert_id: &ExpertId) -> f32 {
+        self.load.share(expert_id)
+    }
+
+    fn batch_k(&self, k: usize) -> usize {
+        ((k as f32 * self.batch_k_fraction).ceil() as usize).clamp(1, k.max(1))
+    }
+
+    fn record(&self, decision: &RoutingDecision) {
+        if self.records_load {
+            self.load.record(&decision.expert_ids);
+        }
+    }
+
+    fn steer(&self, ctx: &RoutingContext, mut outcome: RoutingOutcome) -> RoutingOutcome {
+        if ctx.priority != PriorityClass::Batch || outcome.decision.expert_ids.is_empty() {
+            self.record(&outcome.decision);
+            return outcome;
+        }
+        let shared = (outcome.shared_count as usize).min(outcome.decision.expert_ids.len());
+        let routed = outcome.decision.expert_ids.len() - shared;
+        let k = self.batch_k(routed);
+
+        let mut wide = ctx.clone();
+        wide.tier = Tier::Max;
+        let timestamp = outcome.decision.timestamp;
+        let mut entries = decision_entries(outcome.decision);
+        let pinned: Vec<_> = entries.drain(..shared).collect();
+        let primary: Vec<ExpertId> = entries.iter().map(|(id, _, _)| id.clone()).collect();
+        let mut candidates: Vec<(ExpertId, f32, f32)> = Vec::with_capacity(self.search_width);
+        for entry in entries
+            .into_iter()
+            .chain(decision_entries(self.inner.route_with_context(&wide)))
+        {
+            if candidates.len() == self.search_width.max(routed) {
+                break;
+            }
+            let taken = pinned
+                .iter()
+                .chain(&candidates)
+                .any(|(id, _, _)| *id == entry.0);
+            if !taken {
+                candidates.push(entry);
+            }
+        }
+        {
+            // Stable sort: ties keep the inner router's preference order.
+            let load = self.load.lock();
+            candidates.sort_by(|a, b| load.share(&a.0).total_cmp(&load.share(&b.0)));
+        }
+        candidates.truncate(k);
+
+        let kept: Vec<ExpertId> = candidates.iter().map(|(id, _, _)| id.clone()).collect();
+        outcome.decision =
+            decision_from_entries(pinned.into_iter().chain(candidates).collect(), timestamp);
+        outcome.requested_k = (shared + k) as u32;
+        for id in primary.into_iter().filter(|id| !kept.contains(id)) {
+            outcome.record_drop(id, DropReason::Deprioritized);
+        }
+        self.record(&outcome.decision);
+        outcome
+    }
+}
+
+impl<R: Router> Router for PriorityRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.route_with_context(&RoutingContext::new(tier, token_index))
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        let decision = self.inner.route_with_weights(tier, token_index, weights);
+        self.record(&decision);
+        decision
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.route_outcome(ctx).decision
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        self.steer(ctx, self.inner.route_outcome(ctx))
+    }
+
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        let explanation = self.inner.explain(ctx);
+        let outcome = self.steer(ctx, explanation.outcome.clone());
+        explanation.layer("priority", outcome)
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for PriorityRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.inner.begin_sequence(seq_id)
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.inner.end_sequence(seq_id)
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.inner.active_sequences()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter};
+
+    #[test]
+    fn test_batch_avoids_hot_experts_with_smaller_k() {
+        let router = PriorityRouter::new(DeterministicRouter::new_unchecked(64), 32);
+        let interactive =
+            RoutingContext::new(Tier::Standard, 0).with_priority(PriorityClass::Interactive);
+        for _ in 0..4 {
+            let decision = router.route_with_context(&interactive);
+            assert_eq!(decision.expert_ids.len(), 4);
+        }
+        assert!(router.load(&index_expert_id(0)) > 0.0);
+
+        let batch = RoutingContext::new(Tier::Standard, 0).with_priority(PriorityClass::Batch);
+        let outcome = router.route_outcome(&batch);
+        assert_eq!(outcome.requested_k, 2);
+        assert_eq!(
+            outcome.decision.expert_ids,
+            vec![index_expert_id(4), index_expert_id(5)]
+        );
+        assert_eq!(outcome.dropped[0].reason, DropReason::Deprioritized);
+
+        let standard = router.route(Tier::Standard, 0);
+        assert_eq!(standard.expert_ids[0], index_expert_id(0));
+    }
+}
@@ -0,0 +1,307 @@
+// File: profiles.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Named routing profiles for AURIA Runtime Core.
+//     A ProfileRouter serves several gate configurations (e.g. "code",
+//     "multilingual", "vision") from one process. Each profile is a full
+//     router with its own gate weights and tier policy, selected per request
+//     through RoutingContext::profile; the profile table is swapped
+//     atomically so readers never see a half-updated set.
+//
+use crate::{
+    GatingRouter, Router, RouterState, RoutingContext, RoutingExplanation, RoutingOutcome,
+    StatefulRouter,
+};
+use arc_swap::ArcSwap;
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+struct ProfileTable<R> {
+    profiles: HashMap<String, Arc<R>>,
+    default: String,
+}
+
+pub struct ProfileRouter<R: Router = GatingRouter> {
+    table: ArcSwap<ProfileTable<R>>,
+}
+
+impl<R: Router> ProfileRouter<R> {
+    pub fn new(default: impl Into<String>, router: R) -> Self {
+        let default = default.into();
+        let profiles = HashMap::from([(default.clone(), Arc::new(router))]);
+        Self {
+            table: ArcSwap::from_pointee(ProfileTable { profiles, default }),
+        }
+    }
+
+    pub fn set_profile(&self, name: impl Into<String>, router: R) {
+        let name = name.into();
+        let router = Arc::new(router);
+        self.table.rcu(|table| ProfileTable {
+            profiles: {
+                let mut profiles = table.profiles.clone();
+                profiles.insert(name.clone(), router.clone());
+                profiles
+            },
+            default: table.default.clone(),
+        });
+    }
+
+    // The default profile cannot be removed.
+    pub fn remove_profile(&self, name: &str) -> bool {
+        let mut removed = false;
+        self.table.rcu(|table| {
+            let mut profiles = table.profiles.clone();
+            removed = name != table.default && profiles.remove(name).is_some();
+            ProfileTable {
+                profiles,
+                default: table.default.clone(),
+            }
+        });
+        removed
+    }
+
+    pub fn set_default_profile(&self, name: &str) -> bool {
+        let mut found = false;
+        self.table.rcu(|table| {
+            found = table.profiles.contains_key(name);
+            ProfileTable {
+                profiles: table.profiles.clone(),
+                default: if found {
+                    name.to_string()
+                } else {
+                    table.default.clone()
+                },
+            }
+        });
+        found
+    }
+
+    // Replaces every profile and the default in one atomic switch.
+    pub fn replace_profiles(
+        &self,
+        default: impl Into<String>,
+        profiles: HashMap<String, R>,
+    ) -> bool {
+        let default = default.into();
+        if !profiles.contains_key(&default) {
+            return false;
+        }
+        let profiles = profiles
+            .into_iter()
+            .map(|(name, router)| (name, Arc::new(router)))
+            .collect();
+        self.table
+            .store(Arc::new(ProfileTable { profiles, default }));
+        true
+    }
+
+    pub fn default_profile(&self) -> String {
+        self.table.load().default.clone()
+    }
+
+    pub fn profile_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.table.load().profiles.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    // Unknown or absent profile names fall back to the default profile.
+    pub fn profile(&self, name: Option<&str>) -> Arc<R> {
+        let table = self.table.load();
+        name.and_then(|n| table.profiles.get(n))
+            .unwrap_or_else(|| &table.profiles[&table.default])
+            .clone()
+    }
+}
+
+impl<R: Router> Router for ProfileRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.profile(None).route(tier, token_index)
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        self.profile(None)
+            .route_with_weights(tier, token_index, weights)
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.profile(ctx.profile.as_deref()).route_with_context(ctx)
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        self.profile(ctx.profile.as_deref()).route_outcome(ctx)
+    }
+
+    fn route_outcome_with_weights(
+        &self,
+        ctx: &RoutingContext,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingOutcome {
+        self.profile(ctx.profile.as_deref())
+            .route_outcome_with_weights(ctx, weights)
+    }
+
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        self.profile(ctx.profile.as_deref()).explain(ctx)
+    }
+
+    // Every profile's state is keyed by name. Profiles missing from the
+    // table on restore are skipped; a state without `profiles` (taken before
+    // profiles were snapshotted individually) restores the default profile.
+    fn snapshot(&self) -> RouterState {
+        let table = self.table.load();
+        let profiles = table
+            .profiles
+            .iter()
+            .map(|(name, router)| (name.clone(), router.snapshot()))
+            .filter(|(_, state)| !state.is_empty())
+            .collect();
+        RouterState {
+            profiles,
+            ..RouterState::default()
+        }
+    }
+
+    fn restore(&self, state: &RouterState) {
+        if state.profiles.is_empty() {
+            return self.profile(None).restore(state);
+        }
+        let table = self.table.load();
+        for (name, profile_state) in &state.profiles {
+            if let Some(router) = table.profiles.get(name) {
+                router.restore(profile_state);
+            }
+        }
+    }
+}
+
+// The profile is chosen per request, so every profile sees every sequence.
+impl<R: StatefulRouter> StatefulRouter for ProfileRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        for router in self.table.load().profiles.values() {
+            router.begin_sequence(seq_id);
+        }
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        for router in self.table.load().profiles.values() {
+            router.end_sequence(seq_id);
+        }
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.table
+            .load()
+            .profiles
+            .values()
+            .map(|router| router.active_sequences())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, RoundRobinRouter, StickyRouter, TierKTable};
+
+    fn gating(favourite: u32) -> GatingRouter {
+        let mut router = GatingRouter::new_unchecked(1.0);
+        for i in 0..16 {
+            router.set_gate_weight(index_expert_id(i), if i == favourite { 5.0 } else { 0.0 });
+        }
+        router
+    }
+
+    #[test]
+    fn test_context_selects_profile() {
+        let router = ProfileRouter::new("general", gating(0));
+        router.set_profile(
+            "code",
+            gating(7).with_k_selector(TierKTable {
+                nano: 1,
+                standard: 1,
+                pro: 1,
+                max: 1,
+            }),
+        );
+
+        let ctx = RoutingContext::new(Tier::Standard, 0);
+        assert_eq!(
+            router.route_with_context(&ctx).expert_ids[0],
+            index_expert_id(0)
+        );
+        assert_eq!(router.route_with_context(&ctx).expert_ids.len(), 4);
+
+        let code = router.route_with_context(&ctx.clone().with_profile("code"));
+        assert_eq!(code.expert_ids, vec![index_expert_id(7)]);
+
+        let unknown = router.route_with_context(&ctx.with_profile("vision"));
+        assert_eq!(unknown.expert_ids[0], index_expert_id(0));
+    }
+
+    #[test]
+    fn test_atomic_profile_switching() {
+        let router = ProfileRouter::new("general", gating(0));
+        router.set_profile("code", gating(3));
+        assert!(router.set_default_profile("code"));
+        assert!(!router.set_default_profile("missing"));
+        assert_eq!(
+            router.route(Tier::Nano, 0).expert_ids[0],
+            index_expert_id(3)
+        );
+        assert!(!router.remove_profile("code"));
+        assert!(router.remove_profile("general"));
+
+        let held = router.profile(Some("code"));
+        assert!(router.replace_profiles("vision", HashMap::from([("vision".into(), gating(9))])));
+        assert_eq!(router.profile_names(), vec!["vision".to_string()]);
+        assert_eq!(
+            router.route(Tier::Nano, 0).expert_ids[0],
+            index_expert_id(9)
+        );
+        assert_eq!(held.route(Tier::Nano, 0).expert_ids[0], index_expert_id(3));
+    }
+
+    #[test]
+    fn test_snapshot_covers_every_profile() {
+        let experts: Vec<ExpertId> = (0..16).map(index_expert_id).collect();
+        let router =
+            ProfileRouter::new("general", RoundRobinRouter::new_unchecked(experts.clone()));
+        router.set_profile("code", RoundRobinRouter::new_unchecked(experts.clone()));
+        let code = RoutingContext::new(Tier::Nano, 0).with_profile("code");
+        router.route_with_context(&code);
+        router.route_with_context(&code);
+        let state = RouterState::from_json(&router.snapshot().to_json().unwrap()).unwrap();
+        assert_eq!(state.profiles["code"].round_robin_cursor, Some(2));
+        assert_eq!(state.profiles["general"].round_robin_cursor, Some(0));
+
+        let restored =
+            ProfileRouter::new("general", RoundRobinRouter::new_unchecked(experts.clone()));
+        restored.set_profile("code", RoundRobinRouter::new_unchecked(experts));
+        restored.restore(&state);
+        assert_eq!(
+            restored.route_with_context(&code).expert_ids,
+            router.route_with_context(&code).expert_ids
+        );
+    }
+
+    #[test]
+    fn test_sequences_reach_every_profile() {
+        let router = ProfileRouter::new("general", StickyRouter::new(gating(0)));
+        router.set_profile("code", StickyRouter::new(gating(7)));
+        router.begin_sequence(1);
+        router.begin_sequence(2);
+        assert_eq!(router.profile(Some("code")).active_sequences(), 2);
+        assert_eq!(router.active_sequences(), 2);
+        router.end_sequence(1);
+        assert_eq!(router.profile(None).active_sequences(), 1);
+    }
+}
@@ -0,0 +1,316 @@
+// File: rate_limit.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Per-expert rate limiting for AURIA Runtime Core.
+//     Applies token-bucket limits (rate and burst) to individual experts and
+//     redirects excess selections to the next-best unthrottled experts,
+//     keeping per-expert throttle counters for observability.
+//
+use crate::{
+    DropReason, Router, RouterState, RoutingContext, RoutingExplanation, RoutingOutcome,
+    StatefulRouter,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExpertRateLimit {
+    pub tokens_per_sec: f64,
+    pub burst: f64,
+}
+
+impl ExpertRateLimit {
+    pub fn new(tokens_per_sec: f64, burst: f64) -> Self {
+        Self {
+            tokens_per_sec: tokens_per_sec.max(0.0),
+            burst: burst.max(1.0),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct TokenBucket {
+    limit: ExpertRateLimit,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(limit: ExpertRateLimit, now: Instant) -> Self {
+        Self {
+            limit,
+            tokens: limit.burst,
+            last_refill: now,
+        }
+    }
+
+    fn try_take(&mut self, now: Instant) -> bool {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.limit.tokens_per_sec).min(self.limit.burst);
+        self.last_refill = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+pub struct RateLimitedRouter<R: Router> {
+    inner: R,
+    buckets: Mutex<HashMap<ExpertId, TokenBucket>>,
+    throttled: Mutex<HashMap<ExpertId, u64>>,
+    redirected: AtomicU64,
+    dropped: AtomicU64,
+}
+
+impl<R: Router> RateLimitedRouter<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            buckets: Mutex::new(HashMap::new()),
+            throttled: Mutex::new(HashMap::new()),
+            redirected: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    pub fn set_limit(&self, expert_id: ExpertId, limit: ExpertRateLimit) {
+        self.buckets
+            .lock()
+            .unwrap()
+            .insert(expert_id, TokenBucket::new(limit, Instant::now()));
+    }
+
+    pub fn clear_limit(&self, expert_id: &ExpertId) {
+        self.buckets.lock().unwrap().remove(expert_id);
+    }
+
+    pub fn throttle_counts(&self) -> HashMap<ExpertId, u64> {
+        self.throttled.lock().unwrap().clone()
+    }
+
+    pub fn redirected_count(&self) -> u64 {
+        self.redirected.load(Ordering::Relaxed)
+    }
+
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    fn admit(
+        &self,
+        buckets: &mut HashMap<ExpertId, TokenBucket>,
+        id: &ExpertId,
+        now: Instant,
+    ) -> bool {
+        match buckets.get_mut(id) {
+            Some(bucket) => bucket.try_take(now),
+            None => true,
+        }
+    }
+
+    // `candidates` yields (id, confidence, weight) in preference order and is
+    // only materialized when a primary selection is throttled.
+    fn enforce(
+        &self,
+        mut outcome: RoutingOutcome,
+        candidates: impl FnOnce() -> Vec<(ExpertId, f32, f32)>,
+    ) -> RoutingOutcome {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        if buckets.is_empty() {
+            return outcome;
+        }
+        let decision = &outcome.decision;
+
+        let mut admitted: Vec<(ExpertId, f32, f32)> = Vec::with_capacity(decision.expert_ids.len());
+        let mut throttled = Vec::new();
+        for (i, id) in decision.expert_ids.iter().enumerate() {
+            let confidence = decision.confidence_scores.get(i).copied().unwrap_or(1.0);
+            let weight = decision.gating_weights.get(i).copied().unwrap_or(1.0);
+            if self.admit(&mut buckets, id, now) {
+                admitted.push((id.clone(), confidence, weight));
+            } else {
+                throttled.push(id.clone());
+            }
+        }
+
+        if !throttled.is_empty() {
+            let mut counts = self.throttled.lock().unwrap();
+            for id in &throttled {
+                *counts.entry(id.clone()).or_insert(0) += 1;
+            }
+            drop(counts);
+
+            let mut needed = throttled.len();
+            for (id, confidence, weight) in candidates() {
+                if needed == 0 {
+                    break;
+                }
+                if throttled.contains(&id) || admitted.iter().any(|(a, _, _)| *a == id) {
+                    continue;
+                }
+                if self.admit(&mut buckets, &id, now) {
+                    admitted.push((id, confidence, weight));
+                    self.redirected.fetch_add(1, Ordering::Relaxed);
+                    outcome.fallback_used = true;
+                    needed -= 1;
+                }
+            }
+            self.dropped.fetch_add(needed as u64, Ordering::Relaxed);
+        }
+
+        outcome.decision = RoutingDecision {
+            expert_ids: admitted.iter().map(|(id, _, _)| id.clone()).collect(),
+            confidence_scores: admitted.iter().map(|(_, c, _)| *c).collect(),
+            gating_weights: admitted.iter().map(|(_, _, w)| *w).collect(),
+            timestamp: outcome.decision.timestamp,
+        };
+        for id in throttled {
+            outcome.record_drop(id, DropReason::RateLimited);
+        }
+        outcome
+    }
+}
+
+fn ranked_candidates(decision: RoutingDecision) -> Vec<(ExpertId, f32, f32)> {
+    decision
+        .expert_ids
+        .into_iter()
+        .enumerate()
+        .map(|(i, id)| {
+            let confidence = decision.confidence_scores.get(i).copied().unwrap_or(1.0);
+            let weight = decision.gating_weights.get(i).copied().unwrap_or(1.0);
+            (id, confidence, weight)
+        })
+        .collect()
+}
+
+impl<R: Router> Router for RateLimitedRouter<R> {
+    // Built-in routers return their Max-tier selection as a ranked superset
+    // of every smaller tier, so it doubles as the next-best candidate list.
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        let decision = self.inner.route(tier, token_index);
+        let k = decision.expert_ids.len() as u32;
+        self.enforce(RoutingOutcome::new(decision, k), || {
+            ranked_candidates(self.inner.route(Tier::Max, token_index))
+        })
+        .decision
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        let decision = self.inner.route_with_weights(tier, token_index, weights);
+        let k = decision.expert_ids.len() as u32;
+        let outcome = self.enforce(RoutingOutcome::new(decision, k), || {
+            let mut ranked: Vec<(ExpertId, f32, f32)> = weights
+                .iter()
+                .map(|(id, w)| (id.clone(), 1.0, *w))
+                .collect();
+            ranked.sort_by(|a, b| crate::descending(a.2, b.2));
+            ranked
+        });
+        outcome.decision
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.route_outcome(ctx).decision
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        let outcome = self.inner.route_outcome(ctx);
+        self.enforce(outcome, || {
+            let mut wide = ctx.clone();
+            wide.tier = Tier::Max;
+            ranked_candidates(self.inner.route_with_context(&wide))
+        })
+    }
+
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        let explanation = self.inner.explain(ctx);
+        let outcome = self.enforce(explanation.outcome.clone(), || {
+            let mut wide = ctx.clone();
+            wide.tier = Tier::Max;
+            ranked_candidates(self.inner.route_with_context(&wide))
+        });
+        explanation.layer("rate_limit", outcome)
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for RateLimitedRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.inner.begin_sequence(seq_id)
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.inner.end_sequence(seq_id)
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.inner.active_sequences()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter};
+
+    #[test]
+    fn test_rate_limit_redirects_to_next_best() {
+        let router = RateLimitedRouter::new(DeterministicRouter::new_unchecked(64));
+        router.set_limit(index_expert_id(0), ExpertRateLimit::new(0.0, 2.0));
+
+        for _ in 0..2 {
+            let decision = router.route(Tier::Nano, 0);
+            assert_eq!(
+                decision.expert_ids,
+                vec![index_expert_id(0), index_expert_id(1)]
+            );
+        }
+
+        let decision = router.route(Tier::Nano, 0);
+        assert_eq!(
+            decision.expert_ids,
+            vec![index_expert_id(1), index_expert_id(2)]
+        );
+        assert_eq!(router.throttle_counts()[&index_expert_id(0)], 1);
+        assert_eq!(router.redirected_count(), 1);
+        assert_eq!(router.dropped_count(), 0);
+
+        let outcome = router.route_outcome(&RoutingContext::new(Tier::Nano, 0));
+        assert!(outcome.fallback_used);
+        assert_eq!(outcome.dropped[0].reason, DropReason::RateLimited);
+        assert_eq!(outcome.shortfall(), 0);
+    }
+
+    #[test]
+    fn test_rate_limit_drops_when_no_candidates() {
+        let router = RateLimitedRouter::new(DeterministicRouter::new_unchecked(2));
+        router.set_limit(index_expert_id(0), ExpertRateLimit::new(0.0, 1.0));
+        router.set_limit(index_expert_id(1), ExpertRateLimit::new(0.0, 1.0));
+
+        assert_eq!(router.route(Tier::Nano, 0).expert_ids.len(), 2);
+        assert!(router.route(Tier::Nano, 0).expert_ids.is_empty());
+        assert_eq!(router.dropped_count(), 2);
+    }
+}
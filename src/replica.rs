@@ -0,0 +1,300 @@
+// File: replica.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Expert replica groups for AURIA Runtime Core.
+//     A logical expert may be served by several physical replicas. After the
+//     inner router picks logical experts, a ReplicaRouter maps each one to a
+//     replica by round-robin, least recent load, or node locality, and
+//     reports both the logical and the physical selection.
+//
+use crate::{
+    LoadWindow, Router, RouterState, RoutingContext, RoutingExplanation, RoutingOutcome,
+    StatefulRouter,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Replica {
+    pub id: ExpertId,
+    pub node: u32,
+}
+
+impl Replica {
+    pub fn new(id: ExpertId, node: u32) -> Self {
+        Self { id, node }
+    }
+}
+
+// Experts without a group are their own single replica.
+#[derive(Clone, Debug, Default)]
+pub struct ReplicaGroups {
+    groups: HashMap<ExpertId, Vec<Replica>>,
+}
+
+impl ReplicaGroups {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, logical: ExpertId, replicas: Vec<Replica>) {
+        if replicas.is_empty() {
+            self.groups.remove(&logical);
+        } else {
+            self.groups.insert(logical, replicas);
+        }
+    }
+
+    pub fn with_group(mut self, logical: ExpertId, replicas: Vec<Replica>) -> Self {
+        self.insert(logical, replicas);
+        self
+    }
+
+    pub fn replicas(&self, logical: &ExpertId) -> Option<&[Replica]> {
+        self.groups.get(logical).map(Vec::as_slice)
+    }
+
+    pub fn replica_count(&self, logical: &ExpertId) -> usize {
+        self.groups.get(logical).map_or(1, Vec::len)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReplicaStrategy {
+    // Cycles through each expert's replicas independently.
+    RoundRobin,
+    // The replica with the fewest assignments in the load window.
+    LeastLoaded,
+    // Least loaded among the replicas on `node`, or among all replicas when
+    // none is local.
+    Locality { node: u32 },
+}
+
+#[derive(Clone, Debug)]
+pub struct ReplicatedDecision {
+    pub decision: RoutingDecision,
+    // Aligned with `decision.expert_ids`.
+    pub physical_ids: Vec<ExpertId>,
+}
+
+impl ReplicatedDecision {
+    pub fn logical_ids(&self) -> &[ExpertId] {
+        &self.decision.expert_ids
+    }
+
+    // The decision with each logical id replaced by its replica.
+    pub fn physical_decision(&self) -> RoutingDecision {
+        RoutingDecision {
+            expert_ids: self.physical_ids.clone(),
+            ..self.decision.clone()
+        }
+    }
+}
+
+pub struct ReplicaRouter<R: Router> {
+    inner: R,
+    groups: ReplicaGroups,
+    strategy: ReplicaStrategy,
+    cursors: Mutex<HashMap<ExpertId, usize>>,
+    load: Arc<LoadWindow>,
+    // False when observing a shared window fed by a MeteredRouter.
+    records_load: bool,
+}
+
+impl<R: Router> ReplicaRouter<R> {
+    pub fn new(inner: R, groups: ReplicaGroups, strategy: ReplicaStrategy) -> Self {
+        Self {
+            inner,
+            groups,
+            strategy,
+            cursors: Mutex::new(HashMap::new()),
+            load: Arc::new(LoadWindow::tokens(1024)),
+            records_load: true,
+        }
+    }
+
+    // Balances by a window shared with other layers instead of its own. The
+    // router stops recording; a MeteredRouter must feed the window with
+    // physical ids.
+    pub fn with_load_window(mut self, window: Arc<LoadWindow>) -> Self {
+        self.load = window;
+        self.records_load = false;
+        self
+    }
+
+    pub fn groups(&self) -> &ReplicaGroups {
+        &self.groups
+    }
+
+    pub fn strategy(&self) -> ReplicaStrategy {
+        self.strategy
+    }
+
+    pub fn route_replicated(&self, ctx: &RoutingContext) -> ReplicatedDecision {
+        let decision = self.inner.route_with_context(ctx);
+        let physical_ids = self.place(&decision.expert_ids);
+        ReplicatedDecision {
+            decision,
+            physical_ids,
+        }
+    }
+
+    fn place(&self, logical: &[ExpertId]) -> Vec<ExpertId> {
+        let mut window = self.load.lock();
+        let physical: Vec<ExpertId> = logical
+            .iter()
+            .map(|id| {
+                let Some(replicas) = self.groups.replicas(id) else {
+                    return id.clone();
+                };
+                let least_loaded = |candidates: &mut dyn Iterator<Item = &Replica>| {
+                    // `min_by_key` keeps the first of equal loads.
+                    candidates
+                        .min_by_key(|r| window.count(&r.id))
+                        .map(|r| r.id.clone())
+                };
+                let placed = match self.strategy {
+                    ReplicaStrategy::RoundRobin => {
+                        let mut cursors = self.cursors.lock().unwrap();
+                        let cursor = cursors.entry(id.clone()).or_insert(0);
+                        let replica = replicas[*cursor % replicas.len()].id.clone();
+                        *cursor = cursor.wrapping_add(1);
+                        Some(replica)
+                    }
+                    ReplicaStrategy::LeastLoaded => least_loaded(&mut replicas.iter()),
+                    ReplicaStrategy::Locality { node } => {
+                        least_loaded(&mut replicas.iter().filter(|r| r.node == node))
+                            .or_else(|| least_loaded(&mut replicas.iter()))
+                    }
+                };
+                placed.unwrap_or_else(|| id.clone())
+            })
+            .collect();
+        if self.records_load {
+            window.push(&physical, Instant::now());
+        }
+        physical
+    }
+
+    fn physical(&self, mut decision: RoutingDecision) -> RoutingDecision {
+        decision.expert_ids = self.place(&decision.expert_ids);
+        decision
+    }
+}
+
+// Decisions leave this router with physical ids; use `route_replicated`
+// to keep the logical selection alongside.
+impl<R: Router> Router for ReplicaRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.physical(self.inner.route(tier, token_index))
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        self.physical(self.inner.route_with_weights(tier, token_index, weights))
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.route_replicated(ctx).physical_decision()
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        let mut outcome = self.inner.route_outcome(ctx);
+        outcome.decision = self.physical(outcome.decision);
+        outcome
+    }
+
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        let explanation = self.inner.explain(ctx);
+        let mut outcome = explanation.outcome.clone();
+        outcome.decision = self.physical(outcome.decision);
+        explanation.layer("replicas", outcome)
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for ReplicaRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.inner.begin_sequence(seq_id)
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.inner.end_sequence(seq_id)
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.inner.active_sequences()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter};
+
+    fn replica(expert: u8, replica: u8, node: u32) -> Replica {
+        let mut bytes = [0u8; 32];
+        bytes[0] = expert;
+        bytes[31] = replica + 1;
+        Replica::new(ExpertId(bytes), node)
+    }
+
+    fn groups() -> ReplicaGroups {
+        ReplicaGroups::new().with_group(
+            index_expert_id(0),
+            vec![replica(0, 0, 0), replica(0, 1, 1), replica(0, 2, 1)],
+        )
+    }
+
+    #[test]
+    fn test_replica_strategies() {
+        let ctx = RoutingContext::new(Tier::Nano, 0);
+        let router = ReplicaRouter::new(
+            DeterministicRouter::new_unchecked(64),
+            groups(),
+            ReplicaStrategy::RoundRobin,
+        );
+        let placed: Vec<ExpertId> = (0..4)
+            .map(|_| router.route_replicated(&ctx).physical_ids[0].clone())
+            .collect();
+        assert_eq!(placed[0], replica(0, 0, 0).id);
+        assert_eq!(placed[1], replica(0, 1, 1).id);
+        assert_eq!(placed[3], placed[0]);
+
+        let replicated = router.route_replicated(&ctx);
+        assert_eq!(replicated.logical_ids()[0], index_expert_id(0));
+        // Ungrouped experts keep their logical id.
+        assert_eq!(replicated.physical_ids[1], index_expert_id(1));
+
+        let router = ReplicaRouter::new(
+            DeterministicRouter::new_unchecked(64),
+            groups(),
+            ReplicaStrategy::Locality { node: 1 },
+        );
+        let placed: Vec<ExpertId> = (0..4)
+            .map(|_| router.route(Tier::Nano, 0).expert_ids[0].clone())
+            .collect();
+        assert_eq!(
+            placed,
+            vec![
+                replica(0, 1, 1).id,
+                replica(0, 2, 1).id,
+                replica(0, 1, 1).id,
+                replica(0, 2, 1).id
+            ]
+        );
+    }
+}
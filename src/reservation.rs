@@ -0,0 +1,274 @@
+// File: reservation.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Two-phase routing for admission control in AURIA Runtime Core.
+//     ReservingRouter hands out tentative Reservations that count against
+//     per-expert capacity until they are committed or released, so a
+//     scheduler can route a batch, check aggregate expert load, and re-route
+//     the spill before anything is dispatched.
+//
+use crate::{
+    decision_entries, decision_from_entries, Router, RouterState, RoutingContext, RoutingOutcome,
+    StatefulRouter,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+// Not Clone: a reservation is consumed exactly once by commit or release.
+// Dropping it without either leaves its load reserved until clear_reserved.
+#[must_use = "reservations hold expert capacity until committed or released"]
+#[derive(Debug)]
+pub struct Reservation {
+    pub id: u64,
+    pub tier: Tier,
+    pub token_index: u64,
+    pub decision: RoutingDecision,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CapacityExcess {
+    pub expert_id: ExpertId,
+    pub load: u64,
+    pub capacity: u64,
+}
+
+#[derive(Default)]
+struct Ledger {
+    capacity: HashMap<ExpertId, u64>,
+    reserved: HashMap<ExpertId, u64>,
+    committed: HashMap<ExpertId, u64>,
+}
+
+impl Ledger {
+    fn load(&self, id: &ExpertId) -> u64 {
+        self.reserved.get(id).copied().unwrap_or(0) + self.committed.get(id).copied().unwrap_or(0)
+    }
+}
+
+fn decrement(counts: &mut HashMap<ExpertId, u64>, id: &ExpertId) {
+    if let Some(count) = counts.get_mut(id) {
+        *count -= 1;
+        if *count == 0 {
+            counts.remove(id);
+        }
+    }
+}
+
+pub struct ReservingRouter<R: Router> {
+    inner: R,
+    next_id: AtomicU64,
+    ledger: Mutex<Ledger>,
+}
+
+impl<R: Router> ReservingRouter<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            next_id: AtomicU64::new(0),
+            ledger: Mutex::new(Ledger::default()),
+        }
+    }
+
+    pub fn set_capacity(&self, expert_id: ExpertId, capacity: u64) {
+        self.ledger
+            .lock()
+            .unwrap()
+            .capacity
+            .insert(expert_id, capacity);
+    }
+
+    pub fn reserve(&self, tier: Tier, token_index: u64) -> Reservation {
+        let decision = self.inner.route(tier, token_index);
+        self.hold(tier, token_index, decision)
+    }
+
+    pub fn reserve_batch(&self, tier: Tier, token_indices: &[u64]) -> Vec<Reservation> {
+        token_indices
+            .iter()
+            .map(|&token_index| self.reserve(tier, token_index))
+            .collect()
+    }
+
+    // Re-routes around `excluded` experts (typically the over-capacity ones),
+    // taking next-best candidates from the inner Max-tier selection.
+    pub fn reserve_excluding(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        excluded: &[ExpertId],
+    ) -> Reservation {
+        let decision = self.inner.route(tier, token_index);
+        let k = decision.expert_ids.len();
+        let timestamp = decision.timestamp;
+        let entries = decision_entries(decision)
+            .into_iter()
+            .chain(decision_entries(self.inner.route(Tier::Max, token_index)))
+            .filter(|(id, _, _)| !excluded.contains(id));
+        let mut chosen: Vec<(ExpertId, f32, f32)> = Vec::with_capacity(k);
+        for entry in entries {
+            if chosen.len() == k {
+                break;
+            }
+            if !chosen.iter().any(|(id, _, _)| *id == entry.0) {
+                chosen.push(entry);
+            }
+        }
+        self.hold(tier, token_index, decision_from_entries(chosen, timestamp))
+    }
+
+    pub fn commit(&self, reservation: Reservation) -> RoutingDecision {
+        let mut ledger = self.ledger.lock().unwrap();
+        for id in &reservation.decision.expert_ids {
+            decrement(&mut ledger.reserved, id);
+            *ledger.committed.entry(id.clone()).or_insert(0) += 1;
+        }
+        reservation.decision
+    }
+
+    pub fn release(&self, reservation: Reservation) {
+        let mut ledger = self.ledger.lock().unwrap();
+        for id in &reservation.decision.expert_ids {
+            decrement(&mut ledger.reserved, id);
+        }
+    }
+
+    // Experts whose reserved plus committed load exceeds their capacity.
+    pub fn over_capacity(&self) -> Vec<CapacityExcess> {
+        let ledger = self.ledger.lock().unwrap();
+        let mut excess: Vec<CapacityExcess> = ledger
+            .capacity
+            .iter()
+            .filter_map(|(id, &capacity)| {
+                let load = ledger.load(id);
+                (load > capacity).then(|| CapacityExcess {
+                    expert_id: id.clone(),
+                    load,
+                    capacity,
+                })
+            })
+            .collect();
+        excess.sort_by_key(|e| e.expert_id.0);
+        excess
+    }
+
+    pub fn reserved_load(&self) -> HashMap<ExpertId, u64> {
+        self.ledger.lock().unwrap().reserved.clone()
+    }
+
+    pub fn committed_load(&self) -> HashMap<ExpertId, u64> {
+        self.ledger.lock().unwrap().committed.clone()
+    }
+
+    // Starts a new capacity window; outstanding reservations are kept.
+    pub fn clear_committed(&self) {
+        self.ledger.lock().unwrap().committed.clear();
+    }
+
+    pub fn clear_reserved(&self) {
+        self.ledger.lock().unwrap().reserved.clear();
+    }
+
+    fn hold(&self, tier: Tier, token_index: u64, decision: RoutingDecision) -> Reservation {
+        let mut ledger = self.ledger.lock().unwrap();
+        for id in &decision.expert_ids {
+            *ledger.reserved.entry(id.clone()).or_insert(0) += 1;
+        }
+        Reservation {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+            tier,
+            token_index,
+            decision,
+        }
+    }
+}
+
+// Plain routing calls reserve and commit in one step.
+impl<R: Router> Router for ReservingRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.commit(self.reserve(tier, token_index))
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        let decision = self.inner.route_with_weights(tier, token_index, weights);
+        self.commit(self.hold(tier, token_index, decision))
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        let decision = self.inner.route_with_context(ctx);
+        self.commit(self.hold(ctx.tier, ctx.token_index, decision))
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        let mut outcome = self.inner.route_outcome(ctx);
+        let reservation = self.hold(ctx.tier, ctx.token_index, outcome.decision);
+        outcome.decision = self.commit(reservation);
+        outcome
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for ReservingRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.inner.begin_sequence(seq_id)
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.inner.end_sequence(seq_id)
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.inner.active_sequences()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter};
+
+    #[test]
+    fn test_reserve_check_reroute_commit() {
+        let router = ReservingRouter::new(DeterministicRouter::new_unchecked(64));
+        router.set_capacity(index_expert_id(1), 2);
+
+        // Expert 1 serves tokens 0 and 1 at Nano, so a third request spills.
+        let batch = router.reserve_batch(Tier::Nano, &[0, 1, 1]);
+        let excess = router.over_capacity();
+        assert_eq!(excess.len(), 1);
+        assert_eq!(excess[0].load, 3);
+
+        let mut batch = batch.into_iter();
+        let first: Vec<RoutingDecision> =
+            batch.by_ref().take(2).map(|r| router.commit(r)).collect();
+        let spill = batch.next().unwrap();
+        let token = spill.token_index;
+        router.release(spill);
+        assert!(router.over_capacity().is_empty());
+
+        let rerouted = router.reserve_excluding(Tier::Nano, token, &[index_expert_id(1)]);
+        assert_eq!(
+            rerouted.decision.expert_ids,
+            vec![index_expert_id(2), index_expert_id(3)]
+        );
+        router.commit(rerouted);
+
+        assert_eq!(first.len(), 2);
+        assert_eq!(router.committed_load()[&index_expert_id(1)], 2);
+        assert!(router.reserved_load().is_empty());
+        assert!(router.over_capacity().is_empty());
+    }
+}
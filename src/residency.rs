@@ -0,0 +1,127 @@
+// File: residency.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Warm/cold expert awareness for weight-paging runtimes in AURIA
+//     Runtime Core. A residency signal reports which experts are loaded in
+//     VRAM/RAM; resident experts whose gate score is within a margin of the
+//     selection cutoff get a ranking bonus, so a marginal score edge does
+//     not force a cold expert to be paged in.
+//
+use auria_core::ExpertId;
+use std::collections::HashSet;
+use std::sync::{Arc, RwLock};
+
+pub trait ResidencySource: Send + Sync {
+    fn is_resident(&self, expert_id: &ExpertId) -> bool;
+}
+
+impl ResidencySource for HashSet<ExpertId> {
+    fn is_resident(&self, expert_id: &ExpertId) -> bool {
+        self.contains(expert_id)
+    }
+}
+
+// Shared, runtime-updatable resident set for the paging runtime to drive.
+#[derive(Debug, Default)]
+pub struct ResidentSet {
+    experts: RwLock<HashSet<ExpertId>>,
+}
+
+impl ResidentSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn mark_resident(&self, expert_id: ExpertId) {
+        self.experts.write().unwrap().insert(expert_id);
+    }
+
+    pub fn mark_evicted(&self, expert_id: &ExpertId) {
+        self.experts.write().unwrap().remove(expert_id);
+    }
+
+    pub fn replace(&self, experts: HashSet<ExpertId>) {
+        *self.experts.write().unwrap() = experts;
+    }
+}
+
+impl ResidencySource for ResidentSet {
+    fn is_resident(&self, expert_id: &ExpertId) -> bool {
+        self.experts.read().unwrap().contains(expert_id)
+    }
+}
+
+// `bonus` and `margin` are in ranking-key units: gate scores, or log-scores
+// plus noise when sampling.
+#[derive(Clone)]
+pub struct ResidencyBonus {
+    source: Arc<dyn ResidencySource>,
+    bonus: f32,
+    margin: f32,
+}
+
+impl ResidencyBonus {
+    pub fn new(source: Arc<dyn ResidencySource>, bonus: f32, margin: f32) -> Self {
+        Self {
+            source,
+            bonus: bonus.max(0.0),
+            margin: margin.max(0.0),
+        }
+    }
+
+    // `ranked` holds (id, score, key) sorted by key descending. Resident
+    // experts within `margin` of the k-th key are boosted and re-ranked; the
+    // reported scores are left untouched.
+    pub(crate) fn apply(&self, ranked: &mut [(ExpertId, f32, f32)], k: usize) {
+        if k == 0 || ranked.len() <= k {
+            return;
+        }
+        let cutoff = ranked[k - 1].2 - self.margin;
+        let mut boosted = false;
+        for (id, _, key) in ranked.iter_mut() {
+            if *key < cutoff {
+                break;
+            }
+            if self.source.is_resident(id) {
+                *key += self.bonus;
+                boosted = true;
+            }
+        }
+        if boosted {
+            ranked.sort_by(|a, b| crate::descending(a.2, b.2));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, GatingRouter, Router};
+    use auria_core::Tier;
+
+    #[test]
+    fn test_resident_expert_wins_close_call() {
+        let resident = Arc::new(ResidentSet::new());
+        let mut router = GatingRouter::new_unchecked(1.0);
+        router.set_gate_weight(index_expert_id(0), 3.0);
+        router.set_gate_weight(index_expert_id(1), 1.02);
+        router.set_gate_weight(index_expert_id(2), 1.0);
+        router.set_gate_weight(index_expert_id(3), -2.0);
+        router.set_residency_bonus(Some(ResidencyBonus::new(resident.clone(), 0.05, 0.02)));
+
+        let cold = router.route(Tier::Nano, 0);
+        assert_eq!(
+            cold.expert_ids,
+            vec![index_expert_id(0), index_expert_id(1)]
+        );
+
+        resident.mark_resident(index_expert_id(2));
+        resident.mark_resident(index_expert_id(3));
+        let warm = router.route(Tier::Nano, 0);
+        assert_eq!(
+            warm.expert_ids,
+            vec![index_expert_id(0), index_expert_id(2)]
+        );
+        assert!(warm.gating_weights[1] < cold.gating_weights[1]);
+    }
+}
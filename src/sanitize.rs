@@ -0,0 +1,201 @@
+// File: sanitize.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Gate weight sanitation for AURIA Runtime Core.
+//     A WeightSanitizer repairs raw gate weights before GatingRouter uses
+//     them: non-finite values are replaced or dropped, outliers are clipped
+//     to a fixed range or a number of standard deviations, and the result
+//     can be L1/L2-normalized. A SanitationReport says what was fixed.
+//
+use auria_core::ExpertId;
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NonFinitePolicy {
+    Replace(f32),
+    Drop,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Clip {
+    #[default]
+    None,
+    Range {
+        min: f32,
+        max: f32,
+    },
+    // Clamp to mean ± n standard deviations of the finite weights.
+    StdDevs(f32),
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Normalization {
+    #[default]
+    None,
+    L1,
+    L2,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SanitationReport {
+    pub entries: usize,
+    pub non_finite_replaced: usize,
+    pub non_finite_dropped: usize,
+    pub clipped: usize,
+    pub normalized: bool,
+}
+
+impl SanitationReport {
+    pub fn fixed(&self) -> usize {
+        self.non_finite_replaced + self.non_finite_dropped + self.clipped
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct WeightSanitizer {
+    non_finite: NonFinitePolicy,
+    clip: Clip,
+    normalization: Normalization,
+}
+
+impl Default for WeightSanitizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WeightSanitizer {
+    // Replaces non-finite weights with 0.0; no clipping or normalization.
+    pub fn new() -> Self {
+        Self {
+            non_finite: NonFinitePolicy::Replace(0.0),
+            clip: Clip::None,
+            normalization: Normalization::None,
+        }
+    }
+
+    pub fn with_non_finite(mut self, policy: NonFinitePolicy) -> Self {
+        self.non_finite = match policy {
+            NonFinitePolicy::Replace(v) if !v.is_finite() => NonFinitePolicy::Replace(0.0),
+            policy => policy,
+        };
+        self
+    }
+
+    pub fn with_clip(mut self, clip: Clip) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    pub fn with_normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    pub fn sanitize(&self, weights: &mut HashMap<ExpertId, f32>) -> SanitationReport {
+        let mut report = SanitationReport {
+            entries: weights.len(),
+            ..SanitationReport::default()
+        };
+
+        // Statistics come from the finite inputs only, before replacement
+        // values can skew them.
+        let range = match self.clip {
+            Clip::None => None,
+            Clip::Range { min, max } if min <= max => Some((min, max)),
+            Clip::Range { .. } => None,
+            Clip::StdDevs(n) => finite_stats(weights.values().copied())
+                .filter(|_| n.is_finite() && n >= 0.0)
+                .map(|(mean, std)| (mean - n * std, mean + n * std)),
+        };
+
+        weights.retain(|_, w| {
+            if !w.is_finite() {
+                return match self.non_finite {
+                    NonFinitePolicy::Replace(v) => {
+                        *w = v;
+                        report.non_finite_replaced += 1;
+                        true
+                    }
+                    NonFinitePolicy::Drop => {
+                        report.non_finite_dropped += 1;
+                        false
+                    }
+                };
+            }
+            if let Some((min, max)) = range {
+                let clipped = w.clamp(min, max);
+                if clipped != *w {
+                    *w = clipped;
+                    report.clipped += 1;
+                }
+            }
+            true
+        });
+
+        let norm = match self.normalization {
+            Normalization::None => 0.0,
+            Normalization::L1 => weights.values().map(|w| w.abs()).sum::<f32>(),
+            Normalization::L2 => weights.values().map(|w| w * w).sum::<f32>().sqrt(),
+        };
+        if norm > 0.0 && norm.is_finite() {
+            weights.values_mut().for_each(|w| *w /= norm);
+            report.normalized = true;
+        }
+        report
+    }
+}
+
+fn finite_stats(values: impl Iterator<Item = f32>) -> Option<(f32, f32)> {
+    let finite: Vec<f64> = values.filter(|v| v.is_finite()).map(f64::from).collect();
+    if finite.is_empty() {
+        return None;
+    }
+    let n = finite.len() as f64;
+    let mean = finite.iter().sum::<f64>() / n;
+    let variance = finite.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+    Some((mean as f32, variance.sqrt() as f32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, GatingRouter, Router};
+    use auria_core::Tier;
+
+    #[test]
+    fn test_sanitize_repairs_checkpoint_weights() {
+        let mut weights: HashMap<ExpertId, f32> =
+            (0..8).map(|i| (index_expert_id(i), 1.0)).collect();
+        weights.insert(index_expert_id(0), f32::INFINITY);
+        weights.insert(index_expert_id(1), f32::NAN);
+        weights.insert(index_expert_id(2), 50.0);
+
+        let sanitizer = WeightSanitizer::new()
+            .with_non_finite(NonFinitePolicy::Drop)
+            .with_clip(Clip::Range {
+                min: -4.0,
+                max: 4.0,
+            })
+            .with_normalization(Normalization::L1);
+        let report = sanitizer.sanitize(&mut weights);
+        assert_eq!(report.non_finite_dropped, 2);
+        assert_eq!(report.clipped, 1);
+        assert_eq!(report.fixed(), 3);
+        assert!(report.normalized);
+        assert_eq!(weights.len(), 6);
+        assert!((weights.values().sum::<f32>() - 1.0).abs() < 1e-6);
+        assert!((weights[&index_expert_id(2)] - 4.0 / 9.0).abs() < 1e-6);
+
+        let mut router = GatingRouter::new_unchecked(1.0);
+        router.set_weight_sanitizer(Some(WeightSanitizer::new().with_clip(Clip::StdDevs(1.0))));
+        let mut raw: HashMap<ExpertId, f32> =
+            (0..8).map(|i| (index_expert_id(i), i as f32)).collect();
+        raw.insert(index_expert_id(8), f32::INFINITY);
+        let report = router.set_gate_weights(raw);
+        assert_eq!(report.non_finite_replaced, 1);
+        assert!(report.clipped > 0);
+        let decision = router.route(Tier::Nano, 0);
+        assert!(decision.gating_weights.iter().all(|w| w.is_finite()));
+    }
+}
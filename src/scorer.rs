@@ -0,0 +1,137 @@
+// File: scorer.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Gate scoring functions for AURIA Runtime Core.
+//     A Scorer turns temperature-scaled gate logits into expert scores.
+//     Built-ins cover softmax, sigmoid (independent per-expert gates),
+//     sparsemax, and raw logits for plain top-k.
+//
+pub trait Scorer: Send + Sync {
+    fn score(&self, logits: &[f32], temperature: f32) -> Vec<f32>;
+
+    // Buffer-reusing form used by the dense batch path; `out` is cleared
+    // first. Override to avoid the intermediate Vec.
+    fn score_into(&self, logits: &[f32], temperature: f32, out: &mut Vec<f32>) {
+        out.clear();
+        out.extend(self.score(logits, temperature));
+    }
+
+    // Key perturbed by Gumbel noise when sampling; log-scores by default.
+    fn sampling_key(&self, score: f32) -> f32 {
+        score.ln()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Softmax;
+
+impl Scorer for Softmax {
+    fn score(&self, logits: &[f32], temperature: f32) -> Vec<f32> {
+        let mut out = Vec::with_capacity(logits.len());
+        softmax_into(logits, temperature, &mut out);
+        out
+    }
+
+    fn score_into(&self, logits: &[f32], temperature: f32, out: &mut Vec<f32>) {
+        softmax_into(logits, temperature, out);
+    }
+}
+
+pub(crate) fn softmax_into(logits: &[f32], temperature: f32, out: &mut Vec<f32>) {
+    let max_logit = logits.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    out.clear();
+    out.extend(logits.iter().map(|l| ((l - max_logit) / temperature).exp()));
+    let sum: f32 = out.iter().sum();
+    out.iter_mut().for_each(|e| *e /= sum);
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Sigmoid;
+
+impl Scorer for Sigmoid {
+    fn score(&self, logits: &[f32], temperature: f32) -> Vec<f32> {
+        logits
+            .iter()
+            .map(|l| 1.0 / (1.0 + (-l / temperature).exp()))
+            .collect()
+    }
+
+    fn score_into(&self, logits: &[f32], temperature: f32, out: &mut Vec<f32>) {
+        out.clear();
+        out.extend(
+            logits
+                .iter()
+                .map(|l| 1.0 / (1.0 + (-l / temperature).exp())),
+        );
+    }
+}
+
+// Euclidean projection onto the probability simplex (Martins & Astudillo,
+// 2016); low-scoring experts get exactly zero.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Sparsemax;
+
+impl Scorer for Sparsemax {
+    fn score(&self, logits: &[f32], temperature: f32) -> Vec<f32> {
+        let z: Vec<f32> = logits.iter().map(|l| l / temperature).collect();
+        let mut sorted = z.clone();
+        sorted.sort_by(|a, b| b.total_cmp(a));
+
+        let mut cumulative = 0.0;
+        let mut support_sum = 0.0;
+        let mut support = 0;
+        for (i, &zi) in sorted.iter().enumerate() {
+            cumulative += zi;
+            if 1.0 + (i + 1) as f32 * zi > cumulative {
+                support = i + 1;
+                support_sum = cumulative;
+            }
+        }
+        let tau = (support_sum - 1.0) / support.max(1) as f32;
+        z.into_iter().map(|zi| (zi - tau).max(0.0)).collect()
+    }
+}
+
+// Ranks on the scaled logits themselves.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RawLogits;
+
+impl Scorer for RawLogits {
+    fn score(&self, logits: &[f32], temperature: f32) -> Vec<f32> {
+        logits.iter().map(|l| l / temperature).collect()
+    }
+
+    fn score_into(&self, logits: &[f32], temperature: f32, out: &mut Vec<f32>) {
+        out.clear();
+        out.extend(logits.iter().map(|l| l / temperature));
+    }
+
+    fn sampling_key(&self, score: f32) -> f32 {
+        score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_scorers() {
+        let logits = [2.0, 1.0, 0.0, -1.0];
+
+        let softmax = Softmax.score(&logits, 1.0);
+        assert!((softmax.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+
+        let sigmoid = Sigmoid.score(&logits, 1.0);
+        assert!((sigmoid[2] - 0.5).abs() < 1e-6);
+        assert!(sigmoid.iter().sum::<f32>() > 1.0);
+
+        let sparse = Sparsemax.score(&logits, 1.0);
+        assert_eq!(sparse, vec![1.0, 0.0, 0.0, 0.0]);
+        let sparse = Sparsemax.score(&[1.0, 0.8, -1.0], 1.0);
+        assert!((sparse[0] - 0.6).abs() < 1e-6 && (sparse[1] - 0.4).abs() < 1e-6);
+        assert_eq!(sparse[2], 0.0);
+
+        assert_eq!(RawLogits.score(&logits, 2.0), vec![1.0, 0.5, 0.0, -0.5]);
+    }
+}
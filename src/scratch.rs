@@ -0,0 +1,90 @@
+// File: scratch.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Reusable gate buffers for dense batch routing in AURIA Runtime Core.
+//     GateScratch holds the per-token logits, probabilities and top-k
+//     working set so GatingRouter can route contiguous logit rows aligned
+//     with an ExpertIndexMap without building a HashMap per token.
+//
+use crate::top_k_by_weight;
+
+// One per routing thread; buffers grow to the widest expert set seen and
+// are reused across calls.
+#[derive(Clone, Debug, Default)]
+pub struct GateScratch {
+    // Dense indices of the experts eligible for this token.
+    pub(crate) eligible: Vec<u32>,
+    pub(crate) logits: Vec<f32>,
+    pub(crate) probs: Vec<f32>,
+    // Per-index bias staged once per batch; empty when there are none.
+    pub(crate) bias: Vec<f32>,
+    pub(crate) order: Vec<(usize, f32)>,
+}
+
+impl GateScratch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(experts: usize) -> Self {
+        Self {
+            eligible: Vec::with_capacity(experts),
+            logits: Vec::with_capacity(experts),
+            probs: Vec::with_capacity(experts),
+            bias: Vec::new(),
+            order: Vec::with_capacity(experts),
+        }
+    }
+}
+
+// Fills `out` with the k best (position, key) pairs in descending key order.
+pub(crate) fn topk_into(keys: &[f32], k: usize, out: &mut Vec<(usize, f32)>) {
+    out.clear();
+    out.extend(keys.iter().copied().enumerate());
+    let k = top_k_by_weight(out, k).len();
+    out.truncate(k);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, ExpertIndexMap, GatingRouter, Router};
+    use auria_core::Tier;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_dense_logits_match_hashmap_path() {
+        let registry: ExpertIndexMap = (0..32).map(index_expert_id).collect();
+        let tokens: Vec<u64> = (0..4).collect();
+        let logits: Vec<f32> = (0..4 * 32)
+            .map(|i| ((i * 7919) % 101) as f32 / 10.0)
+            .collect();
+
+        let mut router = GatingRouter::new_unchecked(0.7);
+        router.set_min_gate_prob(0.01, true);
+        router.set_expert_bias(index_expert_id(3), 2.0);
+        let mut scratch = GateScratch::new();
+        let batch =
+            router.route_logits_batch(Tier::Standard, &tokens, &registry, &logits, &mut scratch);
+        assert_eq!(batch.len(), 4);
+
+        for (t, row) in logits.chunks(32).enumerate() {
+            let weights: HashMap<_, _> = registry
+                .ids()
+                .iter()
+                .cloned()
+                .zip(row.iter().copied())
+                .collect();
+            router.set_gate_weights(weights);
+            let expected = router.route(Tier::Standard, t as u64);
+            assert_eq!(batch[t].expert_ids, expected.expert_ids);
+            for (a, b) in batch[t].gating_weights.iter().zip(&expected.gating_weights) {
+                assert!((a - b).abs() < 1e-6);
+            }
+        }
+
+        let mut order = Vec::new();
+        topk_into(&[0.1, f32::NAN, 0.7, 0.3], 2, &mut order);
+        assert_eq!(order, vec![(2, 0.7), (3, 0.3)]);
+    }
+}
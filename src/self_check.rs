@@ -0,0 +1,129 @@
+// File: self_check.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Startup self-test for routers in AURIA Runtime Core.
+//     Exercises a router across tiers and a fixed token range, verifying
+//     that decisions are non-empty, duplicate-free, and stay within the
+//     expected expert count, so misconfiguration fails fast at boot.
+//
+use crate::{RouteError, Router, RoutingContext};
+use auria_core::{ExpertId, Tier};
+use std::collections::HashSet;
+
+pub const SELF_CHECK_TOKENS: u64 = 64;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelfCheckReport {
+    pub decisions_checked: usize,
+    pub tokens_checked: u64,
+    pub distinct_experts: usize,
+    pub tiers: Vec<Tier>,
+}
+
+// Stateful routers (e.g. RoundRobinRouter) advance their state while the
+// check runs, exactly as if the calls had been real traffic.
+pub(crate) fn run_self_check<R: Router + ?Sized>(
+    router: &R,
+    expected_expert_count: u32,
+    tiers: &[Tier],
+) -> Result<SelfCheckReport, RouteError> {
+    let expected = expected_expert_count as usize;
+    let mut distinct: HashSet<ExpertId> = HashSet::new();
+    let mut decisions_checked = 0;
+
+    for &tier in tiers {
+        for token_index in 0..SELF_CHECK_TOKENS {
+            // The router's own k, so custom KSelectors and revision tiers
+            // are checked against what they were configured to select.
+            let outcome = router.route_outcome(&RoutingContext::new(tier, token_index));
+            let limit = (outcome.requested_k as usize).min(expected.max(1));
+            let decision = outcome.decision;
+            decisions_checked += 1;
+
+            if decision.expert_ids.is_empty() {
+                return Err(RouteError::EmptyDecision { tier, token_index });
+            }
+            if decision.expert_ids.len() > limit {
+                return Err(RouteError::TooManyExperts {
+                    tier,
+                    token_index,
+                    count: decision.expert_ids.len(),
+                    limit,
+                });
+            }
+            let mut seen = HashSet::with_capacity(decision.expert_ids.len());
+            for id in &decision.expert_ids {
+                if !seen.insert(id) {
+                    return Err(RouteError::DuplicateExpert {
+                        tier,
+                        token_index,
+                        expert_id: id.clone(),
+                    });
+                }
+            }
+            distinct.extend(decision.expert_ids);
+        }
+    }
+
+    if distinct.len() > expected {
+        return Err(RouteError::ExpertSetOutOfRange {
+            observed: distinct.len(),
+            expected,
+        });
+    }
+
+    Ok(SelfCheckReport {
+        decisions_checked,
+        tokens_checked: SELF_CHECK_TOKENS,
+        distinct_experts: distinct.len(),
+        tiers: tiers.to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DeterministicRouter, HashRouter, RoundRobinRouter, TierKTable};
+
+    const ALL_TIERS: [Tier; 4] = [Tier::Nano, Tier::Standard, Tier::Pro, Tier::Max];
+
+    #[test]
+    fn test_self_check_passes_for_healthy_router() {
+        let report = HashRouter::new_unchecked(64, 3)
+            .self_check(64, &ALL_TIERS)
+            .unwrap();
+        assert_eq!(report.decisions_checked, 4 * SELF_CHECK_TOKENS as usize);
+        assert!(report.distinct_experts <= 64);
+    }
+
+    #[test]
+    fn test_self_check_catches_misconfiguration() {
+        let err = RoundRobinRouter::new_unchecked(Vec::new())
+            .self_check(8, &ALL_TIERS)
+            .unwrap_err();
+        assert!(matches!(err, RouteError::EmptyDecision { .. }));
+
+        let err = DeterministicRouter::new_unchecked(8)
+            .self_check(2, &[Tier::Standard])
+            .unwrap_err();
+        assert!(matches!(err, RouteError::TooManyExperts { .. }));
+
+        let err = HashRouter::new_unchecked(64, 3)
+            .self_check(16, &ALL_TIERS)
+            .unwrap_err();
+        assert!(matches!(err, RouteError::ExpertSetOutOfRange { .. }));
+    }
+
+    #[test]
+    fn test_self_check_uses_router_k() {
+        let table = TierKTable {
+            nano: 4,
+            standard: 1,
+            pro: 6,
+            max: 12,
+        };
+        // Nano selects 4 experts, above the default table's 2.
+        let router = DeterministicRouter::new_unchecked(64).with_k_selector(table);
+        assert!(router.self_check(64, &ALL_TIERS).is_ok());
+    }
+}
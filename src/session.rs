@@ -0,0 +1,256 @@
+// File: session.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Per-sequence routing state for AURIA Runtime Core.
+//     StatefulRouter adds begin/end lifecycle hooks so wrappers keep
+//     session state (sticky tables, canary assignments) only for sequences
+//     that are live, and drop it when the sequence ends instead of
+//     accumulating it for the lifetime of the process. Sticky tables are
+//     kept in a pluggable StickyStore (see affinity.rs).
+//
+use crate::{
+    AnyRouter, DeterministicRouter, GatingRouter, HashRouter, MemoryStickyStore, RoundRobinRouter,
+    Router, RouterState, RoutingContext, RoutingExplanation, RoutingOutcome, StickyRecord,
+    StickyStore, StoreError,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// Contexts carrying the id of a begun sequence (`RoutingContext::sequence_id`)
+// see that sequence's state; any other context routes statelessly.
+pub trait StatefulRouter: Router {
+    fn begin_sequence(&self, seq_id: u64);
+    fn end_sequence(&self, seq_id: u64);
+
+    fn active_sequences(&self) -> usize {
+        0
+    }
+}
+
+impl<R: StatefulRouter + ?Sized> StatefulRouter for Box<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        (**self).begin_sequence(seq_id)
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        (**self).end_sequence(seq_id)
+    }
+
+    fn active_sequences(&self) -> usize {
+        (**self).active_sequences()
+    }
+}
+
+// The core routers keep no per-sequence state; the no-op hooks let
+// stateful wrappers be stacked directly on top of them.
+impl StatefulRouter for DeterministicRouter {
+    fn begin_sequence(&self, _seq_id: u64) {}
+    fn end_sequence(&self, _seq_id: u64) {}
+}
+
+impl StatefulRouter for GatingRouter {
+    fn begin_sequence(&self, _seq_id: u64) {}
+    fn end_sequence(&self, _seq_id: u64) {}
+}
+
+impl StatefulRouter for RoundRobinRouter {
+    fn begin_sequence(&self, _seq_id: u64) {}
+    fn end_sequence(&self, _seq_id: u64) {}
+}
+
+impl StatefulRouter for HashRouter {
+    fn begin_sequence(&self, _seq_id: u64) {}
+    fn end_sequence(&self, _seq_id: u64) {}
+}
+
+impl StatefulRouter for AnyRouter {
+    fn begin_sequence(&self, _seq_id: u64) {}
+    fn end_sequence(&self, _seq_id: u64) {}
+}
+
+// Session affinity: the first decision a sequence makes at each layer is
+// reused for the rest of the sequence, or until `refresh_every` tokens
+// have reused it. The table lives in a StickyStore; when the store fails,
+// routing continues statelessly and the failure is counted.
+pub struct StickyRouter<R: Router> {
+    inner: R,
+    refresh_every: Option<u64>,
+    store: Box<dyn StickyStore>,
+    store_errors: AtomicU64,
+}
+
+impl<R: Router> StickyRouter<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            refresh_every: None,
+            store: Box::new(MemoryStickyStore::new()),
+            store_errors: AtomicU64::new(0),
+        }
+    }
+
+    pub fn with_refresh_every(mut self, tokens: u64) -> Self {
+        self.refresh_every = Some(tokens.max(1));
+        self
+    }
+
+    pub fn with_store(mut self, store: impl StickyStore + 'static) -> Self {
+        self.store = Box::new(store);
+        self
+    }
+
+    // The sticky experts a live sequence holds at `layer`.
+    pub fn sticky_experts(&self, seq_id: u64, layer: u32) -> Option<Vec<ExpertId>> {
+        self.checked(self.store.get(seq_id, layer))
+            .flatten()
+            .map(|record| record.experts())
+    }
+
+    pub fn store_errors(&self) -> u64 {
+        self.store_errors.load(Ordering::Relaxed)
+    }
+
+    fn checked<T>(&self, result: Result<T, StoreError>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(_) => {
+                self.store_errors.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    // A tier change invalidates the entry, since it changes k. Use counts
+    // are read-modify-write on the store, so concurrent hits on one entry
+    // may refresh it slightly late.
+    fn stick(
+        &self,
+        ctx: &RoutingContext,
+        route: impl FnOnce() -> RoutingOutcome,
+    ) -> RoutingOutcome {
+        let Some(seq_id) = ctx.sequence_id else {
+            return route();
+        };
+        let Some(existing) = self.checked(self.store.get(seq_id, ctx.layer)) else {
+            return route();
+        };
+        if let Some(mut record) = existing {
+            let fresh = self.refresh_every.is_none_or(|n| record.uses < n);
+            if record.is_tier(ctx.tier) && fresh {
+                record.uses += 1;
+                let outcome = record.to_outcome();
+                self.checked(self.store.put(seq_id, ctx.layer, record));
+                return outcome;
+            }
+        }
+        // The inner router is consulted without holding the store; the
+        // store ignores the record if the sequence ended meanwhile.
+        let outcome = route();
+        let record = StickyRecord::from_outcome(ctx.tier, &outcome);
+        self.checked(self.store.put(seq_id, ctx.layer, record));
+        outcome
+    }
+}
+
+impl<R: Router> Router for StickyRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.inner.route(tier, token_index)
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        self.inner.route_with_weights(tier, token_index, weights)
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.route_outcome(ctx).decision
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        self.stick(ctx, || self.inner.route_outcome(ctx))
+    }
+
+    fn route_outcome_with_weights(
+        &self,
+        ctx: &RoutingContext,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingOutcome {
+        self.stick(ctx, || self.inner.route_outcome_with_weights(ctx, weights))
+    }
+
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        let explanation = self.inner.explain(ctx);
+        let outcome = self.stick(ctx, || explanation.outcome.clone());
+        explanation.layer("sticky", outcome)
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for StickyRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.checked(self.store.begin(seq_id));
+        self.inner.begin_sequence(seq_id);
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.checked(self.store.end(seq_id));
+        self.inner.end_sequence(seq_id);
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.checked(self.store.live_sequences()).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter};
+
+    #[test]
+    fn test_sticky_state_is_scoped_to_sequence() {
+        let router =
+            StickyRouter::new(DeterministicRouter::new_unchecked(64)).with_refresh_every(2);
+        let at = |token| RoutingContext::new(Tier::Nano, token).with_sequence_id(7);
+
+        // Not begun: routes statelessly and keeps nothing.
+        assert_eq!(
+            router.route_with_context(&at(5)).expert_ids[0],
+            index_expert_id(5)
+        );
+        assert_eq!(router.active_sequences(), 0);
+
+        router.begin_sequence(7);
+        let first = router.route_with_context(&at(0)).expert_ids;
+        assert_eq!(router.route_with_context(&at(1)).expert_ids, first);
+        assert_eq!(router.route_with_context(&at(2)).expert_ids, first);
+        assert_eq!(
+            router.route_with_context(&at(3)).expert_ids[0],
+            index_expert_id(3)
+        );
+        assert_eq!(router.sticky_experts(7, 0).unwrap()[0], index_expert_id(3));
+
+        let other_layer = router.route_with_context(&at(9).with_layer(1));
+        assert_eq!(other_layer.expert_ids[0], index_expert_id(9));
+
+        router.end_sequence(7);
+        assert_eq!(router.active_sequences(), 0);
+        assert!(router.sticky_experts(7, 0).is_none());
+        assert_eq!(
+            router.route_with_context(&at(4)).expert_ids[0],
+            index_expert_id(4)
+        );
+    }
+}
@@ -0,0 +1,101 @@
+// File: shared.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Shared-expert configuration for AURIA Runtime Core.
+//     Models the "s shared experts always active plus k routed experts"
+//     architecture: shared ExpertIds are configured per tier, excluded
+//     from the routed pool, and placed ahead of the routed picks.
+//
+use auria_core::{ExpertId, Tier};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SharedExperts {
+    nano: Vec<ExpertId>,
+    standard: Vec<ExpertId>,
+    pro: Vec<ExpertId>,
+    max: Vec<ExpertId>,
+    weight: f32,
+}
+
+impl SharedExperts {
+    pub fn new(experts: Vec<ExpertId>) -> Self {
+        Self {
+            nano: experts.clone(),
+            standard: experts.clone(),
+            pro: experts.clone(),
+            max: experts,
+            weight: 1.0,
+        }
+    }
+
+    pub fn with_tier(mut self, tier: Tier, experts: Vec<ExpertId>) -> Self {
+        *self.slot_mut(tier) = experts;
+        self
+    }
+
+    // Gating weight reported for every shared expert; shared experts are
+    // not part of the routed softmax and are typically mixed at full weight.
+    pub fn with_weight(mut self, weight: f32) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    pub fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    pub fn for_tier(&self, tier: Tier) -> &[ExpertId] {
+        match tier {
+            Tier::Nano => &self.nano,
+            Tier::Standard => &self.standard,
+            Tier::Pro => &self.pro,
+            Tier::Max => &self.max,
+        }
+    }
+
+    pub fn is_shared(&self, tier: Tier, expert_id: &ExpertId) -> bool {
+        self.for_tier(tier).contains(expert_id)
+    }
+
+    fn slot_mut(&mut self, tier: Tier) -> &mut Vec<ExpertId> {
+        match tier {
+            Tier::Nano => &mut self.nano,
+            Tier::Standard => &mut self.standard,
+            Tier::Pro => &mut self.pro,
+            Tier::Max => &mut self.max,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, GatingRouter, Router, RoutingContext};
+
+    #[test]
+    fn test_shared_experts_lead_routed_picks() {
+        let shared = index_expert_id(0);
+        let mut router = GatingRouter::new_unchecked(1.0);
+        for i in 0..16 {
+            // The shared expert has the strongest logit but must not be
+            // counted against the routed k.
+            router.set_gate_weight(index_expert_id(i), if i == 0 { 10.0 } else { i as f32 });
+        }
+        router.set_shared_experts(Some(
+            SharedExperts::new(vec![shared.clone()]).with_tier(Tier::Nano, Vec::new()),
+        ));
+
+        let outcome = router.route_outcome(&RoutingContext::new(Tier::Standard, 0));
+        let ids = &outcome.decision.expert_ids;
+        assert_eq!(outcome.requested_k, 5);
+        assert_eq!(outcome.shared_count, 1);
+        assert_eq!(ids.len(), 5);
+        assert_eq!(ids[0], shared);
+        assert_eq!(ids.iter().filter(|id| **id == shared).count(), 1);
+        assert_eq!(outcome.decision.gating_weights[0], 1.0);
+
+        let nano = router.route(Tier::Nano, 0);
+        assert_eq!(nano.expert_ids.len(), 2);
+        assert!(!nano.expert_ids.contains(&shared));
+    }
+}
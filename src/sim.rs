@@ -0,0 +1,310 @@
+// File: sim.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Routing simulation harness for AURIA Runtime Core.
+//     Drives a router over synthetic token workloads (uniform, Zipfian,
+//     bursty sessions) in fixed-size batches and reports expert
+//     utilization, capacity overflow and per-call routing latency.
+//
+use crate::{Router, RoutingContext};
+use auria_core::{ExpertId, Tier};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rand_distr::{Distribution, Zipf};
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Instant;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TokenDistribution {
+    Uniform { vocab: u64 },
+    Zipf { vocab: u64, exponent: f64 },
+    // Interleaved sessions, each emitting `burst_len` consecutive positions
+    // before another session is picked.
+    BurstySessions { sessions: u64, burst_len: u64 },
+}
+
+#[derive(Clone, Debug)]
+pub struct SimConfig {
+    pub tier: Tier,
+    pub steps: u64,
+    pub batch_size: usize,
+    pub expert_count: u32,
+    pub capacity_factor: f32,
+    pub distribution: TokenDistribution,
+    pub seed: u64,
+}
+
+impl SimConfig {
+    pub fn new(tier: Tier, expert_count: u32, distribution: TokenDistribution) -> Self {
+        Self {
+            tier,
+            steps: 10_000,
+            batch_size: 256,
+            expert_count,
+            capacity_factor: 1.25,
+            distribution,
+            seed: 0,
+        }
+    }
+
+    pub fn with_steps(mut self, steps: u64) -> Self {
+        self.steps = steps;
+        self
+    }
+
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    pub fn with_capacity_factor(mut self, capacity_factor: f32) -> Self {
+        self.capacity_factor = capacity_factor;
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+}
+
+struct TokenStream {
+    distribution: TokenDistribution,
+    rng: StdRng,
+    zipf: Option<Zipf<f64>>,
+    session: u64,
+    positions: Vec<u64>,
+    remaining: u64,
+}
+
+impl TokenStream {
+    fn new(distribution: TokenDistribution, seed: u64) -> Self {
+        let zipf = match distribution {
+            TokenDistribution::Zipf { vocab, exponent } => Zipf::new(vocab.max(1), exponent).ok(),
+            _ => None,
+        };
+        let sessions = match distribution {
+            TokenDistribution::BurstySessions { sessions, .. } => sessions.max(1) as usize,
+            _ => 0,
+        };
+        Self {
+            distribution,
+            rng: StdRng::seed_from_u64(seed),
+            zipf,
+            session: 0,
+            positions: vec![0; sessions],
+            remaining: 0,
+        }
+    }
+
+    // (token_index, sequence_position)
+    fn next(&mut self) -> (u64, u64) {
+        match self.distribution {
+            TokenDistribution::Uniform { vocab } => (self.rng.gen_range(0..vocab.max(1)), 0),
+            TokenDistribution::Zipf { .. } => {
+                let rank = self.zipf.map_or(1.0, |z| z.sample(&mut self.rng));
+                (rank as u64 - 1, 0)
+            }
+            TokenDistribution::BurstySessions { burst_len, .. } => {
+                if self.remaining == 0 {
+                    self.session = self.rng.gen_range(0..self.positions.len() as u64);
+                    self.remaining = burst_len.max(1);
+                }
+                self.remaining -= 1;
+                let position = &mut self.positions[self.session as usize];
+                let pos = *position;
+                *position += 1;
+                // Sessions occupy disjoint token ranges.
+                ((self.session << 32) | pos, pos)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SimReport {
+    pub decisions: u64,
+    pub assignments: u64,
+    pub expert_count: u32,
+    pub expert_loads: HashMap<ExpertId, u64>,
+    pub overflow_assignments: u64,
+    pub batches: u64,
+    pub capacity_per_batch: u64,
+    pub latency_mean_ns: u64,
+    pub latency_p50_ns: u64,
+    pub latency_p99_ns: u64,
+    pub latency_max_ns: u64,
+}
+
+impl SimReport {
+    // Fraction of configured experts that received any traffic.
+    pub fn utilization(&self) -> f64 {
+        if self.expert_count == 0 {
+            return 0.0;
+        }
+        self.expert_loads.len() as f64 / self.expert_count as f64
+    }
+
+    // Max expert load over the mean load a perfectly balanced router would see.
+    pub fn load_imbalance(&self) -> f64 {
+        let max = self.expert_loads.values().copied().max().unwrap_or(0);
+        if self.expert_count == 0 || self.assignments == 0 {
+            return 0.0;
+        }
+        max as f64 / (self.assignments as f64 / self.expert_count as f64)
+    }
+
+    pub fn overflow_rate(&self) -> f64 {
+        if self.assignments == 0 {
+            return 0.0;
+        }
+        self.overflow_assignments as f64 / self.assignments as f64
+    }
+}
+
+impl fmt::Display for SimReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "decisions      {} ({} assignments, {} batches)",
+            self.decisions, self.assignments, self.batches
+        )?;
+        writeln!(
+            f,
+            "utilization    {:.1}% of {} experts, imbalance {:.2}x",
+            self.utilization() * 100.0,
+            self.expert_count,
+            self.load_imbalance()
+        )?;
+        writeln!(
+            f,
+            "overflow       {} assignments ({:.2}%), capacity {}/batch",
+            self.overflow_assignments,
+            self.overflow_rate() * 100.0,
+            self.capacity_per_batch
+        )?;
+        write!(
+            f,
+            "latency        mean {}ns p50 {}ns p99 {}ns max {}ns",
+            self.latency_mean_ns, self.latency_p50_ns, self.latency_p99_ns, self.latency_max_ns
+        )
+    }
+}
+
+fn capacity_per_batch<R: Router + ?Sized>(router: &R, config: &SimConfig) -> u64 {
+    let k = router
+        .route_outcome(&RoutingContext::new(config.tier, 0))
+        .requested_k as f64;
+    let fair = config.batch_size as f64 * k / config.expert_count.max(1) as f64;
+    (fair * config.capacity_factor as f64).ceil().max(1.0) as u64
+}
+
+pub fn run<R: Router + ?Sized>(router: &R, config: &SimConfig) -> SimReport {
+    let capacity = capacity_per_batch(router, config);
+    let mut tokens = TokenStream::new(config.distribution, config.seed);
+    let mut report = SimReport {
+        expert_count: config.expert_count,
+        capacity_per_batch: capacity,
+        ..SimReport::default()
+    };
+    let mut latencies = Vec::with_capacity(config.steps as usize);
+    let mut batch_loads: HashMap<ExpertId, u64> = HashMap::new();
+
+    for step in 0..config.steps {
+        let (token_index, position) = tokens.next();
+        let ctx = RoutingContext::new(config.tier, token_index).with_sequence_position(position);
+
+        let start = Instant::now();
+        let decision = router.route_with_context(&ctx);
+        latencies.push(start.elapsed().as_nanos() as u64);
+
+        report.decisions += 1;
+        for id in decision.expert_ids {
+            report.assignments += 1;
+            let load = batch_loads.entry(id.clone()).or_insert(0);
+            *load += 1;
+            if *load > capacity {
+                report.overflow_assignments += 1;
+            }
+            *report.expert_loads.entry(id).or_insert(0) += 1;
+        }
+
+        if (step + 1) % config.batch_size as u64 == 0 || step + 1 == config.steps {
+            report.batches += 1;
+            batch_loads.clear();
+        }
+    }
+
+    if !latencies.is_empty() {
+        latencies.sort_unstable();
+        let at = |q: f64| latencies[((latencies.len() - 1) as f64 * q).round() as usize];
+        report.latency_mean_ns = latencies.iter().sum::<u64>() / latencies.len() as u64;
+        report.latency_p50_ns = at(0.5);
+        report.latency_p99_ns = at(0.99);
+        report.latency_max_ns = *latencies.last().unwrap();
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DeterministicRouter, HashRouter};
+
+    #[test]
+    fn test_uniform_workload_spreads_load() {
+        let config = SimConfig::new(
+            Tier::Standard,
+            64,
+            TokenDistribution::Uniform { vocab: 50_000 },
+        )
+        .with_steps(4096);
+        let report = run(&HashRouter::new_unchecked(64, 7), &config);
+
+        assert_eq!(report.decisions, 4096);
+        assert_eq!(report.assignments, 4096 * 4);
+        assert_eq!(report.batches, 16);
+        assert_eq!(report.utilization(), 1.0);
+        assert!(report.load_imbalance() < 2.0);
+        assert!(report.latency_p50_ns <= report.latency_p99_ns);
+    }
+
+    #[test]
+    fn test_zipf_workload_overflows_hot_experts() {
+        let zipf = TokenDistribution::Zipf {
+            vocab: 50_000,
+            exponent: 1.2,
+        };
+        let uniform = TokenDistribution::Uniform { vocab: 50_000 };
+        let router = DeterministicRouter::new_unchecked(64);
+        let skewed = run(
+            &router,
+            &SimConfig::new(Tier::Nano, 64, zipf).with_steps(4096),
+        );
+        let flat = run(
+            &router,
+            &SimConfig::new(Tier::Nano, 64, uniform).with_steps(4096),
+        );
+
+        assert!(skewed.overflow_rate() > flat.overflow_rate());
+        assert!(skewed.to_string().contains("overflow"));
+    }
+
+    #[test]
+    fn test_bursty_sessions_advance_positions() {
+        let mut stream = TokenStream::new(
+            TokenDistribution::BurstySessions {
+                sessions: 4,
+                burst_len: 8,
+            },
+            1,
+        );
+        let burst: Vec<(u64, u64)> = (0..8).map(|_| stream.next()).collect();
+        let session = burst[0].0 >> 32;
+        for (i, &(token, position)) in burst.iter().enumerate() {
+            assert_eq!(token >> 32, session);
+            assert_eq!(position, burst[0].1 + i as u64);
+        }
+    }
+}
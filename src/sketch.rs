@@ -0,0 +1,410 @@
+// File: sketch.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Mergeable routing histograms for AURIA Runtime Core.
+//     Each worker records per-expert selection counts and HyperLogLog
+//     sketches of the distinct sequences that reached each expert. The
+//     sketches serialize to JSON and merge losslessly across workers, so
+//     fleet-wide utilization is reported without shipping audit logs.
+//
+use crate::audit::hex_ids;
+use crate::{
+    splitmix64, Router, RouterState, RoutingContext, RoutingExplanation, RoutingOutcome,
+    SketchError, StatefulRouter,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+// Deserialization goes through SequenceSketchWire, so a sketch whose
+// register count does not match its precision is rejected up front.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "SequenceSketchWire")]
+pub struct SequenceSketch {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+struct SequenceSketchWire {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl TryFrom<SequenceSketchWire> for SequenceSketch {
+    type Error = SketchError;
+
+    fn try_from(wire: SequenceSketchWire) -> Result<Self, SketchError> {
+        if !(4..=16).contains(&wire.precision) || wire.registers.len() != 1 << wire.precision {
+            return Err(SketchError::Parse(format!(
+                "{} registers for precision {}",
+                wire.registers.len(),
+                wire.precision
+            )));
+        }
+        Ok(Self {
+            precision: wire.precision,
+            registers: wire.registers,
+        })
+    }
+}
+
+impl SequenceSketch {
+    // 2^precision registers; the standard error is about 1.04 / sqrt(2^p).
+    pub fn new(precision: u8) -> Self {
+        let precision = precision.clamp(4, 16);
+        Self {
+            precision,
+            registers: vec![0; 1 << precision],
+        }
+    }
+
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    pub fn insert(&mut self, seq_id: u64) {
+        let h = splitmix64(seq_id);
+        let p = self.precision as u32;
+        let index = (h >> (64 - p)) as usize;
+        let rank = ((h << p) | (1 << (p - 1))).leading_zeros() as u8 + 1;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+
+    pub fn merge(&mut self, other: &SequenceSketch) -> Result<(), SketchError> {
+        if self.precision != other.precision {
+            return Err(SketchError::PrecisionMismatch {
+                left: self.precision,
+                right: other.precision,
+            });
+        }
+        for (r, o) in self.registers.iter_mut().zip(&other.registers) {
+            *r = (*r).max(*o);
+        }
+        Ok(())
+    }
+
+    pub fn estimate(&self) -> f64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let sum: f64 = self.registers.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+        let raw = alpha * m * m / sum;
+        let zeros = self.registers.iter().filter(|&&r| r == 0).count();
+        // Linear counting is more accurate while many registers are empty.
+        if raw <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            raw
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistogramSketch {
+    precision: u8,
+    decisions: u64,
+    counts: HashMap<ExpertId, u64>,
+    expert_sequences: HashMap<ExpertId, SequenceSketch>,
+    sequences: SequenceSketch,
+}
+
+#[derive(Serialize, Deserialize)]
+struct HistogramWire {
+    precision: u8,
+    decisions: u64,
+    #[serde(with = "hex_ids")]
+    experts: Vec<[u8; 32]>,
+    counts: Vec<u64>,
+    expert_sequences: Vec<Option<SequenceSketch>>,
+    sequences: SequenceSketch,
+}
+
+impl HistogramSketch {
+    pub fn new(precision: u8) -> Self {
+        let sequences = SequenceSketch::new(precision);
+        Self {
+            precision: sequences.precision(),
+            decisions: 0,
+            counts: HashMap::new(),
+            expert_sequences: HashMap::new(),
+            sequences,
+        }
+    }
+
+    // Decisions without a sequence id only count toward selections.
+    pub fn record(&mut self, seq_id: Option<u64>, decision: &RoutingDecision) {
+        self.decisions += 1;
+        for id in &decision.expert_ids {
+            *self.counts.entry(id.clone()).or_insert(0) += 1;
+            if let Some(seq_id) = seq_id {
+                self.expert_sequences
+                    .entry(id.clone())
+                    .or_insert_with(|| SequenceSketch::new(self.precision))
+                    .insert(seq_id);
+            }
+        }
+        if let Some(seq_id) = seq_id {
+            self.sequences.insert(seq_id);
+        }
+    }
+
+    pub fn merge(&mut self, other: &HistogramSketch) -> Result<(), SketchError> {
+        self.sequences.merge(&other.sequences)?;
+        self.decisions += other.decisions;
+        for (id, count) in &other.counts {
+            *self.counts.entry(id.clone()).or_insert(0) += count;
+        }
+        for (id, sketch) in &other.expert_sequences {
+            match self.expert_sequences.get_mut(id) {
+                Some(mine) => mine.merge(sketch)?,
+                None => {
+                    self.expert_sequences.insert(id.clone(), sketch.clone());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn decisions(&self) -> u64 {
+        self.decisions
+    }
+
+    pub fn count(&self, expert_id: &ExpertId) -> u64 {
+        self.counts.get(expert_id).copied().unwrap_or(0)
+    }
+
+    pub fn counts(&self) -> &HashMap<ExpertId, u64> {
+        &self.counts
+    }
+
+    pub fn distinct_sequences(&self, expert_id: &ExpertId) -> f64 {
+        self.expert_sequences
+            .get(expert_id)
+            .map_or(0.0, SequenceSketch::estimate)
+    }
+
+    pub fn total_distinct_sequences(&self) -> f64 {
+        self.sequences.estimate()
+    }
+
+    // Experts are written in id order, so equal sketches serialize equally.
+    pub fn to_json(&self) -> String {
+        let mut experts: Vec<&ExpertId> = self.counts.keys().collect();
+        experts.sort_by_key(|id| id.0);
+        let wire = HistogramWire {
+            precision: self.precision,
+            decisions: self.decisions,
+            experts: experts.iter().map(|id| id.0).collect(),
+            counts: experts.iter().map(|id| self.counts[*id]).collect(),
+            expert_sequences: experts
+                .iter()
+                .map(|id| self.expert_sequences.get(*id).cloned())
+                .collect(),
+            sequences: self.sequences.clone(),
+        };
+        serde_json::to_string(&wire).expect("histogram serializes")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, SketchError> {
+        let wire: HistogramWire =
+            serde_json::from_str(json).map_err(|e| SketchError::Parse(e.to_string()))?;
+        let valid = |s: &SequenceSketch| {
+            s.precision == wire.precision && s.registers.len() == 1 << wire.precision
+        };
+        if wire.experts.len() != wire.counts.len()
+            || wire.experts.len() != wire.expert_sequences.len()
+            || !(4..=16).contains(&wire.precision)
+            || !valid(&wire.sequences)
+            || !wire.expert_sequences.iter().flatten().all(valid)
+        {
+            return Err(SketchError::Parse("inconsistent sketch".to_string()));
+        }
+        let mut sketch = Self {
+            precision: wire.precision,
+            decisions: wire.decisions,
+            counts: HashMap::new(),
+            expert_sequences: HashMap::new(),
+            sequences: wire.sequences,
+        };
+        for ((id, count), sequences) in wire
+            .experts
+            .into_iter()
+            .zip(wire.counts)
+            .zip(wire.expert_sequences)
+        {
+            sketch.counts.insert(ExpertId(id), count);
+            if let Some(sequences) = sequences {
+                sketch.expert_sequences.insert(ExpertId(id), sequences);
+            }
+        }
+        Ok(sketch)
+    }
+}
+
+// Records every final decision, keyed by the context's sequence id.
+pub struct HistogramRouter<R: Router> {
+    inner: R,
+    histogram: Arc<Mutex<HistogramSketch>>,
+}
+
+impl<R: Router> HistogramRouter<R> {
+    pub fn new(inner: R, histogram: Arc<Mutex<HistogramSketch>>) -> Self {
+        Self { inner, histogram }
+    }
+
+    pub fn histogram(&self) -> &Arc<Mutex<HistogramSketch>> {
+        &self.histogram
+    }
+
+    // Swaps in an empty sketch and returns the recorded one, e.g. to ship
+    // one reporting interval at a time.
+    pub fn take(&self) -> HistogramSketch {
+        let mut histogram = self.histogram.lock().unwrap();
+        let empty = HistogramSketch::new(histogram.precision);
+        std::mem::replace(&mut *histogram, empty)
+    }
+
+    fn recorded(&self, seq_id: Option<u64>, decision: RoutingDecision) -> RoutingDecision {
+        self.histogram.lock().unwrap().record(seq_id, &decision);
+        decision
+    }
+}
+
+impl<R: Router> Router for HistogramRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.recorded(None, self.inner.route(tier, token_index))
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        self.recorded(
+            None,
+            self.inner.route_with_weights(tier, token_index, weights),
+        )
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.recorded(ctx.sequence_id, self.inner.route_with_context(ctx))
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        let outcome = self.inner.route_outcome(ctx);
+        self.histogram
+            .lock()
+            .unwrap()
+            .record(ctx.sequence_id, &outcome.decision);
+        outcome
+    }
+
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        self.inner.explain(ctx)
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for HistogramRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.inner.begin_sequence(seq_id)
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.inner.end_sequence(seq_id)
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.inner.active_sequences()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter};
+
+    #[test]
+    fn test_sequence_sketch_estimates_and_merges() {
+        let mut a = SequenceSketch::new(12);
+        let mut b = SequenceSketch::new(12);
+        (0..20_000).for_each(|s| a.insert(s));
+        (10_000..30_000).for_each(|s| b.insert(s));
+        let estimate = a.estimate();
+        assert!((19_000.0..21_000.0).contains(&estimate), "{}", estimate);
+
+        a.merge(&b).unwrap();
+        let merged = a.estimate();
+        assert!((28_500.0..31_500.0).contains(&merged), "{}", merged);
+        assert_eq!(
+            a.merge(&SequenceSketch::new(10)),
+            Err(SketchError::PrecisionMismatch {
+                left: 12,
+                right: 10
+            })
+        );
+    }
+
+    #[test]
+    fn test_worker_histograms_merge_through_json() {
+        let workers: Vec<HistogramRouter<DeterministicRouter>> = (0..2)
+            .map(|_| {
+                HistogramRouter::new(
+                    DeterministicRouter::new_unchecked(64),
+                    Arc::new(Mutex::new(HistogramSketch::new(12))),
+                )
+            })
+            .collect();
+        for seq in 0..100u64 {
+            let worker = &workers[(seq % 2) as usize];
+            let ctx = RoutingContext::new(Tier::Nano, seq % 4).with_sequence_id(seq);
+            worker.route_with_context(&ctx);
+            worker.route_with_context(&ctx);
+        }
+
+        let mut fleet = HistogramSketch::new(12);
+        for worker in &workers {
+            let shipped = HistogramSketch::from_json(&worker.take().to_json()).unwrap();
+            fleet.merge(&shipped).unwrap();
+        }
+        assert_eq!(fleet.decisions(), 200);
+        assert_eq!(fleet.count(&index_expert_id(0)), 50);
+        assert!((fleet.distinct_sequences(&index_expert_id(0)) - 25.0).abs() < 2.0);
+        assert!((fleet.total_distinct_sequences() - 100.0).abs() < 5.0);
+        assert_eq!(workers[0].histogram().lock().unwrap().decisions(), 0);
+    }
+
+    #[test]
+    fn test_malformed_sketches_are_rejected() {
+        let mut sketch: SequenceSketch =
+            serde_json::from_str(&serde_json::to_string(&SequenceSketch::new(4)).unwrap()).unwrap();
+        sketch.insert(1);
+        assert!(
+            serde_json::from_str::<SequenceSketch>(r#"{"precision":12,"registers":[0]}"#).is_err()
+        );
+        assert!(
+            serde_json::from_str::<SequenceSketch>(r#"{"precision":40,"registers":[]}"#).is_err()
+        );
+
+        let json = r#"{"precision":4,"decisions":0,"experts":[],"counts":[],
+            "expert_sequences":[],"sequences":{"precision":4,"registers":[0]}}"#;
+        assert!(matches!(
+            HistogramSketch::from_json(json),
+            Err(SketchError::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,54 @@
+// File: state.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Warm-state checkpointing for routers in AURIA Runtime Core.
+//     Captures mutable router state (round-robin cursors, canary progress)
+//     into a serializable RouterState that survives process restarts.
+//     Wrapper routers nest the state of the router they wrap under `inner`;
+//     routers holding several named routers key their states under `profiles`.
+//
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RouterState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub round_robin_cursor: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canary: Option<CanaryState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inner: Option<Box<RouterState>>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, RouterState>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CanaryState {
+    pub progress: u64,
+    pub aborted: bool,
+}
+
+impl RouterState {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn with_inner(mut self, inner: RouterState) -> Self {
+        if !inner.is_empty() {
+            self.inner = Some(Box::new(inner));
+        }
+        self
+    }
+
+    pub fn inner(&self) -> Option<&RouterState> {
+        self.inner.as_deref()
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+}
@@ -0,0 +1,328 @@
+// File: trace.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Tracing instrumentation for routing in AURIA Runtime Core (feature
+//     "tracing"). TracedRouter emits one structured event per route call
+//     (tier, token, chosen experts, selection latency) under a target and
+//     level chosen at runtime, so events nest inside the caller's spans.
+//
+use crate::{Router, RouterState, RoutingContext, RoutingOutcome, StatefulRouter};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+use std::time::Instant;
+use tracing::Level;
+use tracing_core::callsite::{Callsite, Identifier};
+use tracing_core::field::{FieldSet, Value};
+use tracing_core::metadata::Kind;
+use tracing_core::subscriber::Interest;
+use tracing_core::{Event, Metadata};
+
+pub const DEFAULT_TRACE_TARGET: &str = "auria_router::route";
+
+const FIELDS: &[&str] = &[
+    "router",
+    "tier",
+    "token_index",
+    "layer",
+    "experts",
+    "expert_count",
+    "latency_us",
+];
+
+const INTEREST_NEVER: u8 = 0;
+const INTEREST_SOMETIMES: u8 = 1;
+const INTEREST_ALWAYS: u8 = 2;
+
+// Tracing macros need a compile-time target, so the callsite for a runtime
+// target is built once per TracedRouter and intentionally leaked.
+struct RouteCallsite {
+    metadata: OnceLock<Metadata<'static>>,
+    interest: AtomicU8,
+}
+
+impl Callsite for RouteCallsite {
+    fn set_interest(&self, interest: Interest) {
+        let interest = if interest.is_never() {
+            INTEREST_NEVER
+        } else if interest.is_always() {
+            INTEREST_ALWAYS
+        } else {
+            INTEREST_SOMETIMES
+        };
+        self.interest.store(interest, Ordering::Relaxed);
+    }
+
+    fn metadata(&self) -> &Metadata<'_> {
+        self.metadata
+            .get()
+            .expect("route callsite metadata initialized")
+    }
+}
+
+fn new_callsite(target: &str, level: Level) -> &'static RouteCallsite {
+    let target: &'static str = Box::leak(target.to_owned().into_boxed_str());
+    let callsite: &'static RouteCallsite = Box::leak(Box::new(RouteCallsite {
+        metadata: OnceLock::new(),
+        interest: AtomicU8::new(INTEREST_SOMETIMES),
+    }));
+    let _ = callsite.metadata.set(Metadata::new(
+        "route",
+        target,
+        level,
+        Some(file!()),
+        Some(line!()),
+        Some(module_path!()),
+        FieldSet::new(FIELDS, Identifier(callsite)),
+        Kind::EVENT,
+    ));
+    tracing_core::callsite::register(callsite);
+    callsite
+}
+
+fn tier_name(tier: Tier) -> &'static str {
+    match tier {
+        Tier::Nano => "nano",
+        Tier::Standard => "standard",
+        Tier::Pro => "pro",
+        Tier::Max => "max",
+    }
+}
+
+pub struct TracedRouter<R: Router> {
+    inner: R,
+    name: String,
+    callsite: &'static RouteCallsite,
+}
+
+impl<R: Router> TracedRouter<R> {
+    pub fn new(inner: R, name: impl Into<String>) -> Self {
+        Self::with_target(inner, name, DEFAULT_TRACE_TARGET, Level::DEBUG)
+    }
+
+    pub fn with_target(inner: R, name: impl Into<String>, target: &str, level: Level) -> Self {
+        Self {
+            inner,
+            name: name.into(),
+            callsite: new_callsite(target, level),
+        }
+    }
+
+    pub fn target(&self) -> &str {
+        self.metadata().target()
+    }
+
+    pub fn level(&self) -> Level {
+        *self.metadata().level()
+    }
+
+    fn metadata(&self) -> &'static Metadata<'static> {
+        self.callsite
+            .metadata
+            .get()
+            .expect("route callsite metadata initialized")
+    }
+
+    fn enabled(&self) -> bool {
+        match self.callsite.interest.load(Ordering::Relaxed) {
+            INTEREST_NEVER => false,
+            _ => tracing_core::dispatcher::get_default(|d| d.enabled(self.metadata())),
+        }
+    }
+
+    fn traced<F>(&self, tier: Tier, token_index: u64, layer: u32, f: F) -> RoutingDecision
+    where
+        F: FnOnce() -> RoutingDecision,
+    {
+        if !self.enabled() {
+            return f();
+        }
+        let start = Instant::now();
+        let decision = f();
+        self.emit(tier, token_index, layer, &decision, start);
+        decision
+    }
+
+    fn emit(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        layer: u32,
+        decision: &RoutingDecision,
+        start: Instant,
+    ) {
+        let latency_us = start.elapsed().as_micros() as u64;
+        let meta = self.metadata();
+        let fields = meta.fields();
+        let field = |name: &str| fields.field(name).expect("route field registered");
+        let (router, tier_f, token_f, layer_f, experts_f, count_f, latency_f) = (
+            field("router"),
+            field("tier"),
+            field("token_index"),
+            field("layer"),
+            field("experts"),
+            field("expert_count"),
+            field("latency_us"),
+        );
+        let experts = tracing_core::field::debug(&decision.expert_ids);
+        let expert_count = decision.expert_ids.len() as u64;
+        let layer = layer as u64;
+        let values: [(&tracing_core::Field, Option<&dyn Value>); 7] = [
+            (&router, Some(&self.name.as_str() as &dyn Value)),
+            (&tier_f, Some(&tier_name(tier) as &dyn Value)),
+            (&token_f, Some(&token_index as &dyn Value)),
+            (&layer_f, Some(&layer as &dyn Value)),
+            (&experts_f, Some(&experts as &dyn Value)),
+            (&count_f, Some(&expert_count as &dyn Value)),
+            (&latency_f, Some(&latency_us as &dyn Value)),
+        ];
+        Event::dispatch(meta, &fields.value_set(&values));
+    }
+}
+
+impl<R: Router> Router for TracedRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.traced(tier, token_index, 0, || self.inner.route(tier, token_index))
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        self.traced(tier, token_index, 0, || {
+            self.inner.route_with_weights(tier, token_index, weights)
+        })
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.traced(ctx.tier, ctx.token_index, ctx.layer, || {
+            self.inner.route_with_context(ctx)
+        })
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        if !self.enabled() {
+            return self.inner.route_outcome(ctx);
+        }
+        let start = Instant::now();
+        let outcome = self.inner.route_outcome(ctx);
+        self.emit(
+            ctx.tier,
+            ctx.token_index,
+            ctx.layer,
+            &outcome.decision,
+            start,
+        );
+        outcome
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for TracedRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.inner.begin_sequence(seq_id)
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.inner.end_sequence(seq_id)
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.inner.active_sequences()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DeterministicRouter;
+    use std::sync::{Arc, Mutex};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event as TracingEvent, Metadata as TracingMetadata, Subscriber};
+
+    #[derive(Clone, Default)]
+    struct Capture {
+        events: Arc<Mutex<Vec<(String, Level, String)>>>,
+        max_level: Option<Level>,
+    }
+
+    struct FieldText(String);
+
+    impl tracing::field::Visit for FieldText {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0.push_str(&format!("{}={:?} ", field.name(), value));
+        }
+    }
+
+    impl Subscriber for Capture {
+        fn enabled(&self, metadata: &TracingMetadata<'_>) -> bool {
+            self.max_level.is_none_or(|max| *metadata.level() <= max)
+        }
+        fn new_span(&self, _span: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+        fn event(&self, event: &TracingEvent<'_>) {
+            let mut text = FieldText(String::new());
+            event.record(&mut text);
+            let meta = event.metadata();
+            self.events
+                .lock()
+                .unwrap()
+                .push((meta.target().to_string(), *meta.level(), text.0));
+        }
+        fn enter(&self, _span: &Id) {}
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[test]
+    fn test_traced_router_emits_event_with_target_and_level() {
+        let capture = Capture::default();
+        let router = TracedRouter::with_target(
+            DeterministicRouter::new_unchecked(64),
+            "primary",
+            "inference::routing",
+            Level::INFO,
+        );
+        tracing::subscriber::with_default(capture.clone(), || {
+            router.route_with_context(&RoutingContext::new(Tier::Nano, 9).with_layer(2));
+        });
+
+        let events = capture.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        let (target, level, fields) = &events[0];
+        assert_eq!(target, "inference::routing");
+        assert_eq!(*level, Level::INFO);
+        assert!(fields.contains("router=\"primary\""));
+        assert!(fields.contains("tier=\"nano\""));
+        assert!(fields.contains("token_index=9"));
+        assert!(fields.contains("layer=2"));
+        assert!(fields.contains("expert_count=2"));
+    }
+
+    #[test]
+    fn test_traced_router_respects_subscriber_level() {
+        let capture = Capture {
+            max_level: Some(Level::INFO),
+            ..Capture::default()
+        };
+        let router = TracedRouter::new(DeterministicRouter::new_unchecked(64), "quiet");
+        tracing::subscriber::with_default(capture.clone(), || {
+            router.route(Tier::Nano, 0);
+        });
+        assert!(capture.events.lock().unwrap().is_empty());
+        assert_eq!(router.level(), Level::DEBUG);
+        assert_eq!(router.target(), DEFAULT_TRACE_TARGET);
+    }
+}
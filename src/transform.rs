@@ -0,0 +1,340 @@
+// File: transform.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Decision post-processing hooks for AURIA Runtime Core.
+//     DecisionTransforms run after the base router has chosen experts and
+//     can dedupe, reorder by device locality, append a mandatory shared
+//     expert, or truncate to k, keeping scores aligned with expert IDs.
+//
+use crate::{
+    decision_entries, decision_from_entries, tier_k, DropReason, Router, RouterState,
+    RoutingContext, RoutingExplanation, RoutingOutcome, StatefulRouter,
+};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::{HashMap, HashSet};
+
+pub trait DecisionTransform: Send + Sync {
+    fn transform(&self, ctx: &RoutingContext, decision: RoutingDecision) -> RoutingDecision;
+
+    // Transforms that add or remove experts override this to keep the
+    // outcome's k, shared count and drops in step with the decision.
+    fn transform_outcome(
+        &self,
+        ctx: &RoutingContext,
+        mut outcome: RoutingOutcome,
+    ) -> RoutingOutcome {
+        outcome.decision = self.transform(ctx, outcome.decision);
+        outcome
+    }
+}
+
+impl<F> DecisionTransform for F
+where
+    F: Fn(&RoutingContext, RoutingDecision) -> RoutingDecision + Send + Sync,
+{
+    fn transform(&self, ctx: &RoutingContext, decision: RoutingDecision) -> RoutingDecision {
+        self(ctx, decision)
+    }
+}
+
+// Keeps the first occurrence of each expert.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Dedupe;
+
+impl DecisionTransform for Dedupe {
+    fn transform(&self, _ctx: &RoutingContext, decision: RoutingDecision) -> RoutingDecision {
+        let timestamp = decision.timestamp;
+        let mut seen = HashSet::new();
+        let entries = decision_entries(decision)
+            .into_iter()
+            .filter(|(id, _, _)| seen.insert(id.clone()))
+            .collect();
+        decision_from_entries(entries, timestamp)
+    }
+
+    fn transform_outcome(
+        &self,
+        _ctx: &RoutingContext,
+        mut outcome: RoutingOutcome,
+    ) -> RoutingOutcome {
+        let timestamp = outcome.decision.timestamp;
+        let shared = outcome.shared_count as usize;
+        let mut seen = HashSet::new();
+        let mut kept = Vec::new();
+        for (i, entry) in decision_entries(outcome.decision.clone())
+            .into_iter()
+            .enumerate()
+        {
+            if seen.insert(entry.0.clone()) {
+                kept.push(entry);
+            } else {
+                if i < shared {
+                    outcome.shared_count -= 1;
+                }
+                outcome.record_drop(entry.0, DropReason::Duplicate);
+            }
+        }
+        outcome.decision = decision_from_entries(kept, timestamp);
+        outcome
+    }
+}
+
+// Truncates to a fixed count, or when unset to the k the router asked for
+// (the tier's default k for bare decisions, which carry no requested k).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TruncateToK(pub Option<u32>);
+
+impl TruncateToK {
+    fn truncate(decision: RoutingDecision, k: u32) -> RoutingDecision {
+        let timestamp = decision.timestamp;
+        let mut entries = decision_entries(decision);
+        entries.truncate(k as usize);
+        decision_from_entries(entries, timestamp)
+    }
+}
+
+impl DecisionTransform for TruncateToK {
+    fn transform(&self, ctx: &RoutingContext, decision: RoutingDecision) -> RoutingDecision {
+        Self::truncate(decision, self.0.unwrap_or_else(|| tier_k(ctx.tier)))
+    }
+
+    // A fixed count is deliberate, so it lowers the requested k rather than
+    // leaving a shortfall.
+    fn transform_outcome(
+        &self,
+        _ctx: &RoutingContext,
+        mut outcome: RoutingOutcome,
+    ) -> RoutingOutcome {
+        let k = self.0.unwrap_or(outcome.requested_k);
+        outcome.requested_k = outcome.requested_k.min(k);
+        outcome.shared_count = outcome.shared_count.min(k);
+        outcome.decision = Self::truncate(outcome.decision, k);
+        outcome
+    }
+}
+
+// Adds an always-active shared expert unless it was already selected. It
+// joins the leading shared block (after any shared experts already there),
+// so routed_experts() and shared_count stay correct.
+#[derive(Clone, Debug)]
+pub struct AppendSharedExpert {
+    pub expert_id: ExpertId,
+    pub weight: f32,
+}
+
+impl AppendSharedExpert {
+    pub fn new(expert_id: ExpertId) -> Self {
+        Self {
+            expert_id,
+            weight: 1.0,
+        }
+    }
+}
+
+impl DecisionTransform for AppendSharedExpert {
+    fn transform(&self, ctx: &RoutingContext, decision: RoutingDecision) -> RoutingDecision {
+        self.transform_outcome(ctx, RoutingOutcome::new(decision, 0))
+            .decision
+    }
+
+    fn transform_outcome(
+        &self,
+        _ctx: &RoutingContext,
+        mut outcome: RoutingOutcome,
+    ) -> RoutingOutcome {
+        if outcome.decision.expert_ids.contains(&self.expert_id) {
+            return outcome;
+        }
+        let timestamp = outcome.decision.timestamp;
+        let shared = (outcome.shared_count as usize).min(outcome.decision.expert_ids.len());
+        let mut entries = decision_entries(outcome.decision);
+        entries.insert(shared, (self.expert_id.clone(), 1.0, self.weight));
+        outcome.decision = decision_from_entries(entries, timestamp);
+        outcome.shared_count = shared as u32 + 1;
+        outcome.requested_k += 1;
+        outcome
+    }
+}
+
+// Stable reorder by device rank; experts without a rank go last.
+#[derive(Clone, Debug, Default)]
+pub struct ReorderByLocality {
+    pub device_rank: HashMap<ExpertId, u32>,
+}
+
+impl ReorderByLocality {
+    pub fn new(device_rank: HashMap<ExpertId, u32>) -> Self {
+        Self { device_rank }
+    }
+}
+
+impl DecisionTransform for ReorderByLocality {
+    fn transform(&self, _ctx: &RoutingContext, decision: RoutingDecision) -> RoutingDecision {
+        let timestamp = decision.timestamp;
+        let mut entries = decision_entries(decision);
+        entries.sort_by_key(|(id, _, _)| self.device_rank.get(id).copied().unwrap_or(u32::MAX));
+        decision_from_entries(entries, timestamp)
+    }
+}
+
+pub struct TransformedRouter<R: Router> {
+    inner: R,
+    transforms: Vec<Box<dyn DecisionTransform>>,
+}
+
+impl<R: Router> TransformedRouter<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            transforms: Vec::new(),
+        }
+    }
+
+    pub fn with_transform(mut self, transform: impl DecisionTransform + 'static) -> Self {
+        self.transforms.push(Box::new(transform));
+        self
+    }
+
+    fn apply(&self, ctx: &RoutingContext, outcome: RoutingOutcome) -> RoutingOutcome {
+        self.transforms
+            .iter()
+            .fold(outcome, |outcome, t| t.transform_outcome(ctx, outcome))
+    }
+}
+
+// Every path goes through the inner outcome, so transforms see the k the
+// inner router asked for.
+impl<R: Router> Router for TransformedRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.route_with_context(&RoutingContext::new(tier, token_index))
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        self.route_outcome_with_weights(&RoutingContext::new(tier, token_index), weights)
+            .decision
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.route_outcome(ctx).decision
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        self.apply(ctx, self.inner.route_outcome(ctx))
+    }
+
+    fn route_outcome_with_weights(
+        &self,
+        ctx: &RoutingContext,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingOutcome {
+        self.apply(ctx, self.inner.route_outcome_with_weights(ctx, weights))
+    }
+
+    fn explain(&self, ctx: &RoutingContext) -> RoutingExplanation {
+        let explanation = self.inner.explain(ctx);
+        let outcome = self.apply(ctx, explanation.outcome.clone());
+        explanation.layer("transform", outcome)
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for TransformedRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.inner.begin_sequence(seq_id)
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.inner.end_sequence(seq_id)
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.inner.active_sequences()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter, RouterConfig, TierKTable, ValidatingRouter};
+
+    #[test]
+    fn test_transform_pipeline() {
+        let shared = ExpertId([0xFF; 32]);
+        let locality: HashMap<ExpertId, u32> = [(index_expert_id(1), 0), (index_expert_id(0), 1)]
+            .into_iter()
+            .collect();
+        let router = TransformedRouter::new(DeterministicRouter::new_unchecked(2))
+            .with_transform(|_: &RoutingContext, mut d: RoutingDecision| {
+                d.expert_ids.push(d.expert_ids[0].clone());
+                d.confidence_scores.push(1.0);
+                d.gating_weights.push(1.0);
+                d
+            })
+            .with_transform(Dedupe)
+            .with_transform(ReorderByLocality::new(locality))
+            .with_transform(AppendSharedExpert::new(shared.clone()));
+
+        let decision = router.route(Tier::Standard, 0);
+        assert_eq!(
+            decision.expert_ids,
+            vec![shared, index_expert_id(1), index_expert_id(0)]
+        );
+        assert_eq!(decision.gating_weights.len(), 3);
+    }
+
+    #[test]
+    fn test_truncate_and_closure_transforms() {
+        let router = TransformedRouter::new(DeterministicRouter::new_unchecked(64))
+            .with_transform(|_: &RoutingContext, mut d: RoutingDecision| {
+                d.gating_weights.iter_mut().for_each(|w| *w *= 0.5);
+                d
+            })
+            .with_transform(TruncateToK(Some(3)));
+
+        let decision = router.route(Tier::Max, 0);
+        assert_eq!(decision.expert_ids.len(), 3);
+        assert!(decision.gating_weights.iter().all(|&w| w == 0.5));
+    }
+
+    #[test]
+    fn test_transforms_keep_outcome_metadata() {
+        let shared = ExpertId([0xFF; 32]);
+        let inner = DeterministicRouter::new_unchecked(64).with_k_selector(TierKTable {
+            nano: 3,
+            standard: 4,
+            pro: 8,
+            max: 16,
+        });
+        let router = ValidatingRouter::new(
+            TransformedRouter::new(inner)
+                .with_transform(|_: &RoutingContext, mut d: RoutingDecision| {
+                    d.expert_ids[1] = d.expert_ids[0].clone();
+                    d
+                })
+                .with_transform(Dedupe)
+                .with_transform(TruncateToK(None))
+                .with_transform(AppendSharedExpert::new(shared.clone())),
+            RouterConfig::new().allow_fewer_than_k(true),
+        );
+        let outcome = router.route_outcome(&RoutingContext::new(Tier::Nano, 0));
+        assert_eq!(outcome.requested_k, 4);
+        assert_eq!(outcome.shared_count, 1);
+        assert_eq!(outcome.decision.expert_ids.len(), 3);
+        assert_eq!(outcome.decision.expert_ids[0], shared);
+        assert_eq!(outcome.dropped.len(), 1);
+        assert_eq!(outcome.dropped[0].reason, DropReason::Duplicate);
+        assert_eq!(router.violation_count(), 0);
+    }
+}
@@ -0,0 +1,351 @@
+// File: validate.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Routing invariant validation for AURIA Runtime Core.
+//     Checks decisions for duplicate experts, tier-k count mismatches,
+//     unregistered or masked experts, and inconsistent score vectors,
+//     and provides a ValidatingRouter debug wrapper that applies the checks.
+//
+use crate::{tier_k, Router, RouterState, RoutingContext, RoutingOutcome, StatefulRouter};
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+#[derive(Clone, Debug, Default)]
+pub struct RouterConfig {
+    pub registered_experts: Option<HashSet<ExpertId>>,
+    pub masked_experts: HashSet<ExpertId>,
+    pub allow_fewer_than_k: bool,
+}
+
+impl RouterConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_registered(mut self, experts: impl IntoIterator<Item = ExpertId>) -> Self {
+        self.registered_experts = Some(experts.into_iter().collect());
+        self
+    }
+
+    pub fn with_masked(mut self, experts: impl IntoIterator<Item = ExpertId>) -> Self {
+        self.masked_experts = experts.into_iter().collect();
+        self
+    }
+
+    pub fn allow_fewer_than_k(mut self, allow: bool) -> Self {
+        self.allow_fewer_than_k = allow;
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Violation {
+    DuplicateExpert {
+        expert_id: ExpertId,
+        first: usize,
+        second: usize,
+    },
+    CountMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    UnregisteredExpert(ExpertId),
+    MaskedExpert(ExpertId),
+    ScoreLengthMismatch {
+        experts: usize,
+        confidence_scores: usize,
+        gating_weights: usize,
+    },
+}
+
+pub fn validate_decision(
+    decision: &RoutingDecision,
+    config: &RouterConfig,
+) -> Result<(), Vec<Violation>> {
+    let mut violations = Vec::new();
+
+    let mut seen: HashMap<&ExpertId, usize> = HashMap::new();
+    for (i, id) in decision.expert_ids.iter().enumerate() {
+        if let Some(&first) = seen.get(id) {
+            violations.push(Violation::DuplicateExpert {
+                expert_id: id.clone(),
+                first,
+                second: i,
+            });
+        } else {
+            seen.insert(id, i);
+        }
+
+        if let Some(registered) = &config.registered_experts {
+            if !registered.contains(id) {
+                violations.push(Violation::UnregisteredExpert(id.clone()));
+            }
+        }
+        if config.masked_experts.contains(id) {
+            violations.push(Violation::MaskedExpert(id.clone()));
+        }
+    }
+
+    let experts = decision.expert_ids.len();
+    if decision.confidence_scores.len() != experts || decision.gating_weights.len() != experts {
+        violations.push(Violation::ScoreLengthMismatch {
+            experts,
+            confidence_scores: decision.confidence_scores.len(),
+            gating_weights: decision.gating_weights.len(),
+        });
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+// Expected count is the tier's k from the default table, capped by the
+// number of routable experts when the registry is known. Routers with their
+// own KSelector or revision tiers are checked with validate_outcome.
+pub fn validate_decision_for_tier(
+    decision: &RoutingDecision,
+    tier: Tier,
+    config: &RouterConfig,
+) -> Result<(), Vec<Violation>> {
+    validate_with_k(decision, tier_k(tier) as usize, config)
+}
+
+// As validate_decision_for_tier, with the k the router reported asking for.
+pub fn validate_outcome(
+    outcome: &RoutingOutcome,
+    config: &RouterConfig,
+) -> Result<(), Vec<Violation>> {
+    validate_with_k(&outcome.decision, outcome.requested_k as usize, config)
+}
+
+fn validate_with_k(
+    decision: &RoutingDecision,
+    k: usize,
+    config: &RouterConfig,
+) -> Result<(), Vec<Violation>> {
+    let mut violations = validate_decision(decision, config)
+        .err()
+        .unwrap_or_default();
+
+    let mut expected = k;
+    if let Some(registered) = &config.registered_experts {
+        let routable = registered
+            .iter()
+            .filter(|id| !config.masked_experts.contains(id))
+            .count();
+        expected = expected.min(routable);
+    }
+    let actual = decision.expert_ids.len();
+    if actual > expected || (actual < expected && !config.allow_fewer_than_k) {
+        violations.push(Violation::CountMismatch { expected, actual });
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+pub struct ValidatingRouter<R: Router> {
+    inner: R,
+    config: RouterConfig,
+    panic_on_violation: bool,
+    violation_count: AtomicU64,
+    last_violations: Mutex<Vec<Violation>>,
+}
+
+impl<R: Router> ValidatingRouter<R> {
+    pub fn new(inner: R, config: RouterConfig) -> Self {
+        Self {
+            inner,
+            config,
+            panic_on_violation: true,
+            violation_count: AtomicU64::new(0),
+            last_violations: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn with_panic_on_violation(mut self, panic_on_violation: bool) -> Self {
+        self.panic_on_violation = panic_on_violation;
+        self
+    }
+
+    pub fn violation_count(&self) -> u64 {
+        self.violation_count.load(Ordering::Relaxed)
+    }
+
+    pub fn last_violations(&self) -> Vec<Violation> {
+        self.last_violations.lock().unwrap().clone()
+    }
+
+    fn check(&self, ctx: &RoutingContext, outcome: RoutingOutcome) -> RoutingOutcome {
+        if let Err(violations) = validate_outcome(&outcome, &self.config) {
+            if self.panic_on_violation {
+                panic!(
+                    "routing invariant violated for {:?} token {}: {:?}",
+                    ctx.tier, ctx.token_index, violations
+                );
+            }
+            self.violation_count.fetch_add(1, Ordering::Relaxed);
+            *self.last_violations.lock().unwrap() = violations;
+        }
+        outcome
+    }
+}
+
+// Every path goes through the inner outcome, so the count check uses the
+// k the inner router was configured with.
+impl<R: Router> Router for ValidatingRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.route_with_context(&RoutingContext::new(tier, token_index))
+    }
+
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        self.route_outcome_with_weights(&RoutingContext::new(tier, token_index), weights)
+            .decision
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.route_outcome(ctx).decision
+    }
+
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        self.check(ctx, self.inner.route_outcome(ctx))
+    }
+
+    fn route_outcome_with_weights(
+        &self,
+        ctx: &RoutingContext,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingOutcome {
+        self.check(ctx, self.inner.route_outcome_with_weights(ctx, weights))
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for ValidatingRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        self.inner.begin_sequence(seq_id)
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.inner.end_sequence(seq_id)
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.inner.active_sequences()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        index_expert_id, DeterministicRouter, RoutingContext, StickyRouter, TierKTable,
+        TransformedRouter,
+    };
+
+    fn duplicating_router() -> TransformedRouter<DeterministicRouter> {
+        TransformedRouter::new(DeterministicRouter::new_unchecked(64)).with_transform(
+            |_: &RoutingContext, mut decision: RoutingDecision| {
+                decision.expert_ids[1] = decision.expert_ids[0].clone();
+                decision
+            },
+        )
+    }
+
+    #[test]
+    fn test_validate_decision_reports_violations() {
+        let decision = RoutingDecision {
+            expert_ids: vec![index_expert_id(1), index_expert_id(2), index_expert_id(1)],
+            confidence_scores: vec![1.0; 3],
+            gating_weights: vec![1.0; 2],
+            timestamp: 0,
+        };
+        let config = RouterConfig::new()
+            .with_registered((0..2).map(index_expert_id))
+            .with_masked([index_expert_id(0)]);
+
+        let violations = validate_decision_for_tier(&decision, Tier::Nano, &config).unwrap_err();
+        assert!(violations.contains(&Violation::DuplicateExpert {
+            expert_id: index_expert_id(1),
+            first: 0,
+            second: 2,
+        }));
+        assert!(violations.contains(&Violation::UnregisteredExpert(index_expert_id(2))));
+        assert!(violations.contains(&Violation::CountMismatch {
+            expected: 1,
+            actual: 3
+        }));
+    }
+
+    #[test]
+    fn test_validating_router_records_violations() {
+        let config = RouterConfig::new().with_registered((0..64).map(index_expert_id));
+        let router = ValidatingRouter::new(DeterministicRouter::new_unchecked(64), config);
+        router.route(Tier::Max, 10);
+
+        let router = ValidatingRouter::new(duplicating_router(), RouterConfig::new())
+            .with_panic_on_violation(false);
+        router.route(Tier::Standard, 0);
+        assert_eq!(router.violation_count(), 1);
+        assert!(matches!(
+            router.last_violations()[0],
+            Violation::DuplicateExpert { .. }
+        ));
+    }
+
+    #[test]
+    fn test_validating_router_uses_router_k() {
+        let table = TierKTable {
+            nano: 4,
+            standard: 1,
+            pro: 6,
+            max: 12,
+        };
+        let router = ValidatingRouter::new(
+            DeterministicRouter::new_unchecked(64).with_k_selector(table),
+            RouterConfig::new(),
+        );
+        assert_eq!(router.route(Tier::Nano, 0).expert_ids.len(), 4);
+        assert_eq!(router.route(Tier::Standard, 0).expert_ids.len(), 1);
+        assert_eq!(router.violation_count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "routing invariant violated")]
+    fn test_validating_router_panics_by_default() {
+        let router = ValidatingRouter::new(duplicating_router(), RouterConfig::new());
+        router.route(Tier::Standard, 0);
+    }
+
+    #[test]
+    fn test_sequence_hooks_reach_sticky_router() {
+        let router = ValidatingRouter::new(
+            TransformedRouter::new(StickyRouter::new(DeterministicRouter::new_unchecked(64))),
+            RouterConfig::new(),
+        );
+        (0..3).for_each(|seq| router.begin_sequence(seq));
+        assert_eq!(router.active_sequences(), 3);
+        (0..3).for_each(|seq| router.end_sequence(seq));
+        assert_eq!(router.active_sequences(), 0);
+    }
+}
@@ -0,0 +1,353 @@
+// File: versioned.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Versioned gate-weight snapshots for AURIA Runtime Core.
+//     Weight sets are published under a version and activated with a
+//     single atomic pointer swap, so readers never take a lock. A sequence
+//     can pin the version it started with and keep routing against it
+//     until it ends, even if a newer version is activated mid-generation.
+//
+use crate::{
+    Router, RouterState, RoutingContext, RoutingOutcome, StatefulRouter, WeightVersionError,
+};
+use arc_swap::ArcSwap;
+use auria_core::{ExpertId, RoutingDecision, Tier};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WeightSnapshot {
+    pub version: u64,
+    pub weights: HashMap<ExpertId, f32>,
+}
+
+pub struct VersionedWeights {
+    active: ArcSwap<WeightSnapshot>,
+    // Only publishers and activators touch this; routing reads `active`.
+    published: Mutex<BTreeMap<u64, Arc<WeightSnapshot>>>,
+}
+
+impl VersionedWeights {
+    pub fn new(version: u64, weights: HashMap<ExpertId, f32>) -> Self {
+        let initial = Arc::new(WeightSnapshot { version, weights });
+        Self {
+            active: ArcSwap::new(initial.clone()),
+            published: Mutex::new(BTreeMap::from([(version, initial)])),
+        }
+    }
+
+    pub fn publish_weights(
+        &self,
+        version: u64,
+        weights: HashMap<ExpertId, f32>,
+    ) -> Result<(), WeightVersionError> {
+        let mut published = self.published.lock().unwrap();
+        if published.contains_key(&version) {
+            return Err(WeightVersionError::VersionExists(version));
+        }
+        published.insert(version, Arc::new(WeightSnapshot { version, weights }));
+        Ok(())
+    }
+
+    pub fn activate_version(&self, version: u64) -> Result<(), WeightVersionError> {
+        let published = self.published.lock().unwrap();
+        let snapshot = published
+            .get(&version)
+            .ok_or(WeightVersionError::UnknownVersion(version))?;
+        self.active.store(snapshot.clone());
+        Ok(())
+    }
+
+    // Drops a published version. Sequences already pinned to it keep their
+    // own reference; the active version cannot be retired.
+    pub fn retire_version(&self, version: u64) -> Result<(), WeightVersionError> {
+        if self.active_version() == version {
+            return Err(WeightVersionError::VersionActive(version));
+        }
+        self.published
+            .lock()
+            .unwrap()
+            .remove(&version)
+            .map(|_| ())
+            .ok_or(WeightVersionError::UnknownVersion(version))
+    }
+
+    pub fn active(&self) -> Arc<WeightSnapshot> {
+        self.active.load_full()
+    }
+
+    pub fn active_version(&self) -> u64 {
+        self.active.load().version
+    }
+
+    pub fn versions(&self) -> Vec<u64> {
+        self.published.lock().unwrap().keys().copied().collect()
+    }
+}
+
+// Handle for a sequence pinned to the weights active when it started.
+#[derive(Clone, Debug)]
+pub struct SequencePin {
+    snapshot: Arc<WeightSnapshot>,
+}
+
+impl SequencePin {
+    pub fn version(&self) -> u64 {
+        self.snapshot.version
+    }
+}
+
+pub struct VersionedRouter<R: Router> {
+    inner: R,
+    weights: VersionedWeights,
+    // Pins taken by begin_sequence, keyed by RoutingContext::sequence_id.
+    pins: Mutex<HashMap<u64, SequencePin>>,
+}
+
+impl<R: Router> VersionedRouter<R> {
+    pub fn new(inner: R, version: u64, weights: HashMap<ExpertId, f32>) -> Self {
+        Self {
+            inner,
+            weights: VersionedWeights::new(version, weights),
+            pins: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn weights(&self) -> &VersionedWeights {
+        &self.weights
+    }
+
+    pub fn publish_weights(
+        &self,
+        version: u64,
+        weights: HashMap<ExpertId, f32>,
+    ) -> Result<(), WeightVersionError> {
+        self.weights.publish_weights(version, weights)
+    }
+
+    pub fn activate_version(&self, version: u64) -> Result<(), WeightVersionError> {
+        self.weights.activate_version(version)
+    }
+
+    // A pin on the active version for callers that track sequences
+    // themselves; StatefulRouter::begin_sequence keeps one per sequence id.
+    pub fn pin_active(&self) -> SequencePin {
+        SequencePin {
+            snapshot: self.weights.active(),
+        }
+    }
+
+    pub fn sequence_pin(&self, seq_id: u64) -> Option<SequencePin> {
+        self.pins.lock().unwrap().get(&seq_id).cloned()
+    }
+
+    pub fn route_pinned(&self, pin: &SequencePin, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.route_pinned_outcome(pin, &RoutingContext::new(tier, token_index))
+            .decision
+    }
+
+    pub fn route_pinned_outcome(&self, pin: &SequencePin, ctx: &RoutingContext) -> RoutingOutcome {
+        self.inner
+            .route_outcome_with_weights(ctx, &pin.snapshot.weights)
+    }
+}
+
+impl<R: Router> Router for VersionedRouter<R> {
+    fn route(&self, tier: Tier, token_index: u64) -> RoutingDecision {
+        self.route_with_context(&RoutingContext::new(tier, token_index))
+    }
+
+    // Explicit weights bypass the versioned set.
+    fn route_with_weights(
+        &self,
+        tier: Tier,
+        token_index: u64,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingDecision {
+        self.inner.route_with_weights(tier, token_index, weights)
+    }
+
+    fn route_with_context(&self, ctx: &RoutingContext) -> RoutingDecision {
+        self.route_outcome(ctx).decision
+    }
+
+    // Contexts of a begun sequence route against its pinned version.
+    fn route_outcome(&self, ctx: &RoutingContext) -> RoutingOutcome {
+        if let Some(pin) = ctx.sequence_id.and_then(|seq_id| self.sequence_pin(seq_id)) {
+            return self.route_pinned_outcome(&pin, ctx);
+        }
+        let snapshot = self.weights.active.load();
+        self.inner
+            .route_outcome_with_weights(ctx, &snapshot.weights)
+    }
+
+    fn route_outcome_with_weights(
+        &self,
+        ctx: &RoutingContext,
+        weights: &HashMap<ExpertId, f32>,
+    ) -> RoutingOutcome {
+        self.inner.route_outcome_with_weights(ctx, weights)
+    }
+
+    fn snapshot(&self) -> RouterState {
+        self.inner.snapshot()
+    }
+
+    fn restore(&self, state: &RouterState) {
+        self.inner.restore(state)
+    }
+}
+
+impl<R: StatefulRouter> StatefulRouter for VersionedRouter<R> {
+    fn begin_sequence(&self, seq_id: u64) {
+        let pin = self.pin_active();
+        self.pins.lock().unwrap().insert(seq_id, pin);
+        self.inner.begin_sequence(seq_id);
+    }
+
+    fn end_sequence(&self, seq_id: u64) {
+        self.pins.lock().unwrap().remove(&seq_id);
+        self.inner.end_sequence(seq_id);
+    }
+
+    fn active_sequences(&self) -> usize {
+        self.pins.lock().unwrap().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        index_expert_id, DeterministicRouter, GatingRouter, PinMode, PinRule, PinnedRouter,
+        PinningPolicy, RoundRobinRouter, StickyRouter,
+    };
+
+    fn favouring(expert: u32) -> HashMap<ExpertId, f32> {
+        (0..8)
+            .map(|i| (index_expert_id(i), if i == expert { 1.0 } else { 0.0 }))
+            .collect()
+    }
+
+    #[test]
+    fn test_pinned_sequence_survives_activation() {
+        let router = VersionedRouter::new(DeterministicRouter::new_unchecked(8), 1, favouring(3));
+        let pin = router.pin_active();
+
+        router.publish_weights(2, favouring(5)).unwrap();
+        assert_eq!(
+            router.route(Tier::Nano, 0).expert_ids[0],
+            index_expert_id(3)
+        );
+        router.activate_version(2).unwrap();
+
+        assert_eq!(
+            router.route(Tier::Nano, 0).expert_ids[0],
+            index_expert_id(5)
+        );
+        assert_eq!(pin.version(), 1);
+        assert_eq!(
+            router.route_pinned(&pin, Tier::Nano, 0).expert_ids[0],
+            index_expert_id(3)
+        );
+        assert_eq!(router.pin_active().version(), 2);
+    }
+
+    #[test]
+    fn test_versions_drive_gating_router() {
+        let mut gate = GatingRouter::new_unchecked(1.0);
+        gate.set_gate_weights(favouring(7));
+        let router = VersionedRouter::new(gate, 1, favouring(3));
+        let pin = router.pin_active();
+        let ctx = RoutingContext::new(Tier::Nano, 0).with_layer(2);
+        assert_eq!(
+            router.route_with_context(&ctx).expert_ids[0],
+            index_expert_id(3)
+        );
+
+        router.publish_weights(2, favouring(5)).unwrap();
+        router.activate_version(2).unwrap();
+        let outcome = router.route_outcome(&ctx);
+        assert_eq!(outcome.decision.expert_ids[0], index_expert_id(5));
+        assert_eq!(outcome.requested_k, 2);
+        assert_eq!(
+            router.route_pinned_outcome(&pin, &ctx).decision.expert_ids[0],
+            index_expert_id(3)
+        );
+    }
+
+    #[test]
+    fn test_context_reaches_inner_router_once() {
+        let experts: Vec<ExpertId> = (0..8).map(index_expert_id).collect();
+        let policy = PinningPolicy::new().with_rule(
+            PinRule::new(vec![ExpertId([0xEE; 32])], PinMode::Exclusive).for_layers(1..2),
+        );
+        let router = VersionedRouter::new(
+            PinnedRouter::new(RoundRobinRouter::new_unchecked(experts), policy),
+            1,
+            favouring(3),
+        );
+        let pinned = router.route_with_context(&RoutingContext::new(Tier::Nano, 0).with_layer(1));
+        assert_eq!(pinned.expert_ids, vec![ExpertId([0xEE; 32])]);
+
+        // Each outcome advances the round-robin cursor exactly once.
+        let ctx = RoutingContext::new(Tier::Nano, 0);
+        let first = router.route_outcome(&ctx).decision.expert_ids;
+        let second = router.route_outcome(&ctx).decision.expert_ids;
+        assert_eq!(first[0], index_expert_id(0));
+        assert_eq!(second[0], index_expert_id(1));
+    }
+
+    #[test]
+    fn test_version_errors() {
+        let weights = VersionedWeights::new(1, favouring(0));
+        assert_eq!(
+            weights.publish_weights(1, favouring(1)),
+            Err(WeightVersionError::VersionExists(1))
+        );
+        assert_eq!(
+            weights.activate_version(7),
+            Err(WeightVersionError::UnknownVersion(7))
+        );
+        assert_eq!(
+            weights.retire_version(1),
+            Err(WeightVersionError::VersionActive(1))
+        );
+        weights.publish_weights(2, favouring(1)).unwrap();
+        weights.activate_version(2).unwrap();
+        weights.retire_version(1).unwrap();
+        assert_eq!(weights.versions(), vec![2]);
+    }
+
+    #[test]
+    fn test_sequences_pin_by_id() {
+        let router = VersionedRouter::new(
+            StickyRouter::new(DeterministicRouter::new_unchecked(8)),
+            1,
+            favouring(3),
+        );
+        router.begin_sequence(7);
+        router.publish_weights(2, favouring(5)).unwrap();
+        router.activate_version(2).unwrap();
+
+        let pinned = RoutingContext::new(Tier::Nano, 0).with_sequence_id(7);
+        assert_eq!(
+            router.route_with_context(&pinned).expert_ids[0],
+            index_expert_id(3)
+        );
+        assert_eq!(
+            router.route(Tier::Nano, 0).expert_ids[0],
+            index_expert_id(5)
+        );
+        assert_eq!(router.sequence_pin(7).unwrap().version(), 1);
+        assert_eq!(router.active_sequences(), 1);
+
+        router.end_sequence(7);
+        assert!(router.sequence_pin(7).is_none());
+        assert_eq!(router.active_sequences(), 0);
+        assert_eq!(
+            router.route_with_context(&pinned).expert_ids[0],
+            index_expert_id(5)
+        );
+    }
+}
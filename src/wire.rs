@@ -0,0 +1,329 @@
+// File: wire.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Compact binary wire format for routing decisions in AURIA Runtime Core.
+//     Expert IDs are sent as dense indices into a shared ExpertIndexMap
+//     registry (varint lists, or a bitset when that is smaller) behind a
+//     versioned header, instead of 32 raw bytes per expert per token.
+//
+use crate::{ExpertIndexMap, WireError};
+use auria_core::RoutingDecision;
+
+pub const WIRE_MAGIC: [u8; 4] = *b"ARWD";
+pub const WIRE_VERSION: u8 = 1;
+
+// Per-decision flags.
+const BITSET: u8 = 1;
+const UNIT_WEIGHTS: u8 = 1 << 1;
+const CONFIDENCE_IS_WEIGHT: u8 = 1 << 2;
+
+// Layout: magic [u8; 4] | version u8 | registry_len varint | count varint |
+// count * decision. A decision is: flags u8 | timestamp delta (zigzag varint
+// against the previous decision) | expert count varint | experts (varint
+// indices, or a registry_len-bit bitset when indices ascend) | gating
+// weights f32 LE unless all 1.0 | confidences f32 LE unless equal to weights.
+pub fn encode_decisions(
+    registry: &ExpertIndexMap,
+    decisions: &[RoutingDecision],
+) -> Result<Vec<u8>, WireError> {
+    let mut buf = Vec::with_capacity(8 + decisions.len() * 8);
+    buf.extend_from_slice(&WIRE_MAGIC);
+    buf.push(WIRE_VERSION);
+    put_varint(&mut buf, registry.len() as u64);
+    put_varint(&mut buf, decisions.len() as u64);
+
+    let bitset_len = registry.len().div_ceil(8);
+    let mut previous = 0u64;
+    for decision in decisions {
+        let indices = decision
+            .expert_ids
+            .iter()
+            .map(|id| {
+                registry
+                    .index_of(id)
+                    .ok_or_else(|| WireError::UnknownExpert(id.clone()))
+            })
+            .collect::<Result<Vec<u32>, _>>()?;
+        let weights = padded(&decision.gating_weights, indices.len());
+        let confidences = padded(&decision.confidence_scores, indices.len());
+
+        let list_len: usize = indices.iter().map(|&i| varint_len(i as u64)).sum();
+        let ascending = indices.windows(2).all(|w| w[0] < w[1]);
+        let mut flags = 0;
+        if ascending && !indices.is_empty() && bitset_len < list_len {
+            flags |= BITSET;
+        }
+        if weights.iter().all(|&w| w == 1.0) {
+            flags |= UNIT_WEIGHTS;
+        }
+        if confidences == weights {
+            flags |= CONFIDENCE_IS_WEIGHT;
+        }
+
+        buf.push(flags);
+        put_varint(
+            &mut buf,
+            zigzag(decision.timestamp.wrapping_sub(previous) as i64),
+        );
+        previous = decision.timestamp;
+        put_varint(&mut buf, indices.len() as u64);
+        if flags & BITSET != 0 {
+            let start = buf.len();
+            buf.resize(start + bitset_len, 0);
+            for &i in &indices {
+                buf[start + i as usize / 8] |= 1 << (i % 8);
+            }
+        } else {
+            for &i in &indices {
+                put_varint(&mut buf, i as u64);
+            }
+        }
+        if flags & UNIT_WEIGHTS == 0 {
+            weights
+                .iter()
+                .for_each(|w| buf.extend_from_slice(&w.to_le_bytes()));
+        }
+        if flags & CONFIDENCE_IS_WEIGHT == 0 {
+            confidences
+                .iter()
+                .for_each(|c| buf.extend_from_slice(&c.to_le_bytes()));
+        }
+    }
+    Ok(buf)
+}
+
+pub fn decode_decisions(
+    registry: &ExpertIndexMap,
+    buf: &[u8],
+) -> Result<Vec<RoutingDecision>, WireError> {
+    let mut reader = Reader { buf, pos: 0 };
+    if reader.take(4)? != WIRE_MAGIC {
+        return Err(WireError::BadMagic);
+    }
+    let version = reader.byte()?;
+    if version != WIRE_VERSION {
+        return Err(WireError::UnsupportedVersion(version));
+    }
+    let registry_len = reader.varint()? as usize;
+    if registry_len != registry.len() {
+        return Err(WireError::RegistryMismatch {
+            expected: registry.len(),
+            actual: registry_len,
+        });
+    }
+    let count = reader.varint()? as usize;
+
+    let resolve = |index: u64| {
+        registry
+            .expert_id(index as u32)
+            .cloned()
+            .ok_or(WireError::IndexOutOfRange(index))
+    };
+    let mut decisions = Vec::with_capacity(count.min(buf.len()));
+    let mut previous = 0u64;
+    for _ in 0..count {
+        let flags = reader.byte()?;
+        let timestamp = previous.wrapping_add(unzigzag(reader.varint()?) as u64);
+        previous = timestamp;
+        let n = reader.varint()? as usize;
+
+        let mut expert_ids = Vec::with_capacity(n.min(registry_len));
+        if flags & BITSET != 0 {
+            let bits = reader.take(registry_len.div_ceil(8))?;
+            for (byte, &b) in bits.iter().enumerate() {
+                for bit in 0..8 {
+                    if b & (1 << bit) != 0 {
+                        expert_ids.push(resolve((byte * 8 + bit) as u64)?);
+                    }
+                }
+            }
+            if expert_ids.len() != n {
+                return Err(WireError::Truncated);
+            }
+        } else {
+            for _ in 0..n {
+                expert_ids.push(resolve(reader.varint()?)?);
+            }
+        }
+        let gating_weights = if flags & UNIT_WEIGHTS != 0 {
+            vec![1.0; n]
+        } else {
+            reader.floats(n)?
+        };
+        let confidence_scores = if flags & CONFIDENCE_IS_WEIGHT != 0 {
+            gating_weights.clone()
+        } else {
+            reader.floats(n)?
+        };
+        decisions.push(RoutingDecision {
+            expert_ids,
+            confidence_scores,
+            gating_weights,
+            timestamp,
+        });
+    }
+    if reader.pos != buf.len() {
+        return Err(WireError::TrailingBytes(buf.len() - reader.pos));
+    }
+    Ok(decisions)
+}
+
+pub fn encode_decision(
+    registry: &ExpertIndexMap,
+    decision: &RoutingDecision,
+) -> Result<Vec<u8>, WireError> {
+    encode_decisions(registry, std::slice::from_ref(decision))
+}
+
+pub fn decode_decision(
+    registry: &ExpertIndexMap,
+    buf: &[u8],
+) -> Result<RoutingDecision, WireError> {
+    let mut decisions = decode_decisions(registry, buf)?;
+    match decisions.len() {
+        1 => Ok(decisions.remove(0)),
+        n => Err(WireError::UnexpectedCount(n)),
+    }
+}
+
+fn padded(scores: &[f32], len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| scores.get(i).copied().unwrap_or(1.0))
+        .collect()
+}
+
+fn zigzag(v: i64) -> u64 {
+    ((v << 1) ^ (v >> 63)) as u64
+}
+
+fn unzigzag(v: u64) -> i64 {
+    ((v >> 1) as i64) ^ -((v & 1) as i64)
+}
+
+fn varint_len(mut v: u64) -> usize {
+    let mut len = 1;
+    while v >= 0x80 {
+        v >>= 7;
+        len += 1;
+    }
+    len
+}
+
+fn put_varint(buf: &mut Vec<u8>, mut v: u64) {
+    while v >= 0x80 {
+        buf.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    buf.push(v as u8);
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], WireError> {
+        let end = self.pos.checked_add(n).ok_or(WireError::Truncated)?;
+        let bytes = self.buf.get(self.pos..end).ok_or(WireError::Truncated)?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8, WireError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn varint(&mut self) -> Result<u64, WireError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let b = self.byte()?;
+            value |= ((b & 0x7F) as u64) << shift;
+            if b & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(WireError::Truncated)
+    }
+
+    fn floats(&mut self, n: usize) -> Result<Vec<f32>, WireError> {
+        let bytes = self.take(n.checked_mul(4).ok_or(WireError::Truncated)?)?;
+        Ok(bytes
+            .chunks_exact(4)
+            .map(|c| f32::from_le_bytes(c.try_into().unwrap()))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index_expert_id, DeterministicRouter, GatingRouter, Router};
+    use auria_core::Tier;
+
+    #[test]
+    fn test_round_trip_is_compact() {
+        let registry: ExpertIndexMap = (0..256).map(index_expert_id).collect();
+        let router = DeterministicRouter::new_unchecked(256);
+        let decisions: Vec<RoutingDecision> =
+            (0..100).map(|t| router.route(Tier::Max, t * 3)).collect();
+
+        let bytes = encode_decisions(&registry, &decisions).unwrap();
+        let raw: usize = decisions.iter().map(|d| d.expert_ids.len() * 32).sum();
+        assert!(bytes.len() * 10 < raw, "{} vs {}", bytes.len(), raw);
+
+        let decoded = decode_decisions(&registry, &bytes).unwrap();
+        for (a, b) in decisions.iter().zip(&decoded) {
+            assert_eq!(a.expert_ids, b.expert_ids);
+            assert_eq!(a.gating_weights, b.gating_weights);
+            assert_eq!(a.timestamp, b.timestamp);
+        }
+    }
+
+    #[test]
+    fn test_weights_and_bitset_round_trip() {
+        let registry: ExpertIndexMap = (0..8).map(index_expert_id).collect();
+        let mut router = GatingRouter::new_unchecked(1.0);
+        for i in 0..8 {
+            router.set_gate_weight(index_expert_id(i), i as f32);
+        }
+        let mut decision = router.route(Tier::Max, 0);
+        decision.expert_ids.reverse();
+        decision.gating_weights.reverse();
+        decision.confidence_scores = vec![0.5; 8];
+
+        let bytes = encode_decision(&registry, &decision).unwrap();
+        let decoded = decode_decision(&registry, &bytes).unwrap();
+        assert_eq!(decoded.expert_ids, decision.expert_ids);
+        assert_eq!(decoded.gating_weights, decision.gating_weights);
+        assert_eq!(decoded.confidence_scores, decision.confidence_scores);
+    }
+
+    #[test]
+    fn test_decode_rejects_bad_input() {
+        let registry: ExpertIndexMap = (0..4).map(index_expert_id).collect();
+        let decision = DeterministicRouter::new_unchecked(4).route(Tier::Nano, 0);
+        let bytes = encode_decision(&registry, &decision).unwrap();
+
+        assert_eq!(
+            decode_decision(&registry, &bytes[..bytes.len() - 1]).unwrap_err(),
+            WireError::Truncated
+        );
+        let mut bad_version = bytes.clone();
+        bad_version[4] = 9;
+        assert_eq!(
+            decode_decision(&registry, &bad_version).unwrap_err(),
+            WireError::UnsupportedVersion(9)
+        );
+        let larger: ExpertIndexMap = (0..5).map(index_expert_id).collect();
+        assert!(matches!(
+            decode_decision(&larger, &bytes),
+            Err(WireError::RegistryMismatch { .. })
+        ));
+        let unknown = DeterministicRouter::new_unchecked(8).route(Tier::Nano, 6);
+        assert!(matches!(
+            encode_decision(&registry, &unknown),
+            Err(WireError::UnknownExpert(_))
+        ));
+    }
+}